@@ -25,28 +25,22 @@ fn main() {
     // - CompOp::Gt -> Greater than
 
     // Easily compare version strings
-    assert_eq!(VersionCompare::compare(&a, &b).unwrap(), CompOp::Lt);
-    assert_eq!(
-        VersionCompare::compare_to(&a, &b, &CompOp::Le).unwrap(),
-        true
-    );
-    assert_eq!(
-        VersionCompare::compare_to(&a, &b, &CompOp::Gt).unwrap(),
-        false
-    );
+    assert_eq!(VersionCompare::compare(a, b).unwrap(), CompOp::Lt);
+    assert!(VersionCompare::compare_to(a, b, &CompOp::Le).unwrap());
+    assert!(!VersionCompare::compare_to(a, b, &CompOp::Gt).unwrap());
 
     // Version string parsing
     let a_ver = Version::from(a).unwrap();
     let b_ver = Version::from(b).unwrap();
 
     // Directly compare parsed versions
-    assert_eq!(a_ver < b_ver, true);
-    assert_eq!(a_ver <= b_ver, true);
-    assert_eq!(a_ver > b_ver, false);
-    assert_eq!(a_ver != b_ver, true);
+    assert!(a_ver < b_ver);
+    assert!(a_ver <= b_ver);
+    assert!(b_ver > a_ver);
+    assert!(a_ver != b_ver);
     assert_eq!(a_ver.compare(&b_ver), CompOp::Lt);
     assert_eq!(b_ver.compare(&a_ver), CompOp::Gt);
-    assert_eq!(a_ver.compare_to(&b_ver, &CompOp::Lt), true);
+    assert!(a_ver.compare_to(&b_ver, &CompOp::Lt));
 
     // Match
     match a_ver.compare(&b_ver) {
@@ -14,7 +14,7 @@ fn main() {
     let b = "1.2.4";
 
     // Match
-    match VersionCompare::compare(&a, &b).unwrap() {
+    match VersionCompare::compare(a, b).unwrap() {
         CompOp::Lt => println!("Version a is less than b"),
         CompOp::Eq => println!("Version a is equal to b"),
         CompOp::Gt => println!("Version a is greater than b"),
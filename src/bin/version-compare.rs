@@ -0,0 +1,260 @@
+//! Command-line interface of the version-compare library.
+//!
+//! This binary compares, tests and sorts version numbers from shell scripts and Makefiles, as
+//! replacement for `sort -V` or `dpkg --compare-versions`. Run `version-compare help` for usage.
+//!
+//! The exit code is `0` on success or if a test passes, `1` if a test fails, and `2` if the
+//! arguments or versions are invalid.
+
+extern crate version_compare;
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use version_compare::{CompOp, Version, VersionCompare, VersionRange};
+
+/// Usage information, printed by the `help` command.
+const USAGE: &str = "\
+Usage: version-compare <COMMAND> [ARGS]
+
+Commands:
+  compare A B              Print <, == or > comparing version A to B
+  test A OP B              Exit with 0 if A OP B holds, 1 otherwise, such as: test 1.2 '<' 1.3
+  sort [-r]                Sort versions read from stdin, one per line
+  max [VERSION...]         Print the greatest version, read from stdin if none given
+  min [VERSION...]         Print the least version, read from stdin if none given
+  satisfies RANGE VERSION  Exit with 0 if VERSION matches RANGE, 1 otherwise
+  help                     Print this help";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    process::exit(run(&args, &mut stdin.lock(), &mut stdout.lock()));
+}
+
+/// Run the command in the given arguments, and return the exit code.
+///
+/// Versions are read from `input` if the command needs them, results are written to `output`.
+fn run(args: &[String], input: &mut dyn BufRead, output: &mut dyn Write) -> i32 {
+    match execute(args, input, output) {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(err) => {
+            eprintln!("version-compare: {}", err);
+            2
+        }
+    }
+}
+
+/// Execute the command in the given arguments.
+///
+/// `true` is returned if the command succeeded or its test passed, `false` if its test failed.
+fn execute(
+    args: &[String],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<bool, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args[..] {
+        ["compare", a, b] => {
+            let result = VersionCompare::compare(a, b).map_err(|err| err.to_string())?;
+            write_line(output, result.sign())?;
+            Ok(true)
+        }
+        ["test", a, op, b] => {
+            let op = CompOp::from_sign(op)
+                .or_else(|_| CompOp::from_name(op))
+                .map_err(|err| err.to_string())?;
+            VersionCompare::compare_to(a, b, &op).map_err(|err| err.to_string())
+        }
+        ["sort"] => sort(input, output, false),
+        ["sort", "-r"] | ["sort", "--reverse"] => sort(input, output, true),
+        ["max", ref versions @ ..] => extreme(versions, input, output, CompOp::Gt),
+        ["min", ref versions @ ..] => extreme(versions, input, output, CompOp::Lt),
+        ["satisfies", range, version] => {
+            let range = VersionRange::from(range).map_err(|err| format!("range: {}", err))?;
+            let version = parse(version)?;
+            Ok(range.matches(&version))
+        }
+        ["help"] | ["-h"] | ["--help"] => {
+            write_line(output, USAGE)?;
+            Ok(true)
+        }
+        ["-V"] | ["--version"] => {
+            write_line(output, env!("CARGO_PKG_VERSION"))?;
+            Ok(true)
+        }
+        _ => Err(format!("invalid arguments\n\n{}", USAGE)),
+    }
+}
+
+/// Sort the versions read from `input` in ascending order, or descending if `reverse` is set.
+///
+/// Equal versions keep their original order.
+fn sort(input: &mut dyn BufRead, output: &mut dyn Write, reverse: bool) -> Result<bool, String> {
+    let lines = read_lines(input)?;
+
+    // Parse each line, and sort by the parsed versions
+    let mut versions = lines
+        .iter()
+        .map(|line| parse(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    if reverse {
+        versions.sort_by(|a, b| b.cmp(a));
+    } else {
+        versions.sort();
+    }
+
+    for version in versions {
+        write_line(output, version.as_str())?;
+    }
+    Ok(true)
+}
+
+/// Print the greatest version if `op` is `CompOp::Gt`, or the least version if it's `CompOp::Lt`.
+///
+/// The given versions are used, or the versions read from `input` if none are given. The first
+/// of equal versions is printed.
+fn extreme(
+    versions: &[&str],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+    op: CompOp,
+) -> Result<bool, String> {
+    let lines = match versions {
+        [] => read_lines(input)?,
+        versions => versions.iter().map(|version| version.to_string()).collect(),
+    };
+    let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+
+    // Find the extreme version, failing on any invalid version
+    let (result, errors) = match op {
+        CompOp::Gt => VersionCompare::max(&lines),
+        _ => VersionCompare::min(&lines),
+    };
+    if let Some(err) = errors.first() {
+        return Err(err.to_string());
+    }
+
+    let result = result.ok_or("no versions given")?;
+    write_line(output, result)?;
+    Ok(true)
+}
+
+/// Parse the given version, with an error message that includes the version.
+fn parse(version: &str) -> Result<Version<'_>, String> {
+    Version::from(version).map_err(|err| format!("{:?}: {}", version, err))
+}
+
+/// Read all non-empty lines from `input`, without surrounding whitespace.
+fn read_lines(input: &mut dyn BufRead) -> Result<Vec<String>, String> {
+    let mut lines = Vec::new();
+    for line in input.lines() {
+        let line = line.map_err(|err| err.to_string())?;
+        if !line.trim().is_empty() {
+            lines.push(line.trim().to_owned());
+        }
+    }
+    Ok(lines)
+}
+
+/// Write the given line to `output`.
+fn write_line(output: &mut dyn Write, line: &str) -> Result<(), String> {
+    writeln!(output, "{}", line).map_err(|err| err.to_string())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    /// Run the command in the given arguments with `input` as stdin, and return the exit code and
+    /// the output.
+    fn run_with(args: &[&str], input: &str) -> (i32, String) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut output = Vec::new();
+        let code = run(&args, &mut input.as_bytes(), &mut output);
+        (code, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(run_with(&["compare", "1.2", "1.3"], ""), (0, "<\n".into()));
+        assert_eq!(
+            run_with(&["compare", "1.2.0", "1.2"], ""),
+            (0, "==\n".into())
+        );
+        assert_eq!(run_with(&["compare", "2", "1.9"], ""), (0, ">\n".into()));
+        assert_eq!(run_with(&["compare", "abc", "1.9"], ""), (2, "".into()));
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(run_with(&["test", "1.2", "<", "1.3"], "").0, 0);
+        assert_eq!(run_with(&["test", "1.2", ">=", "1.3"], "").0, 1);
+        assert_eq!(run_with(&["test", "1.2", "lt", "1.3"], "").0, 0);
+        assert_eq!(run_with(&["test", "1.2", "ne", "1.2.0"], "").0, 1);
+        assert_eq!(run_with(&["test", "1.2", "=>", "1.3"], "").0, 2);
+        assert_eq!(run_with(&["test", "1.2", "<", "abc"], "").0, 2);
+    }
+
+    #[test]
+    fn sort() {
+        let input = "1.10\n1.2\n\n  1.2.0-rc1\n1.9\n1.2.0\n";
+        assert_eq!(
+            run_with(&["sort"], input),
+            (0, "1.2.0-rc1\n1.2\n1.2.0\n1.9\n1.10\n".into())
+        );
+        assert_eq!(
+            run_with(&["sort", "-r"], input),
+            (0, "1.10\n1.9\n1.2\n1.2.0\n1.2.0-rc1\n".into())
+        );
+        assert_eq!(run_with(&["sort"], ""), (0, "".into()));
+        assert_eq!(run_with(&["sort"], "1.2\nabc\n"), (2, "".into()));
+    }
+
+    #[test]
+    fn max_min() {
+        assert_eq!(
+            run_with(&["max", "1.2", "1.10", "1.9"], ""),
+            (0, "1.10\n".into())
+        );
+        assert_eq!(
+            run_with(&["min", "1.2", "1.10", "1.9"], ""),
+            (0, "1.2\n".into())
+        );
+        assert_eq!(
+            run_with(&["max"], "1.2\n1.10\n1.10.0\n"),
+            (0, "1.10\n".into())
+        );
+        assert_eq!(
+            run_with(&["min"], "1.2\n1.2.0\n1.10\n"),
+            (0, "1.2\n".into())
+        );
+        assert_eq!(run_with(&["max"], ""), (2, "".into()));
+        assert_eq!(run_with(&["min", "1.2", "abc"], ""), (2, "".into()));
+    }
+
+    #[test]
+    fn satisfies() {
+        assert_eq!(run_with(&["satisfies", ">=1.2, <2.0", "1.5"], "").0, 0);
+        assert_eq!(run_with(&["satisfies", "^1.4", "2.0"], "").0, 1);
+        assert_eq!(run_with(&["satisfies", ">=", "1.5"], "").0, 2);
+        assert_eq!(run_with(&["satisfies", "^1.4", "abc"], "").0, 2);
+    }
+
+    #[test]
+    fn help() {
+        let (code, output) = run_with(&["help"], "");
+        assert_eq!(code, 0);
+        assert!(output.starts_with("Usage: version-compare"));
+
+        assert_eq!(run_with(&[], "").0, 2);
+        assert_eq!(run_with(&["compare", "1.2"], "").0, 2);
+        assert_eq!(run_with(&["unknown"], "").0, 2);
+    }
+}
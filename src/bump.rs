@@ -0,0 +1,436 @@
+//! Bump module, to compute the next version from a version string.
+//!
+//! Versions are bumped by editing their version string in place, so the prefix, suffix,
+//! separators and zero padding of the original version are preserved. Version strings are split
+//! following the generic scheme, where the leading numeric parts are the release, such as `1.2.0`
+//! in `1.2.0-rc.1`, the parts from the first text part on are the pre-release, and the parts
+//! from a `+` on are build metadata, such as `build.5` in `1.2.0+build.5`. Semver versions are
+//! split following the semver scheme instead. Build metadata is kept when bumping.
+
+use std::ops::Range;
+
+use crate::error::Error;
+use crate::scheme::generic::Parts;
+use crate::scheme::{semver, Scheme};
+use crate::version_manifest::VersionManifest;
+use crate::version_part::VersionPart;
+
+/// Separator inserted before a new pre-release.
+const PRE_RELEASE_SEPARATOR: char = '-';
+
+/// Separator inserted before new release parts and pre-release counters, if the version doesn't
+/// have a separator to follow.
+const PART_SEPARATOR: char = '.';
+
+/// Version string split in its release and pre-release parts, with their byte ranges.
+struct Layout<'a> {
+    /// The release parts, with the separator before each part.
+    release: Vec<(Option<char>, Range<usize>, u64)>,
+
+    /// The pre-release parts, with the separator before each part.
+    pre_release: Vec<(Option<char>, Range<usize>, VersionPart<'a>)>,
+}
+
+impl<'a> Layout<'a> {
+    /// Split the given version string following the scheme of the manifest.
+    ///
+    /// An error is returned if the version doesn't start with a numeric part, or if it doesn't
+    /// use the generic or semver scheme.
+    fn new(version: &'a str, manifest: &VersionManifest) -> Result<Self, Error> {
+        match manifest.scheme() {
+            Scheme::Generic => Self::generic(version, manifest),
+            Scheme::Semver => Self::semver(version),
+            _ => Err(Error::CannotBump),
+        }
+    }
+
+    /// Split the given version string following the generic scheme.
+    ///
+    /// Build metadata, the parts from a `+` separator after the release on, isn't included.
+    fn generic(version: &'a str, manifest: &VersionManifest) -> Result<Self, Error> {
+        let mut release = Vec::new();
+        let mut pre_release = Vec::new();
+        for part in Parts::new(version, manifest)? {
+            match part {
+                (Some('+'), _, _) if !release.is_empty() => break,
+                (separator, range, VersionPart::Number(number)) if pre_release.is_empty() => {
+                    release.push((separator, range, number))
+                }
+                part => pre_release.push(part),
+            }
+        }
+
+        if release.is_empty() {
+            return Err(Error::CannotBump);
+        }
+        Ok(Layout {
+            release,
+            pre_release,
+        })
+    }
+
+    /// Split the given version string following the semver scheme.
+    ///
+    /// The release is the major, minor and patch number, and the pre-release is everything after
+    /// the `-` up to the build metadata. Build metadata isn't included.
+    fn semver(version: &'a str) -> Result<Self, Error> {
+        let mut parts = semver::split(version)?.into_iter();
+        let (core, pre_release, _) = semver::sections(version);
+        let separator = |offset| if offset == 0 { None } else { Some('.') };
+
+        let release = semver::identifiers(core, 0)
+            .zip(parts.by_ref())
+            .filter_map(|((offset, identifier), part)| match part {
+                VersionPart::Number(number) => {
+                    Some((separator(offset), offset..offset + identifier.len(), number))
+                }
+                VersionPart::Text(_) => None,
+            })
+            .collect();
+        let pre_release = semver::identifiers(pre_release.unwrap_or_default(), core.len() + 1)
+            .zip(parts)
+            .map(|((offset, identifier), part)| {
+                let separator = if offset == core.len() + 1 {
+                    Some('-')
+                } else {
+                    Some('.')
+                };
+                (separator, offset..offset + identifier.len(), part)
+            })
+            .collect();
+
+        Ok(Layout {
+            release,
+            pre_release,
+        })
+    }
+
+    /// The byte offset of the end of the release.
+    fn release_end(&self) -> usize {
+        self.release.last().map_or(0, |(_, range, _)| range.end)
+    }
+
+    /// The byte range of the pre-release, including the separator before it.
+    fn pre_release_range(&self) -> Range<usize> {
+        let end = self
+            .pre_release
+            .last()
+            .map_or(self.release_end(), |(_, range, _)| range.end);
+        self.release_end()..end
+    }
+
+    /// The separator to use before new release parts, which is the last separator in the release.
+    fn release_separator(&self) -> char {
+        self.release
+            .iter()
+            .rev()
+            .find_map(|(separator, _, _)| *separator)
+            .unwrap_or(PART_SEPARATOR)
+    }
+}
+
+/// Bump the numeric release part at `index`, and reset the release parts after it to zero.
+///
+/// Missing release parts are added as zero. A pre-release is removed. If the version is a
+/// pre-release and all release parts after `index` are zero, such as for the minor part of
+/// `1.3.0-rc.1`, the version is only finalized because `1.3.0` is the next version.
+pub(crate) fn bump_part(
+    version: &str,
+    manifest: &VersionManifest,
+    index: usize,
+) -> Result<String, Error> {
+    let layout = Layout::new(version, manifest)?;
+
+    // Semver versions can't have more than a major, minor and patch number
+    if manifest.scheme() == &Scheme::Semver && index >= layout.release.len() {
+        return Err(Error::CannotBump);
+    }
+
+    let finalize = !layout.pre_release.is_empty()
+        && layout
+            .release
+            .iter()
+            .skip(index + 1)
+            .all(|(_, _, number)| *number == 0);
+
+    // Bump the part at the index and reset the parts after it, keeping their zero padding
+    let mut edits = Vec::new();
+    for (i, (_, range, number)) in layout.release.iter().enumerate() {
+        let number = match i {
+            i if i < index || finalize => continue,
+            i if i == index => increment(*number, range.start)?,
+            _ => 0,
+        };
+        edits.push((range.clone(), pad(&version[range.clone()], number)));
+    }
+
+    // Add missing parts up to the index, and remove the pre-release
+    let mut added = String::new();
+    for i in layout.release.len()..=index {
+        added.push(layout.release_separator());
+        added.push(if i == index && !finalize { '1' } else { '0' });
+    }
+    edits.push((layout.pre_release_range(), added));
+
+    Ok(splice(version, edits))
+}
+
+/// Bump the pre-release counter if the pre-release has the given `label`, or start a pre-release
+/// with the given `label` and counter `1`.
+///
+/// The counter is the numeric part after the label, such as `1` in `1.2.0-rc.1`, or the number
+/// directly after it, such as in `1.2.0-rc1`. A pre-release with another label is replaced.
+pub(crate) fn bump_pre_release(
+    version: &str,
+    manifest: &VersionManifest,
+    label: &str,
+) -> Result<String, Error> {
+    if label.is_empty() || !label.chars().all(char::is_alphabetic) {
+        return Err(Error::InvalidLabel(label.into()));
+    }
+    let layout = Layout::new(version, manifest)?;
+
+    let edit = match &layout.pre_release[..] {
+        // Bump the counter after the label, or add it
+        [(_, range, VersionPart::Text(text)), rest @ ..] if text.eq_ignore_ascii_case(label) => {
+            match rest.first() {
+                Some((_, range, VersionPart::Number(number))) => (
+                    range.clone(),
+                    pad(&version[range.clone()], increment(*number, range.start)?),
+                ),
+                _ => (range.end..range.end, format!("{}{}", PART_SEPARATOR, 1)),
+            }
+        }
+
+        // Bump the counter directly after the label
+        [(_, range, VersionPart::Text(text)), ..] if counter(text, label).is_some() => {
+            let counter = counter(text, label).unwrap();
+            let start = range.end - counter.len();
+            let number = counter
+                .parse::<u64>()
+                .map_err(|_| Error::NumberOverflow { offset: start })?;
+            (start..range.end, pad(counter, increment(number, start)?))
+        }
+
+        // Replace the pre-release with another label, keeping the separator before it
+        [(_, range, _), ..] => (
+            range.start..layout.pre_release_range().end,
+            format!("{}{}{}", label, PART_SEPARATOR, 1),
+        ),
+
+        // Start a pre-release
+        [] => (
+            layout.release_end()..layout.release_end(),
+            format!("{}{}{}{}", PRE_RELEASE_SEPARATOR, label, PART_SEPARATOR, 1),
+        ),
+    };
+
+    Ok(splice(version, vec![edit]))
+}
+
+/// Remove the pre-release, so `1.2.0-rc.1` becomes `1.2.0`. Build metadata is kept.
+pub(crate) fn finalize(version: &str, manifest: &VersionManifest) -> Result<String, Error> {
+    let layout = Layout::new(version, manifest)?;
+    Ok(splice(
+        version,
+        vec![(layout.pre_release_range(), String::new())],
+    ))
+}
+
+/// Get the counter directly after `label` in the given pre-release `text`, such as `1` in `rc1`.
+fn counter<'t>(text: &'t str, label: &str) -> Option<&'t str> {
+    let counter = text.get(label.len()..)?;
+    let matches = text[..label.len()].eq_ignore_ascii_case(label)
+        && !counter.is_empty()
+        && counter.bytes().all(|b| b.is_ascii_digit());
+    Some(counter).filter(|_| matches)
+}
+
+/// Add one to the given `number` of the numeric part at byte `offset`.
+fn increment(number: u64, offset: usize) -> Result<u64, Error> {
+    number
+        .checked_add(1)
+        .ok_or(Error::NumberOverflow { offset })
+}
+
+/// Format the given `number` with the same zero padding as the `original` number, so `09`
+/// becomes `10` and `00` stays two digits wide.
+fn pad(original: &str, number: u64) -> String {
+    format!("{:0width$}", number, width = original.len())
+}
+
+/// Replace the given ranges in the version string, the ranges must be ascending and must not
+/// overlap.
+fn splice(version: &str, edits: Vec<(Range<usize>, String)>) -> String {
+    let mut result = String::with_capacity(version.len());
+    let mut offset = 0;
+    for (range, replacement) in edits {
+        result.push_str(&version[offset..range.start]);
+        result.push_str(&replacement);
+        offset = range.end;
+    }
+    result.push_str(&version[offset..]);
+    result
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::version_manifest::VersionManifest;
+
+    use super::{bump_part, bump_pre_release, finalize};
+
+    #[test]
+    fn bump_part_release() {
+        let manifest = VersionManifest::new();
+        let bump = |version, index| bump_part(version, &manifest, index).unwrap();
+
+        assert_eq!(bump("1.2.3", 0), "2.0.0");
+        assert_eq!(bump("1.2.3", 1), "1.3.0");
+        assert_eq!(bump("1.2.3", 2), "1.2.4");
+        assert_eq!(bump("1.2.3", 3), "1.2.3.1");
+        assert_eq!(bump("1", 2), "1.0.1");
+        assert_eq!(bump("1_9", 2), "1_9_1");
+
+        // Prefixes, suffixes, separators and zero padding are preserved
+        assert_eq!(bump("v1.2.3", 1), "v1.3.0");
+        assert_eq!(bump("release-1.2", 0), "release-2.0");
+        assert_eq!(
+            bump("MyApp 3.2.0 / build 0932", 2),
+            "MyApp 3.2.1 / build 0932"
+        );
+        assert_eq!(bump("2024.09.05", 1), "2024.10.00");
+        assert_eq!(bump("1-2-3", 1), "1-3-0");
+    }
+
+    #[test]
+    fn bump_part_pre_release() {
+        let manifest = VersionManifest::new();
+        let bump = |version, index| bump_part(version, &manifest, index).unwrap();
+
+        // The pre-release is removed, and only finalized if the lower parts are zero
+        assert_eq!(bump("1.2.3-rc.1", 2), "1.2.3");
+        assert_eq!(bump("1.2.3-rc.1", 1), "1.3.0");
+        assert_eq!(bump("1.3.0-rc.1", 1), "1.3.0");
+        assert_eq!(bump("1.3.0-rc.1", 0), "2.0.0");
+        assert_eq!(bump("2.0.0-beta.2", 0), "2.0.0");
+        assert_eq!(bump("1.2-rc1", 2), "1.2.0");
+        assert_eq!(bump("v1.2.0-rc.1 (nightly)", 0), "v2.0.0 (nightly)");
+    }
+
+    #[test]
+    fn bump_build_metadata() {
+        let manifest = VersionManifest::new();
+
+        // Build metadata is kept, and isn't part of the pre-release
+        assert_eq!(
+            bump_part("1.2.3+build.5", &manifest, 2).unwrap(),
+            "1.2.4+build.5"
+        );
+        assert_eq!(
+            bump_part("1.2.3-rc.1+build.5", &manifest, 1).unwrap(),
+            "1.3.0+build.5"
+        );
+        assert_eq!(
+            bump_pre_release("1.2.3+build.5", &manifest, "rc").unwrap(),
+            "1.2.3-rc.1+build.5"
+        );
+        assert_eq!(
+            bump_pre_release("1.2.3-beta+build.5", &manifest, "rc").unwrap(),
+            "1.2.3-rc.1+build.5"
+        );
+        assert_eq!(
+            finalize("1.2.3-rc.1+build.5", &manifest).unwrap(),
+            "1.2.3+build.5"
+        );
+        assert_eq!(
+            finalize("1.2.3+build.5", &manifest).unwrap(),
+            "1.2.3+build.5"
+        );
+    }
+
+    #[test]
+    fn bump_semver() {
+        let manifest = VersionManifest::new().with_scheme(Scheme::Semver);
+        let bump = |version, index| bump_part(version, &manifest, index).unwrap();
+
+        // The pre-release is everything after the `-`, even if it's numeric
+        assert_eq!(bump("1.2.3", 2), "1.2.4");
+        assert_eq!(bump("1.2.3-0", 2), "1.2.3");
+        assert_eq!(bump("1.2.3-0.3.7", 2), "1.2.3");
+        assert_eq!(bump("1.2.3-0.3.7", 1), "1.3.0");
+        assert_eq!(bump("1.2.3-x.7.z.92+build.5", 0), "2.0.0+build.5");
+        assert_eq!(finalize("1.2.3-0", &manifest).unwrap(), "1.2.3");
+        assert_eq!(
+            finalize("1.2.3-0.3.7+build.5", &manifest).unwrap(),
+            "1.2.3+build.5"
+        );
+
+        // Pre-releases are started, bumped and replaced
+        let bump = |version, label| bump_pre_release(version, &manifest, label).unwrap();
+        assert_eq!(bump("1.2.3", "rc"), "1.2.3-rc.1");
+        assert_eq!(bump("1.2.3-0", "rc"), "1.2.3-rc.1");
+        assert_eq!(bump("1.2.3-rc.1", "rc"), "1.2.3-rc.2");
+        assert_eq!(bump("1.2.3-rc.1+build.5", "rc"), "1.2.3-rc.2+build.5");
+        assert_eq!(bump("1.2.3+build.5", "alpha"), "1.2.3-alpha.1+build.5");
+
+        // Only the major, minor and patch number can be bumped, and versions must be valid
+        assert_eq!(bump_part("1.2.3", &manifest, 3), Err(Error::CannotBump));
+        assert!(bump_part("1.2", &manifest, 0).is_err());
+    }
+
+    #[test]
+    fn bump_pre_release_label() {
+        let manifest = VersionManifest::new();
+        let bump = |version, label| bump_pre_release(version, &manifest, label).unwrap();
+
+        // Bump the counter
+        assert_eq!(bump("1.2.0-rc.1", "rc"), "1.2.0-rc.2");
+        assert_eq!(bump("1.2.0-RC.9", "rc"), "1.2.0-RC.10");
+        assert_eq!(bump("1.2.0-rc.01", "rc"), "1.2.0-rc.02");
+        assert_eq!(bump("1.2.0-rc1", "rc"), "1.2.0-rc2");
+        assert_eq!(bump("1.2.0-rc", "rc"), "1.2.0-rc.1");
+        assert_eq!(bump("1.2.0-rc.1+build.5", "rc"), "1.2.0-rc.2+build.5");
+
+        // Start or replace the pre-release
+        assert_eq!(bump("1.2.0", "rc"), "1.2.0-rc.1");
+        assert_eq!(bump("v1.2 (nightly)", "alpha"), "v1.2-alpha.1 (nightly)");
+        assert_eq!(bump("1.2.0-beta.3", "rc"), "1.2.0-rc.1");
+        assert_eq!(bump("1.2.0~beta", "rc"), "1.2.0~rc.1");
+        assert_eq!(bump("1.2.0-rcx", "rc"), "1.2.0-rc.1");
+    }
+
+    #[test]
+    fn finalize_pre_release() {
+        let manifest = VersionManifest::new();
+        let finalize = |version| finalize(version, &manifest).unwrap();
+
+        assert_eq!(finalize("1.2.0-rc.1"), "1.2.0");
+        assert_eq!(finalize("v1.2.0-rc.1 / build 5"), "v1.2.0 / build 5");
+        assert_eq!(finalize("1.2.0"), "1.2.0");
+        assert_eq!(finalize("1.2_rc1"), "1.2");
+    }
+
+    #[test]
+    fn error() {
+        let manifest = VersionManifest::new();
+        assert_eq!(bump_part("beta.1", &manifest, 0), Err(Error::CannotBump));
+        assert_eq!(finalize("", &manifest), Err(Error::CannotBump));
+        for label in &["", "rc1", "r.c", "rc "] {
+            assert_eq!(
+                bump_pre_release("1.2.0", &manifest, label),
+                Err(Error::InvalidLabel(label.to_string()))
+            );
+        }
+        assert_eq!(
+            bump_pre_release("1.2.0-rc99999999999999999999", &manifest, "rc"),
+            Err(Error::NumberOverflow { offset: 8 })
+        );
+        assert_eq!(
+            bump_part("1.18446744073709551615", &manifest, 1),
+            Err(Error::NumberOverflow { offset: 2 })
+        );
+    }
+}
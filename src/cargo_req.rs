@@ -0,0 +1,581 @@
+//! Cargo requirement module, which provides the `CargoReq` struct as parsed Cargo version
+//! requirement representation.
+//!
+//! A Cargo requirement is a list of comparators that a version must satisfy, such as `^1.2` or
+//! `>=1.2, <1.5`, as used for dependency versions in `Cargo.toml`. Requirements are tested
+//! against a `Version` using `CargoReq::matches`, which behaves exactly like Cargo.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::semver::{self, compare_pre_release};
+use crate::scheme::Scheme;
+use crate::version::Version;
+use crate::version_part::VersionPart;
+use crate::version_range::offset_in;
+
+/// Cargo requirement struct, which is a representation for a parsed Cargo requirement string.
+///
+/// A requirement string consists of one or more comma separated comparators, a version matches
+/// the requirement if it matches all of them. The following comparators are supported:
+///
+/// * `1.2.3`, `^1.2.3`: any version compatible with the given version, allowing changes that do
+///   not modify the left-most non-zero part. So `^0.2.3` matches `0.2.9` but not `0.3.0`.
+/// * `~1.2.3`: any version with the same major and minor part, allowing patch-level changes.
+/// * `=1.2.3`, `>1.2.3`, `>=1.2.3`, `<1.2.3`, `<=1.2.3`: a comparison with the given version.
+/// * `1.*`, `1.2.*`, `*`: any version starting with the given parts.
+///
+/// Minor and patch parts may be omitted, `=1.2` matches any `1.2.x` version.
+///
+/// A pre-release version only matches if a comparator has a pre-release with the same major,
+/// minor and patch part. So `>=1.2.3-alpha` matches `1.2.3-beta` but not `1.2.4-beta`, and `*`
+/// doesn't match any pre-release version.
+///
+/// The original requirement string is stored in the struct, and can be accessed using the
+/// `req.as_str()` method.
+#[derive(Debug)]
+pub struct CargoReq<'a> {
+    req: &'a str,
+    comparators: Vec<ReqComparator<'a>>,
+}
+
+impl<'a> CargoReq<'a> {
+    /// Create a `CargoReq` instance from a requirement string.
+    ///
+    /// An error is returned if the requirement string couldn't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CargoReq, Version};
+    ///
+    /// let req = CargoReq::from(">=1.2, <1.5").unwrap();
+    ///
+    /// assert!(req.matches(&Version::from("1.4.9").unwrap()));
+    /// assert!(!req.matches(&Version::from("1.5.0").unwrap()));
+    /// assert!(CargoReq::from("1.2.03").is_err());
+    /// ```
+    pub fn from(req: &'a str) -> Result<Self, Error> {
+        let text = req.trim_start_matches(' ');
+
+        // A wildcard must be the only comparator
+        if let Some(rest) = strip_wildcard(text) {
+            let rest = rest.trim_start_matches(' ');
+            if !rest.is_empty() {
+                return Err(Error::InvalidRange {
+                    offset: offset_in(req, rest),
+                });
+            }
+            return Ok(CargoReq {
+                req,
+                comparators: vec![],
+            });
+        }
+
+        // Parse each comparator, separated by commas
+        let mut comparators = Vec::new();
+        let mut text = text;
+        loop {
+            let (comparator, rest) = ReqComparator::parse(req, text)?;
+            comparators.push(comparator);
+
+            if rest.is_empty() {
+                break;
+            }
+            match rest.strip_prefix(',') {
+                Some(rest) => text = rest.trim_start_matches(' '),
+                None => {
+                    return Err(Error::InvalidRange {
+                        offset: offset_in(req, rest),
+                    })
+                }
+            }
+        }
+
+        Ok(CargoReq { req, comparators })
+    }
+
+    /// Get the original requirement string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::CargoReq;
+    ///
+    /// let req = CargoReq::from("~1.2.3").unwrap();
+    ///
+    /// assert_eq!(req.as_str(), "~1.2.3");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.req
+    }
+
+    /// Check whether the given `version` matches this requirement.
+    ///
+    /// The version is interpreted as a semver version, like Cargo does. A version string that
+    /// isn't a valid semver version never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CargoReq, Version};
+    ///
+    /// let req = CargoReq::from("^0.3").unwrap();
+    ///
+    /// assert!(req.matches(&Version::from("0.3.7").unwrap()));
+    /// assert!(!req.matches(&Version::from("0.4.0").unwrap()));
+    /// assert!(!req.matches(&Version::from("0.3.8-rc.1").unwrap()));
+    /// assert!(CargoReq::from(">=0.3.8-rc.1").unwrap().matches(&Version::from("0.3.8-rc.2").unwrap()));
+    /// ```
+    pub fn matches(&self, version: &Version) -> bool {
+        let parts = match semver::split(version.as_str()) {
+            Ok(parts) => parts,
+            Err(_) => return false,
+        };
+        let target = match Target::from(&parts) {
+            Some(target) => target,
+            None => return false,
+        };
+
+        if !self
+            .comparators
+            .iter()
+            .all(|comparator| comparator.matches(&target))
+        {
+            return false;
+        }
+
+        // A pre-release version must be opted into by a comparator for the same version
+        target.pre.is_empty()
+            || self
+                .comparators
+                .iter()
+                .any(|comparator| comparator.allows_pre_release(&target))
+    }
+}
+
+impl<'a> fmt::Display for CargoReq<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.req)
+    }
+}
+
+/// The operator of a comparator in a requirement string.
+#[derive(Debug)]
+enum Operator {
+    /// A plain comparison operator: `Eq`, `Gt`, `Ge`, `Lt` or `Le`.
+    Cmp(CompOp),
+
+    /// Caret operator (`^`), the default.
+    Caret,
+
+    /// Tilde operator (`~`).
+    Tilde,
+
+    /// Wildcard version without operator, such as `1.*`.
+    Wildcard,
+}
+
+impl Operator {
+    /// Split the leading operator off the given comparator text.
+    ///
+    /// `None` is returned as operator if the text doesn't start with one.
+    fn split(text: &str) -> (Option<Operator>, &str) {
+        let (op, len) = match text.as_bytes() {
+            [b'>', b'=', ..] => (Operator::Cmp(CompOp::Ge), 2),
+            [b'<', b'=', ..] => (Operator::Cmp(CompOp::Le), 2),
+            [b'=', ..] => (Operator::Cmp(CompOp::Eq), 1),
+            [b'>', ..] => (Operator::Cmp(CompOp::Gt), 1),
+            [b'<', ..] => (Operator::Cmp(CompOp::Lt), 1),
+            [b'~', ..] => (Operator::Tilde, 1),
+            [b'^', ..] => (Operator::Caret, 1),
+            _ => return (None, text),
+        };
+        (Some(op), &text[len..])
+    }
+}
+
+/// A single comparator in a requirement, such as `>=1.2`.
+///
+/// Minor and patch parts are `None` if omitted or a wildcard.
+#[derive(Debug)]
+struct ReqComparator<'a> {
+    op: Operator,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Vec<VersionPart<'a>>,
+}
+
+impl<'a> ReqComparator<'a> {
+    /// Parse a comparator from the start of `text`, returning the comparator and the remaining
+    /// text.
+    ///
+    /// The `text` must be a slice of `req`, which is used to report error offsets.
+    fn parse(req: &'a str, text: &'a str) -> Result<(Self, &'a str), Error> {
+        let (op, text) = Operator::split(text);
+        let default_op = op.is_none();
+        let mut op = op.unwrap_or(Operator::Caret);
+        let text = text.trim_start_matches(' ');
+
+        // Parse the major, minor and patch part, the minor and patch part may be a wildcard
+        let (major, mut text) = numeric(req, text)?;
+        let mut minor = None;
+        let mut patch = None;
+        let mut wildcard = false;
+        if let Some(rest) = text.strip_prefix('.') {
+            text = match strip_wildcard(rest) {
+                Some(rest) => {
+                    wildcard = true;
+                    rest
+                }
+                None => {
+                    let (number, rest) = numeric(req, rest)?;
+                    minor = Some(number);
+                    rest
+                }
+            };
+        }
+        if let Some(rest) = text.strip_prefix('.') {
+            text = match strip_wildcard(rest) {
+                Some(rest) => {
+                    wildcard = true;
+                    rest
+                }
+                None if wildcard => {
+                    return Err(Error::InvalidRange {
+                        offset: offset_in(req, rest),
+                    })
+                }
+                None => {
+                    let (number, rest) = numeric(req, rest)?;
+                    patch = Some(number);
+                    rest
+                }
+            };
+        }
+        if wildcard && default_op {
+            op = Operator::Wildcard;
+        }
+
+        // Parse the pre-release and build metadata, which are only allowed after a patch part
+        let mut pre = Vec::new();
+        if patch.is_some() {
+            if let Some(rest) = text.strip_prefix('-') {
+                let (identifiers, rest) = identifiers(req, rest, true)?;
+                pre = identifiers;
+                text = rest;
+            }
+            if let Some(rest) = text.strip_prefix('+') {
+                text = identifiers(req, rest, false)?.1;
+            }
+        }
+
+        let comparator = ReqComparator {
+            op,
+            major,
+            minor,
+            patch,
+            pre,
+        };
+        Ok((comparator, text.trim_start_matches(' ')))
+    }
+
+    /// Check whether the given `target` version matches this comparator, ignoring whether
+    /// pre-release versions are allowed.
+    fn matches(&self, target: &Target) -> bool {
+        match self.op {
+            Operator::Cmp(CompOp::Eq) | Operator::Wildcard => self.matches_exact(target),
+            Operator::Cmp(CompOp::Gt) => self.compare(target) == Some(Ordering::Greater),
+            Operator::Cmp(CompOp::Ge) => {
+                self.matches_exact(target) || self.compare(target) == Some(Ordering::Greater)
+            }
+            Operator::Cmp(CompOp::Lt) => self.compare(target) == Some(Ordering::Less),
+            Operator::Cmp(CompOp::Le) => {
+                self.matches_exact(target) || self.compare(target) == Some(Ordering::Less)
+            }
+            Operator::Cmp(_) => false,
+            Operator::Tilde => self.matches_tilde(target),
+            Operator::Caret => self.matches_caret(target),
+        }
+    }
+
+    /// Check whether the given `target` version equals this comparator, omitted parts match any
+    /// value.
+    fn matches_exact(&self, target: &Target) -> bool {
+        target.major == self.major
+            && self.minor.map_or(true, |minor| target.minor == minor)
+            && self.patch.map_or(true, |patch| target.patch == patch)
+            && compare_pre_release(target.pre, &self.pre) == Ordering::Equal
+    }
+
+    /// Compare the given `target` version to this comparator.
+    ///
+    /// `None` is returned if the target is within the omitted parts of this comparator, in which
+    /// case it's neither greater nor less.
+    fn compare(&self, target: &Target) -> Option<Ordering> {
+        let parts = [
+            (target.major, Some(self.major)),
+            (target.minor, self.minor),
+            (target.patch, self.patch),
+        ];
+        for (part, other) in parts {
+            match part.cmp(&other?) {
+                Ordering::Equal => {}
+                ord => return Some(ord),
+            }
+        }
+        Some(compare_pre_release(target.pre, &self.pre))
+    }
+
+    /// Check whether the given `target` version matches this tilde comparator.
+    fn matches_tilde(&self, target: &Target) -> bool {
+        if target.major != self.major || self.minor.is_some_and(|minor| target.minor != minor) {
+            return false;
+        }
+        match self.patch {
+            Some(patch) if target.patch != patch => target.patch > patch,
+            _ => compare_pre_release(target.pre, &self.pre) != Ordering::Less,
+        }
+    }
+
+    /// Check whether the given `target` version matches this caret comparator.
+    ///
+    /// Changes to the left-most non-zero part of the comparator aren't allowed.
+    fn matches_caret(&self, target: &Target) -> bool {
+        if target.major != self.major {
+            return false;
+        }
+        let minor = match self.minor {
+            Some(minor) => minor,
+            None => return true,
+        };
+        let patch = match self.patch {
+            Some(patch) => patch,
+            None if self.major > 0 => return target.minor >= minor,
+            None => return target.minor == minor,
+        };
+
+        if self.major > 0 {
+            if target.minor != minor {
+                return target.minor > minor;
+            } else if target.patch != patch {
+                return target.patch > patch;
+            }
+        } else if minor > 0 {
+            if target.minor != minor {
+                return false;
+            } else if target.patch != patch {
+                return target.patch > patch;
+            }
+        } else if target.minor != minor || target.patch != patch {
+            return false;
+        }
+
+        compare_pre_release(target.pre, &self.pre) != Ordering::Less
+    }
+
+    /// Check whether this comparator allows pre-releases of the given `target` version.
+    ///
+    /// This is the case if the comparator has a pre-release for the same version.
+    fn allows_pre_release(&self, target: &Target) -> bool {
+        self.major == target.major
+            && self.minor == Some(target.minor)
+            && self.patch == Some(target.patch)
+            && !self.pre.is_empty()
+    }
+}
+
+/// A semver version that is matched against a requirement.
+struct Target<'b, 'a> {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: &'b [VersionPart<'a>],
+}
+
+impl<'b, 'a> Target<'b, 'a> {
+    /// Build a target from the parts of a semver version.
+    fn from(parts: &'b [VersionPart<'a>]) -> Option<Self> {
+        match parts {
+            [VersionPart::Number(major), VersionPart::Number(minor), VersionPart::Number(patch), pre @ ..] => {
+                Some(Target {
+                    major: *major,
+                    minor: *minor,
+                    patch: *patch,
+                    pre,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Strip a leading wildcard (`*`, `x` or `X`) off the given text.
+fn strip_wildcard(text: &str) -> Option<&str> {
+    text.strip_prefix(['*', 'x', 'X'])
+}
+
+/// Parse a leading number off the given text, returning the number and the remaining text.
+///
+/// The `text` must be a slice of `req`, which is used to report error offsets.
+fn numeric<'a>(req: &'a str, text: &'a str) -> Result<(u64, &'a str), Error> {
+    let offset = offset_in(req, text);
+    let len = text.bytes().take_while(u8::is_ascii_digit).count();
+    match &text[..len] {
+        "" => Err(Error::InvalidRange { offset }),
+        digits if len > 1 && digits.starts_with('0') => Err(Error::InvalidFormat {
+            scheme: Scheme::Semver,
+            offset,
+        }),
+        digits => digits
+            .parse()
+            .map(|number| (number, &text[len..]))
+            .map_err(|_| Error::NumberOverflow { offset }),
+    }
+}
+
+/// Parse leading dot separated identifiers off the given text, returning them and the remaining
+/// text.
+///
+/// Pre-release identifiers are parsed into version parts, and numeric pre-release identifiers
+/// must not have leading zeros. The `text` must be a slice of `req`, which is used to report
+/// error offsets.
+fn identifiers<'a>(
+    req: &'a str,
+    text: &'a str,
+    pre_release: bool,
+) -> Result<(Vec<VersionPart<'a>>, &'a str), Error> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    loop {
+        let offset = offset_in(req, rest);
+        let len = rest
+            .bytes()
+            .take_while(|&b| b.is_ascii_alphanumeric() || b == b'-')
+            .count();
+        let identifier = &rest[..len];
+        if identifier.is_empty() {
+            return Err(Error::InvalidRange { offset });
+        }
+
+        if pre_release {
+            if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                let (number, _) = numeric(req, identifier)?;
+                parts.push(VersionPart::Number(number));
+            } else {
+                parts.push(VersionPart::Text(Cow::Borrowed(identifier)));
+            }
+        }
+
+        rest = &rest[len..];
+        match rest.strip_prefix('.') {
+            Some(next) => rest = next,
+            None => return Ok((parts, rest)),
+        }
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_cargo_req::{TEST_CARGO_REQS, TEST_CARGO_REQS_ERROR};
+    use crate::version::Version;
+
+    use super::CargoReq;
+
+    #[test]
+    fn from() {
+        // Test whether parsing works for each test requirement
+        for entry in TEST_CARGO_REQS {
+            assert!(CargoReq::from(entry.0).is_ok(), "Parsing {}", entry.0);
+        }
+
+        // Test whether parsing fails for each invalid requirement
+        for req in TEST_CARGO_REQS_ERROR {
+            assert!(CargoReq::from(req).is_err(), "Parsing {}", req);
+        }
+    }
+
+    #[test]
+    fn from_error() {
+        assert_eq!(
+            CargoReq::from(">=1.2, abc").unwrap_err(),
+            Error::InvalidRange { offset: 7 }
+        );
+        assert_eq!(
+            CargoReq::from("1.2 <2").unwrap_err(),
+            Error::InvalidRange { offset: 4 }
+        );
+        assert_eq!(
+            CargoReq::from("*, 1.2").unwrap_err(),
+            Error::InvalidRange { offset: 1 }
+        );
+        assert_eq!(
+            CargoReq::from("1.*.3").unwrap_err(),
+            Error::InvalidRange { offset: 4 }
+        );
+        assert_eq!(
+            CargoReq::from("=1.02").unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 3
+            }
+        );
+        assert_eq!(
+            CargoReq::from("^1.2.3-01").unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 7
+            }
+        );
+        assert_eq!(
+            CargoReq::from("<1.99999999999999999999").unwrap_err(),
+            Error::NumberOverflow { offset: 3 }
+        );
+    }
+
+    #[test]
+    fn as_str() {
+        for entry in TEST_CARGO_REQS {
+            assert_eq!(CargoReq::from(entry.0).unwrap().as_str(), entry.0);
+        }
+    }
+
+    #[test]
+    fn matches() {
+        for entry in TEST_CARGO_REQS {
+            let req = CargoReq::from(entry.0).unwrap();
+            let version = Version::from(entry.1).unwrap();
+
+            assert_eq!(
+                req.matches(&version),
+                entry.2,
+                "Testing that {} matches {}",
+                entry.1,
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn matches_scheme() {
+        // The version is interpreted as semver version, regardless of its scheme
+        let req = CargoReq::from("^1.2").unwrap();
+        assert!(req.matches(&Version::from_scheme("1.3.0", Scheme::Semver).unwrap()));
+        assert!(!req.matches(&Version::from("1.3").unwrap()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", CargoReq::from(">=1.2, <1.5").unwrap()),
+            ">=1.2, <1.5"
+        );
+    }
+}
@@ -8,6 +8,8 @@
 
 use std::cmp::Ordering;
 
+use crate::error::Error;
+
 /// Enum of supported comparison operators.
 #[derive(Debug, Clone, PartialEq)]
 pub enum CompOp {
@@ -60,15 +62,15 @@ impl CompOp {
     /// assert_eq!(CompOp::from_sign("  >=   "), Ok(CompOp::Ge));
     /// assert!(CompOp::from_sign("*").is_err());
     /// ```
-    pub fn from_sign(sign: &str) -> Result<CompOp, ()> {
-        match sign.trim().as_ref() {
+    pub fn from_sign(sign: &str) -> Result<CompOp, Error> {
+        match sign.trim() {
             "==" | "=" => Ok(CompOp::Eq),
             "!=" | "!" | "<>" => Ok(CompOp::Ne),
             "<" => Ok(CompOp::Lt),
             "<=" => Ok(CompOp::Le),
             ">=" => Ok(CompOp::Ge),
             ">" => Ok(CompOp::Gt),
-            _ => Err(()),
+            sign => Err(Error::UnknownOperator(sign.to_owned())),
         }
     }
 
@@ -86,7 +88,7 @@ impl CompOp {
     /// assert_eq!(CompOp::from_name("  Ge   "), Ok(CompOp::Ge));
     /// assert!(CompOp::from_name("abc").is_err());
     /// ```
-    pub fn from_name(sign: &str) -> Result<CompOp, ()> {
+    pub fn from_name(sign: &str) -> Result<CompOp, Error> {
         match sign.trim().to_lowercase().as_ref() {
             "eq" => Ok(CompOp::Eq),
             "ne" => Ok(CompOp::Ne),
@@ -94,7 +96,7 @@ impl CompOp {
             "le" => Ok(CompOp::Le),
             "ge" => Ok(CompOp::Ge),
             "gt" => Ok(CompOp::Gt),
-            _ => Err(()),
+            _ => Err(Error::UnknownOperator(sign.trim().to_owned())),
         }
     }
 
@@ -126,12 +128,12 @@ impl CompOp {
     /// ```
     pub fn name(&self) -> &str {
         match self {
-            &CompOp::Eq => "eq",
-            &CompOp::Ne => "ne",
-            &CompOp::Lt => "lt",
-            &CompOp::Le => "le",
-            &CompOp::Ge => "ge",
-            &CompOp::Gt => "gt",
+            CompOp::Eq => "eq",
+            CompOp::Ne => "ne",
+            CompOp::Lt => "lt",
+            CompOp::Le => "le",
+            CompOp::Ge => "ge",
+            CompOp::Gt => "gt",
         }
     }
 
@@ -175,12 +177,12 @@ impl CompOp {
     /// ```
     pub fn invert(&self) -> Self {
         match self {
-            &CompOp::Eq => CompOp::Ne,
-            &CompOp::Ne => CompOp::Eq,
-            &CompOp::Lt => CompOp::Ge,
-            &CompOp::Le => CompOp::Gt,
-            &CompOp::Ge => CompOp::Lt,
-            &CompOp::Gt => CompOp::Le,
+            CompOp::Eq => CompOp::Ne,
+            CompOp::Ne => CompOp::Eq,
+            CompOp::Lt => CompOp::Ge,
+            CompOp::Le => CompOp::Gt,
+            CompOp::Ge => CompOp::Lt,
+            CompOp::Gt => CompOp::Le,
         }
     }
 
@@ -224,12 +226,12 @@ impl CompOp {
     /// ```
     pub fn opposite(&self) -> Self {
         match self {
-            &CompOp::Eq => CompOp::Ne,
-            &CompOp::Ne => CompOp::Eq,
-            &CompOp::Lt => CompOp::Gt,
-            &CompOp::Le => CompOp::Ge,
-            &CompOp::Ge => CompOp::Le,
-            &CompOp::Gt => CompOp::Lt,
+            CompOp::Eq => CompOp::Ne,
+            CompOp::Ne => CompOp::Eq,
+            CompOp::Lt => CompOp::Gt,
+            CompOp::Le => CompOp::Ge,
+            CompOp::Ge => CompOp::Le,
+            CompOp::Gt => CompOp::Lt,
         }
     }
 
@@ -273,10 +275,10 @@ impl CompOp {
     /// ```
     pub fn flip(&self) -> Self {
         match self {
-            &CompOp::Lt => CompOp::Gt,
-            &CompOp::Le => CompOp::Ge,
-            &CompOp::Ge => CompOp::Le,
-            &CompOp::Gt => CompOp::Lt,
+            CompOp::Lt => CompOp::Gt,
+            CompOp::Le => CompOp::Ge,
+            CompOp::Ge => CompOp::Le,
+            CompOp::Gt => CompOp::Lt,
             _ => self.clone(),
         }
     }
@@ -307,12 +309,12 @@ impl CompOp {
     /// ```
     pub fn sign(&self) -> &'static str {
         match self {
-            &CompOp::Eq => "==",
-            &CompOp::Ne => "!=",
-            &CompOp::Lt => "<",
-            &CompOp::Le => "<=",
-            &CompOp::Ge => ">=",
-            &CompOp::Gt => ">",
+            CompOp::Eq => "==",
+            CompOp::Ne => "!=",
+            CompOp::Lt => "<",
+            CompOp::Le => "<=",
+            CompOp::Ge => ">=",
+            CompOp::Gt => ">",
         }
     }
 
@@ -338,9 +340,9 @@ impl CompOp {
     /// ```
     pub fn factor(&self) -> i8 {
         match self {
-            &CompOp::Eq | &CompOp::Ne => 0,
-            &CompOp::Lt | &CompOp::Le => -1,
-            &CompOp::Gt | &CompOp::Ge => 1,
+            CompOp::Eq | CompOp::Ne => 0,
+            CompOp::Lt | CompOp::Le => -1,
+            CompOp::Gt | CompOp::Ge => 1,
         }
     }
 
@@ -367,12 +369,23 @@ impl CompOp {
     /// ```
     pub fn ord(&self) -> Option<Ordering> {
         match self {
-            &CompOp::Eq => Some(Ordering::Equal),
-            &CompOp::Lt => Some(Ordering::Less),
-            &CompOp::Gt => Some(Ordering::Greater),
+            CompOp::Eq => Some(Ordering::Equal),
+            CompOp::Lt => Some(Ordering::Less),
+            CompOp::Gt => Some(Ordering::Greater),
             _ => None,
         }
     }
+
+    /// Check whether this operator holds for the given comparison `result`, which must be
+    /// `CompOp::Eq`, `CompOp::Lt` or `CompOp::Gt`.
+    pub(crate) fn holds_for(&self, result: CompOp) -> bool {
+        match result {
+            CompOp::Eq => matches!(self, CompOp::Eq | CompOp::Le | CompOp::Ge),
+            CompOp::Lt => matches!(self, CompOp::Ne | CompOp::Lt | CompOp::Le),
+            CompOp::Gt => matches!(self, CompOp::Ne | CompOp::Gt | CompOp::Ge),
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -381,6 +394,7 @@ mod tests {
     use std::cmp::Ordering;
 
     use super::CompOp;
+    use crate::error::Error;
 
     #[test]
     fn from_sign() {
@@ -397,7 +411,10 @@ mod tests {
 
         // Exceptional cases
         assert_eq!(CompOp::from_sign("  <=  ").unwrap(), CompOp::Le);
-        assert!(CompOp::from_sign("*").is_err());
+        assert_eq!(
+            CompOp::from_sign(" * "),
+            Err(Error::UnknownOperator("*".into()))
+        );
     }
 
     #[test]
@@ -412,7 +429,10 @@ mod tests {
 
         // Exceptional cases
         assert_eq!(CompOp::from_name("  Le  ").unwrap(), CompOp::Le);
-        assert!(CompOp::from_name("abc").is_err());
+        assert_eq!(
+            CompOp::from_name("Abc"),
+            Err(Error::UnknownOperator("Abc".into()))
+        );
     }
 
     #[test]
@@ -0,0 +1,405 @@
+//! Error module, with all errors that may occur when parsing or comparing versions.
+//!
+//! This module provides the `Error` enum, which describes why a version string, comparison
+//! operator or version range was rejected, and the `BatchError` struct for versions in a batch.
+
+use std::error;
+use std::fmt;
+
+use crate::scheme::Scheme;
+
+/// Enum of errors that may occur when parsing or comparing versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The version string has parts, but none of them is numeric, such as `abc`.
+    NoNumber,
+
+    /// The requested part index exceeds the depth of the version.
+    DepthExceeded {
+        /// The requested part index.
+        index: usize,
+
+        /// The number of parts in the version.
+        depth: usize,
+    },
+
+    /// A numeric part is too large to be represented, it doesn't fit in an `u64`.
+    NumberOverflow {
+        /// The byte offset of the numeric part.
+        offset: usize,
+    },
+
+    /// The string contains a character that isn't allowed.
+    InvalidChar {
+        /// The byte offset of the character.
+        offset: usize,
+
+        /// The invalid character.
+        ch: char,
+    },
+
+    /// The version string doesn't follow the format of the version scheme, for example because
+    /// a semver version is missing its patch number.
+    InvalidFormat {
+        /// The version scheme.
+        scheme: Scheme,
+
+        /// The byte offset at which the problem was found.
+        offset: usize,
+    },
+
+    /// The calendar versioning format is malformed, for example because it contains an unknown
+    /// segment or no year.
+    InvalidCalverFormat {
+        /// The byte offset in the format string at which the problem was found.
+        offset: usize,
+    },
+
+    /// The comparison operator isn't recognized.
+    UnknownOperator(String),
+
+    /// The version range is malformed, for example because a comparator is missing its version.
+    InvalidRange {
+        /// The byte offset in the range string at which the problem was found.
+        offset: usize,
+    },
+
+    /// A restriction in the version range can't match any version, because its lower bound is
+    /// greater than its upper bound, such as `[2.0,1.0]`.
+    EmptyRange {
+        /// The byte offset of the restriction in the range string.
+        offset: usize,
+    },
+
+    /// A restriction in the version range overlaps with, or isn't ordered after, the restriction
+    /// before it, such as `[1.0,2.0],[1.5,3.0]`.
+    OverlappingRange {
+        /// The byte offset of the restriction in the range string.
+        offset: usize,
+    },
+
+    /// The version can't be bumped, because it doesn't follow the generic or semver scheme, or
+    /// doesn't start with a numeric part.
+    CannotBump,
+
+    /// The pre-release label to bump to is empty, or contains a character that isn't a letter.
+    InvalidLabel(String),
+
+    /// One of the versions in a comparison couldn't be parsed.
+    Comparison {
+        /// The side of the comparison that failed.
+        side: Side,
+
+        /// The error for the failed version.
+        error: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Shift the byte offset of this error by `offset`.
+    ///
+    /// This is used to report offsets relative to an enclosing string, when the error occurred
+    /// while parsing a substring of it.
+    pub(crate) fn offset_by(self, offset: usize) -> Self {
+        match self {
+            Error::NumberOverflow { offset: o } => Error::NumberOverflow { offset: o + offset },
+            Error::InvalidChar { offset: o, ch } => Error::InvalidChar {
+                offset: o + offset,
+                ch,
+            },
+            Error::InvalidFormat { scheme, offset: o } => Error::InvalidFormat {
+                scheme,
+                offset: o + offset,
+            },
+            Error::InvalidCalverFormat { offset: o } => {
+                Error::InvalidCalverFormat { offset: o + offset }
+            }
+            Error::InvalidRange { offset: o } => Error::InvalidRange { offset: o + offset },
+            Error::EmptyRange { offset: o } => Error::EmptyRange { offset: o + offset },
+            Error::OverlappingRange { offset: o } => Error::OverlappingRange { offset: o + offset },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoNumber => write!(f, "version does not contain a numeric part"),
+            Error::DepthExceeded { index, depth } => write!(
+                f,
+                "part index {} exceeds version depth of {} parts",
+                index, depth
+            ),
+            Error::NumberOverflow { offset } => {
+                write!(f, "numeric part at byte offset {} is too large", offset)
+            }
+            Error::InvalidChar { offset, ch } => {
+                write!(f, "invalid character {:?} at byte offset {}", ch, offset)
+            }
+            Error::InvalidFormat { scheme, offset } => write!(
+                f,
+                "version does not follow the {} scheme at byte offset {}",
+                scheme, offset
+            ),
+            Error::InvalidCalverFormat { offset } => {
+                write!(f, "malformed calver format at byte offset {}", offset)
+            }
+            Error::UnknownOperator(op) => write!(f, "unknown comparison operator {:?}", op),
+            Error::InvalidRange { offset } => {
+                write!(f, "malformed version range at byte offset {}", offset)
+            }
+            Error::EmptyRange { offset } => write!(
+                f,
+                "version range restriction at byte offset {} matches no version",
+                offset
+            ),
+            Error::OverlappingRange { offset } => write!(
+                f,
+                "version range restriction at byte offset {} overlaps the previous one",
+                offset
+            ),
+            Error::CannotBump => write!(
+                f,
+                "version can't be bumped, it must follow the generic or semver scheme and start \
+                 with a numeric part"
+            ),
+            Error::InvalidLabel(label) => write!(
+                f,
+                "invalid pre-release label {:?}, it must only contain letters",
+                label
+            ),
+            Error::Comparison { side, error } => {
+                write!(f, "failed to parse {} version: {}", side, error)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Comparison { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// The side of a comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The left-hand version, `a` in `a < b`.
+    Left,
+
+    /// The right-hand version, `b` in `a < b`.
+    Right,
+}
+
+impl Side {
+    /// Wrap the given `error` as a comparison error for this side.
+    pub(crate) fn wrap(self, error: Error) -> Error {
+        Error::Comparison {
+            side: self,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Side::Left => write!(f, "left"),
+            Side::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// A version string in a batch that failed to parse.
+///
+/// Batch methods of `VersionCompare` skip such versions, and return them along with the result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchError<'a> {
+    /// The index of the version string in the batch.
+    pub index: usize,
+
+    /// The version string that failed to parse.
+    pub version: &'a str,
+
+    /// Why the version string failed to parse.
+    pub error: Error,
+}
+
+impl<'a> fmt::Display for BatchError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "failed to parse version {:?} at index {}: {}",
+            self.version, self.index, self.error
+        )
+    }
+}
+
+impl<'a> error::Error for BatchError<'a> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use crate::scheme::Scheme;
+
+    use super::{BatchError, Error, Side};
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", Error::NoNumber),
+            "version does not contain a numeric part"
+        );
+        assert_eq!(
+            format!("{}", Error::DepthExceeded { index: 3, depth: 2 }),
+            "part index 3 exceeds version depth of 2 parts"
+        );
+        assert_eq!(
+            format!("{}", Error::NumberOverflow { offset: 4 }),
+            "numeric part at byte offset 4 is too large"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InvalidChar {
+                    offset: 1,
+                    ch: '\0'
+                }
+            ),
+            "invalid character '\\0' at byte offset 1"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InvalidFormat {
+                    scheme: Scheme::Semver,
+                    offset: 3
+                }
+            ),
+            "version does not follow the semver scheme at byte offset 3"
+        );
+        assert_eq!(
+            format!("{}", Error::InvalidCalverFormat { offset: 5 }),
+            "malformed calver format at byte offset 5"
+        );
+        assert_eq!(
+            format!("{}", Error::UnknownOperator("=>".into())),
+            "unknown comparison operator \"=>\""
+        );
+        assert_eq!(
+            format!("{}", Error::InvalidRange { offset: 2 }),
+            "malformed version range at byte offset 2"
+        );
+        assert_eq!(
+            format!("{}", Error::EmptyRange { offset: 0 }),
+            "version range restriction at byte offset 0 matches no version"
+        );
+        assert_eq!(
+            format!("{}", Error::OverlappingRange { offset: 10 }),
+            "version range restriction at byte offset 10 overlaps the previous one"
+        );
+        assert_eq!(
+            format!("{}", Error::CannotBump),
+            "version can't be bumped, it must follow the generic or semver scheme and start with \
+             a numeric part"
+        );
+        assert_eq!(
+            format!("{}", Error::InvalidLabel("rc1".into())),
+            "invalid pre-release label \"rc1\", it must only contain letters"
+        );
+        assert_eq!(
+            format!("{}", Side::Right.wrap(Error::NoNumber)),
+            "failed to parse right version: version does not contain a numeric part"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                BatchError {
+                    index: 2,
+                    version: "abc",
+                    error: Error::NoNumber,
+                }
+            ),
+            "failed to parse version \"abc\" at index 2: version does not contain a numeric part"
+        );
+    }
+
+    #[test]
+    fn source() {
+        assert!(Error::NoNumber.source().is_none());
+        assert_eq!(
+            Side::Left
+                .wrap(Error::NoNumber)
+                .source()
+                .unwrap()
+                .to_string(),
+            Error::NoNumber.to_string()
+        );
+        assert_eq!(
+            BatchError {
+                index: 0,
+                version: "abc",
+                error: Error::NoNumber,
+            }
+            .source()
+            .unwrap()
+            .to_string(),
+            Error::NoNumber.to_string()
+        );
+    }
+
+    #[test]
+    fn offset_by() {
+        assert_eq!(
+            Error::NumberOverflow { offset: 1 }.offset_by(2),
+            Error::NumberOverflow { offset: 3 }
+        );
+        assert_eq!(
+            Error::InvalidChar {
+                offset: 0, ch: '€'
+            }
+            .offset_by(5),
+            Error::InvalidChar {
+                offset: 5, ch: '€'
+            }
+        );
+        assert_eq!(
+            Error::InvalidRange { offset: 0 }.offset_by(5),
+            Error::InvalidRange { offset: 5 }
+        );
+        assert_eq!(
+            Error::InvalidCalverFormat { offset: 1 }.offset_by(5),
+            Error::InvalidCalverFormat { offset: 6 }
+        );
+        assert_eq!(
+            Error::EmptyRange { offset: 1 }.offset_by(5),
+            Error::EmptyRange { offset: 6 }
+        );
+        assert_eq!(
+            Error::OverlappingRange { offset: 1 }.offset_by(5),
+            Error::OverlappingRange { offset: 6 }
+        );
+        assert_eq!(
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 1
+            }
+            .offset_by(5),
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 6
+            }
+        );
+        assert_eq!(Error::NoNumber.offset_by(5), Error::NoNumber);
+    }
+}
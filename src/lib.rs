@@ -20,9 +20,10 @@
 //! - `3.10.4.1`
 //! - `1.2.alpha`
 //! - `1.2.dev.4`
+//! - `v1.2.3`, `release-1.2`
 //! - ` ` _(empty)_
 //! - ` .   -32 . 1` _(undefined formats)_
-//! - `MyApp 3.2.0 / build 0932` _(complex formats, not fully functional yet)_
+//! - `MyApp 3.2.0 / build 0932` _(prefixes are stripped, suffixes are compared last)_
 //! - _Many more and support for custom formats to come..._
 //!
 //! ### Semver
@@ -30,20 +31,32 @@
 //! there are too many projects however that don't follow such format.
 //!
 //! Version-compare makes working with them easy and supports semver formats out of the box with zero configuration.
+//! When strict semver parsing and precedence is required, including pre-release identifiers and build metadata,
+//! versions can be parsed using the `Scheme::Semver` version scheme.
 //!
 //! ## Features
 //! * Compare two version numbers, get: `<`, `==` or `>`.
 //! * Compare two version numbers against any comparison operator, get `true` or `false`.
 //! * Parse complex version numbers.
+//! * Compare text parts, such as pre-release tags: `dev` < `alpha` < `beta` < `rc` < `release`.
+//! * Strict [semver](https://semver.org/) parsing and precedence, through the `Scheme::Semver` version scheme.
+//! * [PEP 440](https://peps.python.org/pep-0440/) parsing, normalization and ordering for Python package versions, through the `Scheme::Pep440` version scheme.
+//! * Debian version comparison, exactly like `dpkg --compare-versions`, through the `Scheme::Debian` version scheme.
+//! * RPM version comparison, like `rpmdev-vercmp`, through the `Scheme::Rpm` version scheme.
+//! * Maven version comparison, like `ComparableVersion`, through the `Scheme::Maven` version scheme.
+//! * [Calendar versioning](https://calver.org/) with formats such as `YYYY.0M.MICRO` or `YY.0M`, validated dates and comparison across short and full years, through the `Scheme::Calver` version scheme.
+//! * Version ranges with operators, [npm-style](https://docs.npmjs.com/misc/semver), such as `>=1.2, <2.0` or `^1.4`, and tests against them.
+//! * [Maven-style](https://maven.apache.org/enforcer/enforcer-rules/versionRanges.html) version ranges, such as `[1.0,2.0)` or `(,1.0],[1.2,)`, through `MavenRange`.
+//! * [Cargo](https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html) version requirements, such as `^0.3` or `>=1.2, <1.5`, matching exactly like Cargo through `CargoReq`.
+//! * Find versions in free-form text, such as `git version 2.43.0`, along with their byte spans through `Version::find_all`.
+//! * Bump versions to the next major, minor, patch or pre-release version, such as `v1.2.0-rc.1` to `v1.2.0-rc.2`, keeping their prefix and separators.
 //! * Static, single-statement methods available.
+//! * Batch methods to sort, deduplicate, group and filter lists of version strings, reporting the versions that fail to parse.
+//! * Optional [serde](https://serde.rs/) support through the `serde` feature, to store versions as strings and load a `VersionManifest` from configuration files.
 //!
 //! The following features will be added in a later version:
 //!
-//! * Support for text parts in version strings.
 //! * Version manifest, to specify detailed version number constraints.
-//! * Version ranges, and tests against them.
-//! * Support for operators in version strings, [npm-style](https://docs.npmjs.com/misc/semver), and tests against them.
-//! * Batch comparisons.
 //!
 //! ## Examples
 //! Check out the [examples](https://github.com/timvisee/version-compare/tree/master/examples) directory for all available examples.
@@ -51,18 +64,32 @@
 //!
 //! _[View complete README](https://github.com/timvisee/version-compare/blob/master/README.md)_
 
+pub mod cargo_req;
 pub mod comp_op;
+pub mod error;
+pub mod maven_range;
+pub mod scheme;
 pub mod version;
 pub mod version_compare;
 pub mod version_manifest;
 pub mod version_part;
+pub mod version_range;
 
+mod bump;
+mod part_vec;
+#[cfg(feature = "serde")]
+mod serde_impl;
 #[cfg(test)]
 mod test;
 
 // Reexports
+pub use crate::cargo_req::CargoReq;
 pub use crate::comp_op::CompOp;
+pub use crate::error::{BatchError, Error};
+pub use crate::maven_range::MavenRange;
+pub use crate::scheme::{CalverDate, CalverFormat, Scheme};
 pub use crate::version::Version;
-pub use crate::version_compare::VersionCompare;
-pub use crate::version_manifest::VersionManifest;
+pub use crate::version_compare::{BatchResult, VersionCompare, VersionGroups};
+pub use crate::version_manifest::{CharPolicy, VersionManifest};
 pub use crate::version_part::VersionPart;
+pub use crate::version_range::VersionRange;
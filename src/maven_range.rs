@@ -0,0 +1,419 @@
+//! Maven range module, which provides the `MavenRange` struct as parsed Maven range
+//! representation.
+//!
+//! A Maven range is a union of intervals, such as `[1.0,2.0)` or `(,1.0],[1.2,)`, as used for
+//! dependency versions in Maven POM files. Ranges are tested against a `Version` using
+//! `MavenRange::contains`.
+
+use std::fmt;
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::Scheme;
+use crate::version::Version;
+use crate::version_range::{offset_in, Comparator};
+
+/// Maven range struct, which is a representation for a parsed Maven version range string.
+///
+/// A range string consists of one or more restrictions separated by commas. A version is
+/// contained in the range if it's contained in any of its restrictions. The following
+/// restrictions are supported:
+///
+/// * `[1.0,2.0]`, `(1.0,2.0)`: any version between the bounds, a square bracket includes the
+///   bound, a parenthesis excludes it.
+/// * `[1.0,)`, `(,2.0]`: any version above or below a bound, an empty bound is unbounded.
+/// * `[1.2]`: exactly the given version.
+///
+/// A range string without brackets, such as `1.0`, is a soft requirement: it recommends a
+/// version, but any version is contained in it.
+///
+/// Versions in the range are parsed with the Maven scheme. Versions tested against the range
+/// should use the Maven scheme too, otherwise they're compared with the generic rules.
+///
+/// The original range string is stored in the struct, and can be accessed using the
+/// `range.as_str()` method.
+#[derive(Debug)]
+pub struct MavenRange<'a> {
+    range: &'a str,
+    restrictions: Vec<Restriction<'a>>,
+    recommended: Option<Version<'a>>,
+}
+
+impl<'a> MavenRange<'a> {
+    /// Create a `MavenRange` instance from a range string.
+    ///
+    /// An error is returned if the range string couldn't be parsed, if a restriction can't
+    /// contain any version, or if restrictions overlap or aren't in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{MavenRange, Scheme, Version};
+    ///
+    /// let range = MavenRange::from("[1.0,2.0)").unwrap();
+    ///
+    /// assert!(range.contains(&Version::from_scheme("1.5-SNAPSHOT", Scheme::Maven).unwrap()));
+    /// assert!(!range.contains(&Version::from_scheme("2.0", Scheme::Maven).unwrap()));
+    /// assert!(MavenRange::from("[2.0,1.0]").is_err());
+    /// ```
+    pub fn from(range: &'a str) -> Result<Self, Error> {
+        let mut restrictions: Vec<Restriction<'a>> = Vec::new();
+
+        // Parse each restriction, optionally separated by a comma
+        let mut rest = range.trim_start();
+        while rest.starts_with(['[', '(']) {
+            let end = rest.find([']', ')']).ok_or(Error::InvalidRange {
+                offset: range.len(),
+            })?;
+            let restriction = Restriction::parse(range, &rest[..=end])?;
+
+            // Restrictions must be in ascending order, without overlap
+            if let Some(previous) = restrictions.last() {
+                if !previous.is_before(&restriction) {
+                    return Err(Error::OverlappingRange {
+                        offset: offset_in(range, rest),
+                    });
+                }
+            }
+            restrictions.push(restriction);
+
+            rest = rest[end + 1..].trim_start();
+            if let Some(next) = rest.strip_prefix(',') {
+                rest = next.trim_start();
+                if !rest.starts_with(['[', '(']) {
+                    return Err(Error::InvalidRange {
+                        offset: offset_in(range, rest),
+                    });
+                }
+            }
+        }
+
+        // Parse a soft requirement, which can't be combined with restrictions
+        let rest = rest.trim_end();
+        let recommended = match (rest.is_empty(), restrictions.is_empty()) {
+            (true, false) => None,
+            (false, true) => Some(parse_version(range, rest)?),
+            _ => {
+                return Err(Error::InvalidRange {
+                    offset: offset_in(range, rest),
+                })
+            }
+        };
+
+        Ok(MavenRange {
+            range,
+            restrictions,
+            recommended,
+        })
+    }
+
+    /// Get the original range string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::MavenRange;
+    ///
+    /// let range = MavenRange::from("(,1.0],[1.2,)").unwrap();
+    ///
+    /// assert_eq!(range.as_str(), "(,1.0],[1.2,)");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.range
+    }
+
+    /// Get the restrictions of this range.
+    ///
+    /// The list is empty if the range is a soft requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, MavenRange};
+    ///
+    /// let range = MavenRange::from("(,1.0],[1.2,)").unwrap();
+    /// let restrictions = range.restrictions();
+    ///
+    /// assert_eq!(restrictions.len(), 2);
+    /// assert!(restrictions[0].lower().is_none());
+    /// assert_eq!(restrictions[0].upper().unwrap().op(), &CompOp::Le);
+    /// assert_eq!(restrictions[1].lower().unwrap().op(), &CompOp::Ge);
+    /// ```
+    pub fn restrictions(&self) -> &Vec<Restriction<'a>> {
+        &self.restrictions
+    }
+
+    /// Get the recommended version, if this range is a soft requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::MavenRange;
+    ///
+    /// assert_eq!(MavenRange::from("1.0").unwrap().recommended().unwrap().as_str(), "1.0");
+    /// assert!(MavenRange::from("[1.0]").unwrap().recommended().is_none());
+    /// ```
+    pub fn recommended(&self) -> Option<&Version<'a>> {
+        self.recommended.as_ref()
+    }
+
+    /// Check whether the given `version` is contained in this range.
+    ///
+    /// Any version is contained in a soft requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{MavenRange, Scheme, Version};
+    ///
+    /// let range = MavenRange::from("(,1.0],[1.2,)").unwrap();
+    ///
+    /// assert!(range.contains(&Version::from_scheme("1.0.Final", Scheme::Maven).unwrap()));
+    /// assert!(!range.contains(&Version::from_scheme("1.1", Scheme::Maven).unwrap()));
+    /// assert!(range.contains(&Version::from_scheme("1.2-sp1", Scheme::Maven).unwrap()));
+    /// ```
+    pub fn contains(&self, version: &Version) -> bool {
+        self.recommended.is_some()
+            || self
+                .restrictions
+                .iter()
+                .any(|restriction| restriction.contains(version))
+    }
+}
+
+impl<'a> fmt::Display for MavenRange<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.range)
+    }
+}
+
+/// A single restriction in a Maven range, such as `[1.0,2.0)`.
+///
+/// The lower bound uses the `Ge` or `Gt` operator, the upper bound uses `Le` or `Lt`. A missing
+/// bound is unbounded.
+#[derive(Debug)]
+pub struct Restriction<'a> {
+    lower: Option<Comparator<'a>>,
+    upper: Option<Comparator<'a>>,
+}
+
+impl<'a> Restriction<'a> {
+    /// Parse a restriction, including its brackets.
+    ///
+    /// The `spec` must be a slice of `range`, which is used to report error offsets.
+    fn parse(range: &'a str, spec: &'a str) -> Result<Self, Error> {
+        let offset = offset_in(range, spec);
+        let lower_inclusive = spec.starts_with('[');
+        let upper_inclusive = spec.ends_with(']');
+        let inner = &spec[1..spec.len() - 1];
+
+        // Parse a single version, which must be inclusive
+        let (lower, upper) = match inner.split_once(',') {
+            None => {
+                if !lower_inclusive || !upper_inclusive || inner.trim().is_empty() {
+                    return Err(Error::InvalidRange { offset });
+                }
+                let version = parse_version(range, inner.trim())?;
+                return Ok(Restriction {
+                    lower: Some(Comparator::new(CompOp::Ge, version.clone())),
+                    upper: Some(Comparator::new(CompOp::Le, version)),
+                });
+            }
+            Some((_, upper)) if upper.contains(',') => {
+                return Err(Error::InvalidRange { offset });
+            }
+            Some((lower, upper)) => (lower.trim(), upper.trim()),
+        };
+        let lower = match lower {
+            "" => None,
+            lower => Some(parse_version(range, lower)?),
+        };
+        let upper = match upper {
+            "" => None,
+            upper => Some(parse_version(range, upper)?),
+        };
+
+        // The lower bound must be below the upper bound, or equal to it if both are inclusive
+        if let (Some(lower), Some(upper)) = (&lower, &upper) {
+            match lower.compare(upper) {
+                CompOp::Lt => {}
+                CompOp::Eq if lower_inclusive && upper_inclusive => {}
+                _ => return Err(Error::EmptyRange { offset }),
+            }
+        }
+
+        let lower_op = if lower_inclusive {
+            CompOp::Ge
+        } else {
+            CompOp::Gt
+        };
+        let upper_op = if upper_inclusive {
+            CompOp::Le
+        } else {
+            CompOp::Lt
+        };
+        Ok(Restriction {
+            lower: lower.map(|version| Comparator::new(lower_op, version)),
+            upper: upper.map(|version| Comparator::new(upper_op, version)),
+        })
+    }
+
+    /// Get the lower bound, `None` if unbounded.
+    pub fn lower(&self) -> Option<&Comparator<'a>> {
+        self.lower.as_ref()
+    }
+
+    /// Get the upper bound, `None` if unbounded.
+    pub fn upper(&self) -> Option<&Comparator<'a>> {
+        self.upper.as_ref()
+    }
+
+    /// Check whether the given `version` is contained in this restriction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{MavenRange, Scheme, Version};
+    ///
+    /// let range = MavenRange::from("[1.0,2.0)").unwrap();
+    ///
+    /// assert!(range.restrictions()[0].contains(&Version::from_scheme("1.0", Scheme::Maven).unwrap()));
+    /// ```
+    pub fn contains(&self, version: &Version) -> bool {
+        self.lower
+            .iter()
+            .chain(&self.upper)
+            .all(|bound| bound.matches(version))
+    }
+
+    /// Check whether this restriction ends before the `next` restriction starts.
+    ///
+    /// Restrictions may share a bound, as in `[1.0,2.0],[2.0,3.0]`.
+    fn is_before(&self, next: &Restriction) -> bool {
+        match (&self.upper, &next.lower) {
+            (Some(upper), Some(lower)) => lower.version().compare(upper.version()) != CompOp::Lt,
+            _ => false,
+        }
+    }
+}
+
+/// Parse a version of a range string with the Maven scheme.
+///
+/// The `version` must be a slice of `range`, which is used to report error offsets.
+fn parse_version<'a>(range: &'a str, version: &'a str) -> Result<Version<'a>, Error> {
+    Version::from_scheme(version, Scheme::Maven)
+        .map_err(|err| err.offset_by(offset_in(range, version)))
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_maven_range::{TEST_MAVEN_RANGES, TEST_MAVEN_RANGES_ERROR};
+    use crate::version::Version;
+
+    use super::MavenRange;
+
+    #[test]
+    fn from() {
+        // Test whether parsing works for each test range
+        for entry in TEST_MAVEN_RANGES {
+            assert!(MavenRange::from(entry.0).is_ok(), "Parsing {}", entry.0);
+        }
+
+        // Test whether parsing fails for each invalid range
+        for range in TEST_MAVEN_RANGES_ERROR {
+            assert!(MavenRange::from(range).is_err(), "Parsing {}", range);
+        }
+    }
+
+    #[test]
+    fn from_error() {
+        assert_eq!(
+            MavenRange::from("[1.0,2.0").unwrap_err(),
+            Error::InvalidRange { offset: 8 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,2.0] (3.0)").unwrap_err(),
+            Error::InvalidRange { offset: 10 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,2.0],").unwrap_err(),
+            Error::InvalidRange { offset: 10 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,2.0] 3.0").unwrap_err(),
+            Error::InvalidRange { offset: 10 }
+        );
+        assert_eq!(
+            MavenRange::from("(,1.0],[2.0,1.5]").unwrap_err(),
+            Error::EmptyRange { offset: 7 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,1.0)").unwrap_err(),
+            Error::EmptyRange { offset: 0 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,2.0],[1.5,3.0]").unwrap_err(),
+            Error::OverlappingRange { offset: 10 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0,),[2.0,3.0]").unwrap_err(),
+            Error::OverlappingRange { offset: 7 }
+        );
+        assert_eq!(
+            MavenRange::from("[1.0, 99999999999999999999]").unwrap_err(),
+            Error::NumberOverflow { offset: 6 }
+        );
+    }
+
+    #[test]
+    fn as_str() {
+        for entry in TEST_MAVEN_RANGES {
+            assert_eq!(MavenRange::from(entry.0).unwrap().as_str(), entry.0);
+        }
+    }
+
+    #[test]
+    fn restrictions() {
+        let range = MavenRange::from("(1.0,2.0], [2.0]").unwrap();
+        let restrictions = range.restrictions();
+
+        assert_eq!(restrictions.len(), 2);
+        assert_eq!(restrictions[0].lower().unwrap().op(), &CompOp::Gt);
+        assert_eq!(restrictions[0].upper().unwrap().op(), &CompOp::Le);
+        assert_eq!(restrictions[1].lower().unwrap().op(), &CompOp::Ge);
+        assert_eq!(restrictions[1].upper().unwrap().version().as_str(), "2.0");
+        assert!(range.recommended().is_none());
+
+        let range = MavenRange::from("1.0").unwrap();
+        assert!(range.restrictions().is_empty());
+        assert_eq!(range.recommended().unwrap().scheme(), &Scheme::Maven);
+    }
+
+    #[test]
+    fn contains() {
+        for entry in TEST_MAVEN_RANGES {
+            let range = MavenRange::from(entry.0).unwrap();
+            let version = Version::from_scheme(entry.1, Scheme::Maven).unwrap();
+
+            assert_eq!(
+                range.contains(&version),
+                entry.2,
+                "Testing that {} is contained in {}",
+                entry.1,
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", MavenRange::from("[1.0,2.0)").unwrap()),
+            "[1.0,2.0)"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Part list module.
+//!
+//! A module that provides the `PartVec` list, which holds the parts of a parsed `Version`. Short
+//! versions are stored inline, so parsing them doesn't allocate.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::vec;
+
+use crate::version_part::VersionPart;
+
+/// Number of parts that are stored inline, before moving them to the heap.
+const INLINE_CAPACITY: usize = 4;
+
+/// Part used to fill the unused inline slots.
+const EMPTY: VersionPart<'static> = VersionPart::Number(0);
+
+/// List of version parts, that stores a few parts inline before allocating.
+///
+/// Most versions have four parts or less, such as `1.2.3-rc1`, so parsing them doesn't allocate.
+/// Unlike a `SmallVec`, this list is covariant over the lifetime of its parts, so a
+/// `Version<'static>` can still be used where a `Version<'a>` is expected.
+#[derive(Clone)]
+pub(crate) enum PartVec<'a> {
+    /// Parts stored inline, with the number of slots used.
+    Inline([VersionPart<'a>; INLINE_CAPACITY], usize),
+
+    /// Parts stored on the heap.
+    Heap(Vec<VersionPart<'a>>),
+}
+
+impl<'a> PartVec<'a> {
+    /// Create an empty list.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        PartVec::Inline([EMPTY; INLINE_CAPACITY], 0)
+    }
+
+    /// Add the given part to the end of the list.
+    ///
+    /// The parts are moved to the heap when the inline slots are full.
+    #[inline]
+    pub(crate) fn push(&mut self, part: VersionPart<'a>) {
+        match self {
+            PartVec::Inline(parts, len) if *len < INLINE_CAPACITY => {
+                parts[*len] = part;
+                *len += 1;
+            }
+            PartVec::Inline(..) => self.spill(part),
+            PartVec::Heap(parts) => parts.push(part),
+        }
+    }
+
+    /// Move the inline parts to the heap, and add the given part to the end.
+    #[cold]
+    fn spill(&mut self, part: VersionPart<'a>) {
+        let mut heap = Vec::with_capacity(INLINE_CAPACITY * 2);
+        heap.extend(self.iter_mut().map(|part| mem::replace(part, EMPTY)));
+        heap.push(part);
+        *self = PartVec::Heap(heap);
+    }
+
+    /// Take ownership of all borrowed parts, so the list doesn't borrow anymore.
+    pub(crate) fn into_owned(self) -> PartVec<'static> {
+        match self {
+            PartVec::Heap(parts) => {
+                PartVec::Heap(parts.into_iter().map(VersionPart::into_owned).collect())
+            }
+            PartVec::Inline(parts, len) => IntoIterator::into_iter(parts)
+                .take(len)
+                .map(VersionPart::into_owned)
+                .collect(),
+        }
+    }
+}
+
+impl<'a> Default for PartVec<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Deref for PartVec<'a> {
+    type Target = [VersionPart<'a>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            PartVec::Inline(parts, len) => &parts[..*len],
+            PartVec::Heap(parts) => parts,
+        }
+    }
+}
+
+impl<'a> DerefMut for PartVec<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            PartVec::Inline(parts, len) => &mut parts[..*len],
+            PartVec::Heap(parts) => parts,
+        }
+    }
+}
+
+/// Keep the given vector on the heap, it's already allocated.
+impl<'a> From<Vec<VersionPart<'a>>> for PartVec<'a> {
+    fn from(parts: Vec<VersionPart<'a>>) -> Self {
+        PartVec::Heap(parts)
+    }
+}
+
+impl<'a> FromIterator<VersionPart<'a>> for PartVec<'a> {
+    fn from_iter<I: IntoIterator<Item = VersionPart<'a>>>(iter: I) -> Self {
+        let mut parts = Self::new();
+        for part in iter {
+            parts.push(part);
+        }
+        parts
+    }
+}
+
+impl<'a> IntoIterator for PartVec<'a> {
+    type Item = VersionPart<'a>;
+    type IntoIter = vec::IntoIter<VersionPart<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            PartVec::Inline(parts, len) => {
+                let mut parts = Vec::from(parts);
+                parts.truncate(len);
+                parts.into_iter()
+            }
+            PartVec::Heap(parts) => parts.into_iter(),
+        }
+    }
+}
+
+/// Compare the used parts only, regardless of where they're stored.
+impl<'a> PartialEq for PartVec<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<'a> fmt::Debug for PartVec<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::version_part::VersionPart;
+
+    use super::{PartVec, INLINE_CAPACITY};
+
+    #[test]
+    fn push() {
+        let mut parts = PartVec::new();
+        assert!(parts.is_empty());
+
+        // Parts are stored inline until the inline slots are full
+        for number in 0..INLINE_CAPACITY as u64 {
+            parts.push(VersionPart::Number(number));
+            assert!(matches!(parts, PartVec::Inline(..)));
+        }
+        parts.push(VersionPart::Text(Cow::Borrowed("rc1")));
+        assert!(matches!(parts, PartVec::Heap(..)));
+
+        assert_eq!(
+            &*parts,
+            &[
+                VersionPart::Number(0),
+                VersionPart::Number(1),
+                VersionPart::Number(2),
+                VersionPart::Number(3),
+                VersionPart::Text(Cow::Borrowed("rc1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn eq() {
+        let inline: PartVec = vec![VersionPart::Number(1), VersionPart::Number(2)]
+            .into_iter()
+            .collect();
+        let heap = PartVec::from(vec![VersionPart::Number(1), VersionPart::Number(2)]);
+        assert!(matches!(inline, PartVec::Inline(..)));
+        assert!(matches!(heap, PartVec::Heap(..)));
+        assert_eq!(inline, heap);
+
+        assert_ne!(inline, PartVec::from(vec![VersionPart::Number(1)]));
+    }
+
+    #[test]
+    fn into_owned() {
+        let text = String::from("alpha");
+        let parts: PartVec = vec![
+            VersionPart::Number(1),
+            VersionPart::Text(Cow::Borrowed(&text)),
+        ]
+        .into_iter()
+        .collect();
+        let owned = parts.clone().into_owned();
+        drop(text);
+
+        assert!(matches!(owned, PartVec::Inline(_, 2)));
+        assert_eq!(owned[1], VersionPart::Text(Cow::Owned("alpha".into())));
+    }
+
+    #[test]
+    fn into_iter() {
+        let parts: PartVec = (1..=6).map(VersionPart::Number).collect();
+        assert_eq!(parts.len(), 6);
+        assert_eq!(
+            parts.into_iter().collect::<Vec<_>>(),
+            (1..=6).map(VersionPart::Number).collect::<Vec<_>>()
+        );
+
+        let parts: PartVec = (1..=2).map(VersionPart::Number).collect();
+        assert_eq!(parts.into_iter().count(), 2);
+    }
+}
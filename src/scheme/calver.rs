@@ -0,0 +1,667 @@
+//! Calendar versioning scheme, for versions based on the release date.
+//!
+//! The format of versions is described by a [`CalverFormat`], using the conventions of
+//! [calver.org](https://calver.org/), such as `YYYY.0M.MICRO`.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::Scheme;
+use crate::version::{Parsed, Version};
+use crate::version_part::VersionPart;
+
+/// Characters that separate the segments of a format and version.
+const SEPARATORS: &[char] = &['.', '-', '_'];
+
+/// The year short years are relative to, so `24` is `2024`.
+const SHORT_YEAR_BASE: u16 = 2000;
+
+/// A segment of a calendar version format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// Full year, such as `2024`.
+    FullYear,
+
+    /// Short year since 2000, such as `6`, `24` or `106`.
+    ShortYear,
+
+    /// Zero-padded year since 2000, such as `06`, `24` or `106`.
+    PaddedYear,
+
+    /// Short month, `1` to `12`.
+    ShortMonth,
+
+    /// Zero-padded month, `01` to `12`.
+    PaddedMonth,
+
+    /// Short week of the year, `1` to `53`.
+    ShortWeek,
+
+    /// Zero-padded week of the year, `01` to `53`.
+    PaddedWeek,
+
+    /// Short day of the month, `1` to `31`.
+    ShortDay,
+
+    /// Zero-padded day of the month, `01` to `31`.
+    PaddedDay,
+
+    /// A major, minor or micro number.
+    Number,
+
+    /// A text modifier, such as `dev` or `rc1`.
+    Modifier,
+}
+
+/// The names of the tokens in a format string.
+///
+/// Longer names must be listed first, as the name of a token is matched as a prefix.
+const TOKENS: &[(&str, Token)] = &[
+    ("YYYY", Token::FullYear),
+    ("YY", Token::ShortYear),
+    ("0Y", Token::PaddedYear),
+    ("MM", Token::ShortMonth),
+    ("0M", Token::PaddedMonth),
+    ("WW", Token::ShortWeek),
+    ("0W", Token::PaddedWeek),
+    ("DD", Token::ShortDay),
+    ("0D", Token::PaddedDay),
+    ("MAJOR", Token::Number),
+    ("MINOR", Token::Number),
+    ("MICRO", Token::Number),
+    ("MODIFIER", Token::Modifier),
+];
+
+/// The year tokens.
+const YEARS: &[Token] = &[Token::FullYear, Token::ShortYear, Token::PaddedYear];
+
+/// The month tokens.
+const MONTHS: &[Token] = &[Token::ShortMonth, Token::PaddedMonth];
+
+/// The week tokens.
+const WEEKS: &[Token] = &[Token::ShortWeek, Token::PaddedWeek];
+
+/// The day tokens.
+const DAYS: &[Token] = &[Token::ShortDay, Token::PaddedDay];
+
+impl Token {
+    /// Check whether this token is part of the date.
+    fn is_date(self) -> bool {
+        !matches!(self, Token::Number | Token::Modifier)
+    }
+}
+
+/// The format of calendar versions, such as `YYYY.0M.MICRO`.
+///
+/// A format consists of the following segments, separated by `.`, `-` or `_`:
+///
+/// - `YYYY`: full year, such as `2024`.
+/// - `YY` and `0Y`: short and zero-padded year since 2000, such as `24` or `06`.
+/// - `MM` and `0M`: short and zero-padded month, such as `4` or `04`.
+/// - `WW` and `0W`: short and zero-padded week of the year, such as `9` or `09`.
+/// - `DD` and `0D`: short and zero-padded day of the month, such as `5` or `05`.
+/// - `MAJOR`, `MINOR` and `MICRO`: a number.
+/// - `MODIFIER`: a text tag such as `dev` or `rc1`, only allowed as last segment.
+///
+/// A format must contain a year. A day requires a month, and a week can't be combined with a
+/// month or day. Segments that follow all date segments are optional in versions, a missing
+/// number is equal to zero and a version with a modifier is less than the same version without
+/// one.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{CalverFormat, Scheme, Version};
+///
+/// let format = CalverFormat::from("YYYY.0M.MICRO").unwrap();
+/// let ver = Version::from_scheme("2024.03.1", Scheme::Calver(format)).unwrap();
+///
+/// assert_eq!(ver.date().unwrap().month, Some(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalverFormat {
+    /// The format string.
+    format: String,
+
+    /// The segments, with the separator that precedes them.
+    tokens: Vec<(Option<char>, Token)>,
+}
+
+impl CalverFormat {
+    /// Parse the given format string.
+    ///
+    /// An error is returned if the format contains an unknown segment, or if its date segments
+    /// are invalid, for example because it has no year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CalverFormat, Error};
+    ///
+    /// assert!(CalverFormat::from("YY.0M").is_ok());
+    /// assert_eq!(
+    ///     CalverFormat::from("YYYY.MONTH").unwrap_err(),
+    ///     Error::InvalidCalverFormat { offset: 5 },
+    /// );
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from(format: &str) -> Result<Self, Error> {
+        let mut tokens: Vec<(Option<char>, Token)> = Vec::new();
+        let mut rest = format;
+        let mut separator = None;
+
+        // Parse each segment, followed by a separator or the end of the format
+        loop {
+            let offset = format.len() - rest.len();
+            let &(name, token) = TOKENS
+                .iter()
+                .find(|(name, _)| rest.starts_with(name))
+                .ok_or(Error::InvalidCalverFormat { offset })?;
+
+            // Check whether the segment is allowed after the previous segments
+            let has = |group: &[Token]| tokens.iter().any(|(_, t)| group.contains(t));
+            let valid = match token {
+                Token::FullYear | Token::ShortYear | Token::PaddedYear => !has(YEARS),
+                Token::ShortMonth | Token::PaddedMonth => !has(MONTHS) && !has(WEEKS),
+                Token::ShortWeek | Token::PaddedWeek => !has(WEEKS) && !has(MONTHS) && !has(DAYS),
+                Token::ShortDay | Token::PaddedDay => has(MONTHS) && !has(DAYS),
+                Token::Number | Token::Modifier => true,
+            };
+            let is_last_modifier = tokens.last().is_some_and(|(_, t)| *t == Token::Modifier);
+            if !valid || is_last_modifier {
+                return Err(Error::InvalidCalverFormat { offset });
+            }
+            tokens.push((separator, token));
+            rest = &rest[name.len()..];
+
+            match rest.chars().next() {
+                None => break,
+                Some(c) if SEPARATORS.contains(&c) => {
+                    separator = Some(c);
+                    rest = &rest[1..];
+                }
+                Some(_) => {
+                    return Err(Error::InvalidCalverFormat {
+                        offset: format.len() - rest.len(),
+                    })
+                }
+            }
+        }
+
+        // A format must contain a year
+        if !tokens.iter().any(|(_, t)| YEARS.contains(t)) {
+            return Err(Error::InvalidCalverFormat { offset: 0 });
+        }
+
+        Ok(CalverFormat {
+            format: format.to_owned(),
+            tokens,
+        })
+    }
+
+    /// Get the format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::CalverFormat;
+    ///
+    /// assert_eq!(CalverFormat::from("YYYY.0M.MICRO").unwrap().as_str(), "YYYY.0M.MICRO");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+}
+
+impl fmt::Display for CalverFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format)
+    }
+}
+
+/// The date of a calendar version.
+///
+/// Short years are relative to 2000, so the year of `24.04` is `2024`. Fields that aren't part
+/// of the format are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalverDate {
+    /// The full year.
+    pub year: u16,
+
+    /// The month, `1` to `12`.
+    pub month: Option<u8>,
+
+    /// The week of the year, `1` to `53`.
+    pub week: Option<u8>,
+
+    /// The day of the month, `1` to `31`.
+    pub day: Option<u8>,
+}
+
+/// A parsed calendar version.
+#[derive(Debug, Clone)]
+pub(crate) struct Calver<'a> {
+    /// The date of the version.
+    pub(crate) date: CalverDate,
+
+    /// The numbers of the version in order of the format, with full years.
+    numbers: Vec<u64>,
+
+    /// The modifier, if specified.
+    modifier: Option<Cow<'a, str>>,
+}
+
+impl<'a> Calver<'a> {
+    /// Parse the given version string, following `format`.
+    ///
+    /// Segments that follow all date segments of the format may be omitted, along with their
+    /// separator. An error is returned if the version doesn't follow the format, or if its date
+    /// is invalid.
+    pub(crate) fn parse(version: &'a str, format: &CalverFormat) -> Result<Self, Error> {
+        let invalid = |offset| Error::InvalidFormat {
+            scheme: Scheme::Calver(format.clone()),
+            offset,
+        };
+
+        // Segments after the last date segment are optional
+        let required = format
+            .tokens
+            .iter()
+            .rposition(|(_, token)| token.is_date())
+            .map_or(0, |index| index + 1);
+
+        let mut date = CalverDate {
+            year: 0,
+            month: None,
+            week: None,
+            day: None,
+        };
+        let mut day_offset = 0;
+        let mut numbers = Vec::new();
+        let mut modifier = None;
+        let mut offset = 0;
+
+        // Parse each segment of the format
+        for (index, &(separator, token)) in format.tokens.iter().enumerate() {
+            // Skip optional segments that aren't in the version
+            let present = match separator {
+                Some(separator) => version[offset..].starts_with(separator),
+                None => offset < version.len(),
+            };
+            if !present && index >= required {
+                continue;
+            }
+            if separator.is_some() {
+                if !present {
+                    return Err(invalid(offset));
+                }
+                offset += 1;
+            }
+            let rest = &version[offset..];
+
+            // A modifier is any alphanumeric text
+            if token == Token::Modifier {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(rest.len());
+                if len == 0 {
+                    return Err(invalid(offset));
+                }
+                modifier = Some(Cow::Borrowed(&rest[..len]));
+                offset += len;
+                continue;
+            }
+
+            // Check the number of digits, and whether the number is zero-padded
+            let digits = &rest[..rest.bytes().take_while(u8::is_ascii_digit).count()];
+            let padded = digits.len() > 1 && digits.starts_with('0');
+            let valid = match token {
+                Token::FullYear => digits.len() == 4,
+                Token::PaddedYear => digits.len() == 2 || (digits.len() > 2 && !padded),
+                Token::PaddedMonth | Token::PaddedWeek | Token::PaddedDay => digits.len() == 2,
+                Token::Number => !digits.is_empty(),
+                _ => !digits.is_empty() && !padded,
+            };
+            if !valid {
+                return Err(invalid(offset));
+            }
+            let number: u64 = digits
+                .parse()
+                .map_err(|_| Error::NumberOverflow { offset })?;
+
+            // Validate date segments
+            let in_range = |min, max| (min..=max).contains(&number);
+            match token {
+                Token::FullYear => date.year = number as u16,
+                Token::ShortYear | Token::PaddedYear => {
+                    if !in_range(0, (u16::MAX - SHORT_YEAR_BASE) as u64) {
+                        return Err(invalid(offset));
+                    }
+                    date.year = SHORT_YEAR_BASE + number as u16;
+                }
+                Token::ShortMonth | Token::PaddedMonth if in_range(1, 12) => {
+                    date.month = Some(number as u8)
+                }
+                Token::ShortWeek | Token::PaddedWeek if in_range(1, 53) => {
+                    date.week = Some(number as u8)
+                }
+                Token::ShortDay | Token::PaddedDay if in_range(1, 31) => {
+                    date.day = Some(number as u8);
+                    day_offset = offset;
+                }
+                Token::Number => {}
+                _ => return Err(invalid(offset)),
+            }
+            numbers.push(match token {
+                Token::ShortYear | Token::PaddedYear => date.year as u64,
+                _ => number,
+            });
+            offset += digits.len();
+        }
+        if offset != version.len() {
+            return Err(invalid(offset));
+        }
+
+        // The day must exist in its month, which is only known once the year is parsed
+        if let (Some(month), Some(day)) = (date.month, date.day) {
+            if day > days_in_month(date.year, month) {
+                return Err(invalid(day_offset));
+            }
+        }
+
+        Ok(Calver {
+            date,
+            numbers,
+            modifier,
+        })
+    }
+
+    /// Get the version parts.
+    ///
+    /// These are the numbers in order of the format with full years, followed by the modifier.
+    pub(crate) fn parts(&self) -> Vec<VersionPart<'a>> {
+        self.numbers
+            .iter()
+            .map(|number| VersionPart::Number(*number))
+            .chain(self.modifier.clone().map(VersionPart::Text))
+            .collect()
+    }
+
+    /// Convert into an owned calendar version, that doesn't borrow any data.
+    pub(crate) fn into_owned(self) -> Calver<'static> {
+        Calver {
+            date: self.date,
+            numbers: self.numbers,
+            modifier: self
+                .modifier
+                .map(|modifier| Cow::Owned(modifier.into_owned())),
+        }
+    }
+
+    /// Get the modifier as text part.
+    fn modifier_part(&self) -> Option<VersionPart<'_>> {
+        self.modifier
+            .as_deref()
+            .map(|modifier| VersionPart::Text(Cow::Borrowed(modifier)))
+    }
+}
+
+impl<'a> PartialEq for Calver<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Calver<'a> {}
+
+impl<'a> PartialOrd for Calver<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Calver<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the numbers, missing numbers are zero
+        let len = self.numbers.len().max(other.numbers.len());
+        let number = |numbers: &[u64], index| numbers.get(index).copied().unwrap_or(0);
+        let ord = (0..len)
+            .map(|index| number(&self.numbers, index).cmp(&number(&other.numbers, index)))
+            .find(|&ord| ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal);
+
+        // A version with a modifier is less than the same version without one
+        ord.then_with(|| match (self.modifier_part(), other.modifier_part()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(modifier), Some(other)) => {
+                modifier.compare(&other).ord().unwrap_or(Ordering::Equal)
+            }
+        })
+    }
+}
+
+impl<'a> Hash for Calver<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Skip trailing zeros, because missing numbers are zero
+        let len = self
+            .numbers
+            .iter()
+            .rposition(|number| *number != 0)
+            .map_or(0, |index| index + 1);
+        self.numbers[..len].hash(state);
+        self.modifier_part().hash(state);
+    }
+}
+
+/// Compare two calendar versions.
+///
+/// Versions are compared by their numbers in order of their format, with full years. So versions
+/// with formats that only differ in how the year is written are compared by their dates: `24.04`
+/// in the `YY.0M` format is equal to `2024.04` in the `YYYY.0M` format. Other mixed formats are
+/// only compared by position, such as a month against a week. `None` is returned if either
+/// version doesn't use a calendar versioning scheme.
+pub(crate) fn compare(version: &Version, other: &Version) -> Option<CompOp> {
+    let version = parsed(version)?;
+    let other = parsed(other)?;
+    Some(CompOp::from_ord(version.cmp(other)))
+}
+
+/// Get the given version as calendar version, as it was parsed following the format of its
+/// scheme.
+///
+/// `None` is returned if the version doesn't use the calendar versioning scheme.
+pub(crate) fn parsed<'v>(version: &'v Version) -> Option<&'v Calver<'v>> {
+    match version.parsed() {
+        Parsed::Calver(calver) => Some(calver),
+        _ => None,
+    }
+}
+
+/// Get the number of days in the given month.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_calver::{
+        TEST_CALVER_ERROR, TEST_CALVER_FORMATS_ERROR, TEST_CALVER_SETS, TEST_CALVER_VERSIONS,
+    };
+
+    use super::{Calver, CalverDate, CalverFormat};
+
+    /// Parse the given version following the given format.
+    fn parse_with<'a>(format: &str, version: &'a str) -> Result<Calver<'a>, Error> {
+        Calver::parse(version, &CalverFormat::from(format).unwrap())
+    }
+
+    #[test]
+    fn format() {
+        // Test whether parsing works for each format of the test versions
+        for version in TEST_CALVER_VERSIONS {
+            assert_eq!(CalverFormat::from(version.0).unwrap().as_str(), version.0);
+        }
+
+        // Test whether parsing fails for each invalid format
+        for format in TEST_CALVER_FORMATS_ERROR {
+            assert!(CalverFormat::from(format).is_err(), "Parsing {}", format);
+        }
+        assert_eq!(
+            CalverFormat::from("YYYY.0M.MICRO.").unwrap_err(),
+            Error::InvalidCalverFormat { offset: 14 }
+        );
+        assert_eq!(
+            CalverFormat::from("YYYY/0M").unwrap_err(),
+            Error::InvalidCalverFormat { offset: 4 }
+        );
+        assert_eq!(
+            CalverFormat::from("MAJOR.MINOR").unwrap_err(),
+            Error::InvalidCalverFormat { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn parse() {
+        // Test whether parsing works for each test version
+        for version in TEST_CALVER_VERSIONS {
+            assert_eq!(
+                parse_with(version.0, version.1).unwrap().parts().len(),
+                version.2,
+                "Parsing {} as {}",
+                version.1,
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for (format, version) in TEST_CALVER_ERROR {
+            assert!(
+                parse_with(format, version).is_err(),
+                "Parsing {} as {}",
+                version,
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let format = CalverFormat::from("YYYY.0M.0D").unwrap();
+        assert_eq!(
+            Calver::parse("2024.13.01", &format).unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Calver(format.clone()),
+                offset: 5
+            }
+        );
+        assert_eq!(
+            Calver::parse("2023.02.29", &format).unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Calver(format),
+                offset: 8
+            }
+        );
+        assert_eq!(
+            parse_with("YYYY.MICRO", "2024.99999999999999999999").unwrap_err(),
+            Error::NumberOverflow { offset: 5 }
+        );
+    }
+
+    #[test]
+    fn date() {
+        assert_eq!(
+            parse_with("YY.0M", "24.04").unwrap().date,
+            CalverDate {
+                year: 2024,
+                month: Some(4),
+                week: None,
+                day: None,
+            }
+        );
+        assert_eq!(
+            parse_with("0Y.0W.MICRO", "06.52.3").unwrap().date,
+            CalverDate {
+                year: 2006,
+                month: None,
+                week: Some(52),
+                day: None,
+            }
+        );
+        assert_eq!(
+            parse_with("YYYY.MM.DD-MICRO", "2024.2.29-1").unwrap().date,
+            CalverDate {
+                year: 2024,
+                month: Some(2),
+                week: None,
+                day: Some(29),
+            }
+        );
+    }
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in TEST_CALVER_SETS {
+            let version_a = parse_with(entry.0, entry.1).unwrap();
+            let version_b = parse_with(entry.2, entry.3).unwrap();
+
+            assert_eq!(
+                CompOp::from_ord(version_a.cmp(&version_b)),
+                entry.4,
+                "Testing that {} is {} {}",
+                entry.1,
+                entry.4.sign(),
+                entry.3
+            );
+            assert_eq!(
+                CompOp::from_ord(version_b.cmp(&version_a)),
+                entry.4.flip(),
+                "Testing that {} is {} {}",
+                entry.3,
+                entry.4.flip().sign(),
+                entry.1
+            );
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Calver) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Equal versions must have equal hashes
+        for entry in TEST_CALVER_SETS {
+            if entry.4 == CompOp::Eq {
+                assert_eq!(
+                    hash(&parse_with(entry.0, entry.1).unwrap()),
+                    hash(&parse_with(entry.2, entry.3).unwrap()),
+                    "Testing that {} and {} have an equal hash",
+                    entry.1,
+                    entry.3
+                );
+            }
+        }
+    }
+}
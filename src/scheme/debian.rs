@@ -0,0 +1,395 @@
+//! Debian scheme, used by dpkg and apt.
+//!
+//! Versions have the form `[epoch:]upstream_version[-debian_revision]`, and are compared exactly
+//! like `dpkg --compare-versions` does, as described in the
+//! [Debian policy](https://www.debian.org/doc/debian-policy/ch-controlfields.html#version).
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::{split_runs, Scheme};
+use crate::version::Version;
+use crate::version_part::VersionPart;
+
+/// A parsed Debian version.
+#[derive(Debug)]
+pub(crate) struct Debian<'a> {
+    /// The original version string.
+    version: &'a str,
+
+    /// The epoch, zero if not specified.
+    pub(crate) epoch: u64,
+
+    /// The upstream version.
+    upstream: &'a str,
+
+    /// The Debian revision, if specified.
+    pub(crate) revision: Option<&'a str>,
+}
+
+impl<'a> Debian<'a> {
+    /// Parse the given version string.
+    ///
+    /// Surrounding whitespace is ignored. Like dpkg, syntax that dpkg only warns about, such as
+    /// an upstream version that doesn't start with a digit, is accepted.
+    pub(crate) fn parse(version: &'a str) -> Result<Self, Error> {
+        let start = version.len() - version.trim_start().len();
+        let trimmed = version.trim();
+        if trimmed.is_empty() {
+            return Err(invalid(start));
+        }
+
+        // The version may not contain embedded whitespace
+        if let Some((offset, ch)) = trimmed.char_indices().find(|(_, c)| c.is_whitespace()) {
+            return Err(Error::InvalidChar {
+                offset: start + offset,
+                ch,
+            });
+        }
+
+        // Parse the epoch, which is separated by the first colon
+        let (epoch, rest, rest_offset) = match trimmed.find(':') {
+            Some(index) => {
+                let epoch = &trimmed[..index];
+                if epoch.is_empty() || !epoch.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid(start));
+                }
+                let epoch = epoch
+                    .parse()
+                    .map_err(|_| Error::NumberOverflow { offset: start })?;
+                (epoch, &trimmed[index + 1..], start + index + 1)
+            }
+            None => (0, trimmed, start),
+        };
+
+        // Split the Debian revision at the last hyphen
+        let (upstream, revision) = match rest.rfind('-') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+        if upstream.is_empty() {
+            return Err(invalid(rest_offset));
+        }
+        if revision == Some("") {
+            return Err(invalid(rest_offset + rest.len()));
+        }
+
+        Ok(Debian {
+            version,
+            epoch,
+            upstream,
+            revision,
+        })
+    }
+
+    /// Get the version parts.
+    ///
+    /// These are the alphanumeric runs of the upstream version and Debian revision, split into
+    /// numeric and text parts. The epoch is not included.
+    pub(crate) fn parts(&self) -> Result<Vec<VersionPart<'a>>, Error> {
+        let sections: Vec<_> = [Some(self.upstream), self.revision]
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        split_runs(self.version, &sections)
+    }
+}
+
+impl<'a> PartialEq for Debian<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Debian<'a> {}
+
+impl<'a> PartialOrd for Debian<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Debian<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| compare_str(self.upstream, other.upstream))
+            .then_with(|| {
+                compare_str(
+                    self.revision.unwrap_or_default(),
+                    other.revision.unwrap_or_default(),
+                )
+            })
+    }
+}
+
+impl<'a> Hash for Debian<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.epoch.hash(state);
+        hash_str(self.upstream, state);
+        hash_str(self.revision.unwrap_or_default(), state);
+    }
+}
+
+/// Compare two versions like dpkg.
+///
+/// `None` is returned if either version isn't a valid Debian version.
+pub(crate) fn compare(version: &Version, other: &Version) -> Option<CompOp> {
+    let version = Debian::parse(version.as_str()).ok()?;
+    let other = Debian::parse(other.as_str()).ok()?;
+    Some(CompOp::from_ord(version.cmp(&other)))
+}
+
+/// Compare an upstream version or Debian revision, using the dpkg algorithm.
+///
+/// The strings are compared from left to right, alternating between a run of non-digits and a
+/// run of digits. Non-digits are compared by their sort weight, digits are compared numerically.
+fn compare_str(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+
+    while !a.is_empty() || !b.is_empty() {
+        // Compare the leading non-digits
+        let (a_text, a_rest) = split_run(a, false);
+        let (b_text, b_rest) = split_run(b, false);
+        let a_weights = a_text.iter().map(|&c| weight(Some(c)));
+        let b_weights = b_text.iter().map(|&c| weight(Some(c)));
+        for (a_weight, b_weight) in padded(a_weights, b_weights) {
+            match a_weight.cmp(&b_weight) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+        }
+
+        // Compare the leading digits numerically
+        let (a_digits, a_rest) = split_run(a_rest, true);
+        let (b_digits, b_rest) = split_run(b_rest, true);
+        match compare_digits(a_digits, b_digits) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+
+        a = a_rest;
+        b = b_rest;
+    }
+
+    Ordering::Equal
+}
+
+/// Hash an upstream version or Debian revision, consistent with `compare_str`.
+///
+/// Leading zeros of digit runs and trailing zero runs are skipped, because they don't affect the
+/// comparison.
+fn hash_str<H: Hasher>(s: &str, state: &mut H) {
+    let mut rest = s.as_bytes();
+    while !rest.is_empty() {
+        let (text, tail) = split_run(rest, false);
+        let (digits, tail) = split_run(tail, true);
+        let digits = trim_zeros(digits);
+
+        // A final run of zeros is equal to the end of the string
+        if text.is_empty() && digits.is_empty() && tail.is_empty() {
+            break;
+        }
+
+        state.write_usize(text.len());
+        state.write(text);
+        state.write_usize(digits.len());
+        state.write(digits);
+        rest = tail;
+    }
+    state.write_u8(0xff);
+}
+
+/// Sort weight of a non-digit character, `None` for the end of the run.
+///
+/// A tilde sorts before anything, even the end of the run. Letters sort before other characters.
+fn weight(c: Option<u8>) -> i32 {
+    match c {
+        Some(b'~') => -1,
+        Some(c) if c.is_ascii_alphabetic() => i32::from(c),
+        Some(c) => i32::from(c) + 256,
+        None => 0,
+    }
+}
+
+/// Zip two iterators of sort weights, padding the shorter one with the end of run weight.
+fn padded<'i>(
+    a: impl Iterator<Item = i32> + 'i,
+    b: impl Iterator<Item = i32> + 'i,
+) -> impl Iterator<Item = (i32, i32)> + 'i {
+    let end = weight(None);
+    let mut a = a.fuse();
+    let mut b = b.fuse();
+    std::iter::from_fn(move || match (a.next(), b.next()) {
+        (None, None) => None,
+        (a, b) => Some((a.unwrap_or(end), b.unwrap_or(end))),
+    })
+}
+
+/// Split the leading run of digits, or non-digits, from the given bytes.
+fn split_run(s: &[u8], digits: bool) -> (&[u8], &[u8]) {
+    let len = s
+        .iter()
+        .take_while(|b| b.is_ascii_digit() == digits)
+        .count();
+    s.split_at(len)
+}
+
+/// Strip leading zeros from a run of digits.
+fn trim_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().take_while(|&&b| b == b'0').count();
+    &digits[zeros..]
+}
+
+/// Compare two runs of digits numerically, regardless of their length.
+fn compare_digits(a: &[u8], b: &[u8]) -> Ordering {
+    let (a, b) = (trim_zeros(a), trim_zeros(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Create an error for a version string that doesn't follow the Debian format at `offset`.
+fn invalid(offset: usize) -> Error {
+    Error::InvalidFormat {
+        scheme: Scheme::Debian,
+        offset,
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_debian::{TEST_DEBIAN_ERROR, TEST_DEBIAN_SETS, TEST_DEBIAN_VERSIONS};
+    use crate::version_part::VersionPart;
+
+    use super::Debian;
+
+    #[test]
+    fn parse() {
+        // Test whether parsing works for each test version
+        for version in TEST_DEBIAN_VERSIONS {
+            assert_eq!(
+                Debian::parse(version.0).unwrap().parts().unwrap().len(),
+                version.1,
+                "Parsing {}",
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for version in TEST_DEBIAN_ERROR {
+            assert!(Debian::parse(version).is_err(), "Parsing {}", version);
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let invalid = |offset| Error::InvalidFormat {
+            scheme: Scheme::Debian,
+            offset,
+        };
+
+        assert_eq!(Debian::parse(" ").unwrap_err(), invalid(1));
+        assert_eq!(Debian::parse("a:1.0").unwrap_err(), invalid(0));
+        assert_eq!(Debian::parse("1:").unwrap_err(), invalid(2));
+        assert_eq!(Debian::parse("1:-1").unwrap_err(), invalid(2));
+        assert_eq!(Debian::parse("1.0-").unwrap_err(), invalid(4));
+        assert_eq!(
+            Debian::parse("1.0 beta").unwrap_err(),
+            Error::InvalidChar { offset: 3, ch: ' ' }
+        );
+        assert_eq!(
+            Debian::parse("99999999999999999999:1.0").unwrap_err(),
+            Error::NumberOverflow { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn parse_sections() {
+        let version = Debian::parse(" 2:1.0-rc1-0ubuntu1 ").unwrap();
+        assert_eq!(version.epoch, 2);
+        assert_eq!(version.upstream, "1.0-rc1");
+        assert_eq!(version.revision, Some("0ubuntu1"));
+
+        let version = Debian::parse("1:2:3").unwrap();
+        assert_eq!(version.epoch, 1);
+        assert_eq!(version.upstream, "2:3");
+        assert_eq!(version.revision, None);
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            Debian::parse("1:2.30~rc1-0ubuntu2").unwrap().parts(),
+            Ok(vec![
+                VersionPart::Number(2),
+                VersionPart::Number(30),
+                VersionPart::Text("rc".into()),
+                VersionPart::Number(1),
+                VersionPart::Number(0),
+                VersionPart::Text("ubuntu".into()),
+                VersionPart::Number(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in TEST_DEBIAN_SETS {
+            let version_a = Debian::parse(entry.0).unwrap();
+            let version_b = Debian::parse(entry.1).unwrap();
+
+            assert_eq!(
+                CompOp::from_ord(version_a.cmp(&version_b)),
+                entry.2,
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1
+            );
+            assert_eq!(
+                CompOp::from_ord(version_b.cmp(&version_a)),
+                entry.2.flip(),
+                "Testing that {} is {} {}",
+                entry.1,
+                entry.2.flip().sign(),
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Debian) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Equal versions must have equal hashes
+        for entry in TEST_DEBIAN_SETS {
+            let version_a = Debian::parse(entry.0).unwrap();
+            let version_b = Debian::parse(entry.1).unwrap();
+
+            if entry.2 == CompOp::Eq {
+                assert_eq!(
+                    hash(&version_a),
+                    hash(&version_b),
+                    "Testing that {} and {} have an equal hash",
+                    entry.0,
+                    entry.1
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,518 @@
+//! Generic scheme, which parses any version format on a best effort basis.
+//!
+//! Version strings are split into their parts lazily by the `Parts` iterator, so two version
+//! strings can be compared part by part without allocating. See `Scheme::Generic`.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::comp_op::CompOp;
+use crate::error::{Error, Side};
+use crate::part_vec::PartVec;
+use crate::version::Version;
+use crate::version_manifest::{CharPolicy, VersionManifest};
+use crate::version_part::VersionPart;
+
+/// Keywords that are recognized as version prefix when followed by a hyphen or underscore, such
+/// as in `release-1.2`.
+const PREFIX_KEYWORDS: [&str; 4] = ["release", "version", "rel", "ver"];
+
+/// Iterator over the parts of a version string, following the generic scheme.
+///
+/// Each part is returned with the separator directly before it, which is `None` for the first
+/// part, and the byte range of the part in the version string. Parts aren't padded up to the
+/// minimum depth of the manifest.
+#[derive(Clone)]
+pub(crate) struct Parts<'a, 'm> {
+    /// The full version string.
+    version: &'a str,
+
+    /// The manifest to follow.
+    manifest: &'m VersionManifest,
+
+    /// The byte offset of the next part.
+    offset: usize,
+
+    /// The byte offset of the end of the version core, or of the suffix.
+    end: usize,
+
+    /// The separator after the previous part.
+    separator: Option<char>,
+
+    /// The number of returned parts.
+    count: usize,
+}
+
+impl<'a, 'm> Parts<'a, 'm> {
+    /// Start splitting the given version string.
+    ///
+    /// An error is returned if the version string contains invalid characters.
+    #[inline]
+    pub(crate) fn new(version: &'a str, manifest: &'m VersionManifest) -> Result<Self, Error> {
+        // Make sure the version string doesn't contain invalid characters
+        if manifest.other_chars() == CharPolicy::Error {
+            if let Some((offset, ch)) = version.char_indices().find(|&(_, c)| is_other(manifest, c))
+            {
+                return Err(Error::InvalidChar { offset, ch });
+            }
+        }
+
+        // Only parse the version core, skipping any prefix and suffix
+        let core = core_range(version, manifest);
+
+        Ok(Parts {
+            version,
+            manifest,
+            offset: core.start,
+            end: core.end,
+            separator: None,
+            count: 0,
+        })
+    }
+
+    /// Get the parts of the suffix after the version core, such as `build` and `932` in
+    /// `MyApp 3.2.0 / build 0932`.
+    pub(crate) fn suffix(&self) -> Self {
+        Parts {
+            offset: self.end,
+            end: self.version.len(),
+            separator: None,
+            count: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Only return the parts, and remember whether any of them is a number.
+    ///
+    /// See `CheckedParts::finish` to check the version, after the parts that were needed are
+    /// used.
+    pub(crate) fn checked(self) -> CheckedParts<'a, 'm> {
+        CheckedParts {
+            parts: self,
+            count: 0,
+            has_number: false,
+        }
+    }
+}
+
+impl<'a, 'm> Iterator for Parts<'a, 'm> {
+    type Item = (Option<char>, Range<usize>, VersionPart<'a>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let manifest = self.manifest;
+
+        // Loop over the parts with their byte offset, and parse them
+        while self.offset < self.end {
+            // We may not go over the maximum depth
+            if manifest.max_depth().is_some() && self.count >= manifest.max_depth_number() {
+                return None;
+            }
+
+            // Take the part up to the next separator, and remember it as separator of the next
+            // part
+            let rest = &self.version[self.offset..self.end];
+            let (part, next_separator) =
+                match rest.char_indices().find(|&(_, c)| manifest.is_separator(c)) {
+                    Some((index, c)) => (&rest[..index], Some(c)),
+                    None => (rest, None),
+                };
+            let range = self.offset..self.offset + part.len();
+            self.offset += part.len() + next_separator.map_or(0, char::len_utf8);
+            let part_separator = if self.count == 0 {
+                None
+            } else {
+                self.separator
+            };
+            self.separator = next_separator;
+            if part.is_empty() {
+                continue;
+            }
+
+            // Remove ignored characters, and skip empty parts
+            let part = match manifest.other_chars() {
+                CharPolicy::Ignore if part.contains(|c| is_other(manifest, c)) => {
+                    Cow::Owned(part.chars().filter(|c| !is_other(manifest, *c)).collect())
+                }
+                _ => Cow::Borrowed(part),
+            };
+            if part.is_empty() {
+                continue;
+            }
+
+            // Parse the value as an number if it only has digits, numbers that are too large are
+            // kept as text
+            if part.bytes().all(|b| b.is_ascii_digit()) {
+                if let Ok(number) = part.parse::<u64>() {
+                    self.count += 1;
+                    return Some((part_separator, range, VersionPart::Number(number)));
+                }
+            }
+
+            // Ignore text parts if specified
+            if manifest.ignore_text() {
+                continue;
+            }
+
+            self.count += 1;
+            return Some((part_separator, range, VersionPart::Text(part)));
+        }
+
+        None
+    }
+}
+
+/// Iterator over the parts of a version string, that remembers whether any part is a number.
+/// See `Parts::checked`.
+///
+/// This allows comparing two versions in a single pass, checking the rest of both versions once
+/// the result is known.
+pub(crate) struct CheckedParts<'a, 'm> {
+    /// The parts to check.
+    parts: Parts<'a, 'm>,
+
+    /// The number of returned parts.
+    count: usize,
+
+    /// Whether a number part was returned.
+    has_number: bool,
+}
+
+impl<'a, 'm> CheckedParts<'a, 'm> {
+    /// Check the parts that weren't used yet.
+    ///
+    /// An error is returned if none of the parts is a number.
+    pub(crate) fn finish(mut self) -> Result<(), Error> {
+        self.by_ref().for_each(drop);
+        if !self.has_number && self.count > 0 {
+            return Err(Error::NoNumber);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'm> Iterator for CheckedParts<'a, 'm> {
+    type Item = VersionPart<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, _, part) = self.parts.next()?;
+        self.count += 1;
+        self.has_number |= matches!(part, VersionPart::Number(_));
+        Some(part)
+    }
+}
+
+/// Layout of a version string following the generic scheme.
+///
+/// This is stored in a `Version` when it's parsed, so versions can be compared and hashed without
+/// splitting their version strings again.
+#[derive(Clone)]
+pub(crate) struct Layout<'a> {
+    /// The byte range of the version core, see `core_range`.
+    pub(crate) core: Range<usize>,
+
+    /// The parts of the suffix after the version core.
+    pub(crate) suffix: PartVec<'a>,
+
+    /// The rank of the separator before each part, if separators are significant in the manifest.
+    pub(crate) separator_ranks: Option<Vec<Option<usize>>>,
+}
+
+impl<'a> Layout<'a> {
+    /// Convert into an owned layout, that doesn't borrow any data.
+    pub(crate) fn into_owned(self) -> Layout<'static> {
+        Layout {
+            core: self.core,
+            suffix: self.suffix.into_owned(),
+            separator_ranks: self.separator_ranks,
+        }
+    }
+}
+
+/// Split the given version string in its parts, and get its layout.
+#[inline]
+pub(crate) fn parse<'a>(
+    version: &'a str,
+    manifest: &VersionManifest,
+) -> Result<(PartVec<'a>, Layout<'a>), Error> {
+    let split = Parts::new(version, manifest)?;
+    let mut layout = Layout {
+        core: split.offset..split.end,
+        suffix: split.suffix().checked().collect(),
+        separator_ranks: None,
+    };
+
+    // Only remember the separators if they're significant
+    let mut parts = PartVec::new();
+    let mut ranks = Vec::new();
+    let significant = manifest.significant_separators();
+    split_with(split, manifest, |separator, part| {
+        if significant {
+            ranks.push(separator.map(|c| manifest.separator_rank(c)));
+        }
+        parts.push(part);
+    })?;
+    if significant {
+        layout.separator_ranks = Some(ranks);
+    }
+
+    Ok((parts, layout))
+}
+
+/// Split the given version string in its parts, and give each part with its separator to `push`.
+///
+/// The version is padded with zeros up to the minimum depth, without exceeding the maximum depth.
+#[inline]
+fn split_with<'a>(
+    split: Parts<'a, '_>,
+    manifest: &VersionManifest,
+    mut push: impl FnMut(Option<char>, VersionPart<'a>),
+) -> Result<(), Error> {
+    let mut count = 0;
+    let mut has_number = false;
+    for (separator, _, part) in split {
+        has_number |= matches!(part, VersionPart::Number(_));
+        count += 1;
+        push(separator, part);
+    }
+
+    // The version must contain a number part, if any part was parsed
+    if !has_number && count > 0 {
+        return Err(Error::NoNumber);
+    }
+
+    // Pad the version with zeros up to the minimum depth, without exceeding the maximum depth
+    let min_depth = match manifest.max_depth() {
+        Some(max_depth) => manifest.min_depth_number().min(*max_depth),
+        None => manifest.min_depth_number(),
+    };
+    while count < min_depth {
+        push(
+            if count == 0 { None } else { Some('.') },
+            VersionPart::Number(0),
+        );
+        count += 1;
+    }
+
+    Ok(())
+}
+
+/// Compare two version strings following the generic scheme with the default manifest.
+///
+/// The result equals parsing both versions with `Version::from` and comparing them, but both
+/// strings are walked part by part in a single pass without allocating. The error tells which
+/// side of the comparison failed to parse.
+pub(crate) fn compare(a: &str, b: &str) -> Result<CompOp, Error> {
+    let manifest = VersionManifest::new();
+    let parts = Parts::new(a, &manifest).map_err(|err| Side::Left.wrap(err))?;
+    let other_parts = Parts::new(b, &manifest).map_err(|err| Side::Right.wrap(err))?;
+    let (suffix, other_suffix) = (parts.suffix(), other_parts.suffix());
+    let mut iter = parts.checked();
+    let mut other_iter = other_parts.checked();
+
+    // Compare the parts, then make sure the rest of both versions is valid as well
+    let result = Version::compare_iter(
+        iter.by_ref().peekable(),
+        other_iter.by_ref().peekable(),
+        false,
+        false,
+    );
+    iter.finish().map_err(|err| Side::Left.wrap(err))?;
+    other_iter.finish().map_err(|err| Side::Right.wrap(err))?;
+
+    // Compare the suffixes if the cores are equal, such as the builds in `1.0 / build 0042`
+    if result != CompOp::Eq {
+        return Ok(result);
+    }
+    Ok(Version::compare_iter(
+        suffix.checked().peekable(),
+        other_suffix.checked().peekable(),
+        false,
+        false,
+    ))
+}
+
+/// Find the byte range of the version core in the given version string.
+///
+/// The core starts at the first whitespace separated word that starts with a digit, such as
+/// `3.2.0` in `MyApp 3.2.0 / build 0932`. A known prefix keyword directly before the digit, such
+/// as in `release-1.2`, and a leading `v` or `V` if allowed aren't part of the core either. The
+/// core ends before the whitespace in front of a word starting with `/` or `(`, such as in
+/// `1.2 (stable)`, so other words such as `rc1` in `1.2 rc1` are part of it. Everything before
+/// the core is its prefix, everything after it is its suffix. The full version string is
+/// returned if no word starts with a digit, or if the words before it don't contain any letter,
+/// such as in ` .   -32 . 1`.
+pub(crate) fn core_range(version: &str, manifest: &VersionManifest) -> Range<usize> {
+    // Loop over the words with their byte offset, to find the first one holding a core
+    let mut words = version.split_inclusive(char::is_whitespace);
+    let mut offset = 0;
+    while let Some(word) = words.next() {
+        let word_offset = offset;
+        offset += word.len();
+        let word = word.trim_end();
+
+        // Skip a known prefix keyword followed by a hyphen or underscore
+        let mut start = PREFIX_KEYWORDS
+            .iter()
+            .find(|keyword| {
+                word.get(..keyword.len())
+                    .is_some_and(|w| w.eq_ignore_ascii_case(keyword))
+                    && matches!(word.as_bytes().get(keyword.len()), Some(b'-' | b'_'))
+            })
+            .map_or(0, |keyword| keyword.len() + 1);
+
+        // Skip a leading `v` or `V` directly followed by a digit, if allowed
+        if manifest.v_prefix() {
+            if let [b'v' | b'V', digit, ..] = word.as_bytes()[start..] {
+                if digit.is_ascii_digit() {
+                    start += 1;
+                }
+            }
+        }
+
+        // The core must start with a digit
+        if !word.as_bytes().get(start).is_some_and(u8::is_ascii_digit) {
+            continue;
+        }
+
+        // Words before the core must form a name, otherwise it's an undefined format
+        if word_offset > 0 && !version[..word_offset].contains(char::is_alphabetic) {
+            break;
+        }
+
+        // The core ends at the last word before a word starting with a suffix delimiter
+        let mut end = word_offset + word.len();
+        for word in words.by_ref() {
+            let word_offset = offset;
+            offset += word.len();
+            let word = word.trim_end();
+            if word.starts_with(['/', '(']) {
+                break;
+            }
+            if !word.is_empty() {
+                end = word_offset + word.len();
+            }
+        }
+        return word_offset + start..end;
+    }
+
+    0..version.len()
+}
+
+/// Check whether the given character is neither alphanumeric nor a separator.
+fn is_other(manifest: &VersionManifest, c: char) -> bool {
+    !c.is_alphanumeric() && !manifest.is_separator(c)
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::comp_op::CompOp;
+    use crate::error::{Error, Side};
+    use crate::test::test_version::{TEST_VERSIONS, TEST_VERSIONS_ERROR};
+    use crate::test::test_version_set::{TEST_VERSION_SETS, TEST_VERSION_SETS_ERROR};
+    use crate::version::Version;
+    use crate::version_manifest::VersionManifest;
+    use crate::version_part::VersionPart;
+
+    use super::{compare, Parts};
+
+    #[test]
+    fn parts() {
+        let manifest = VersionManifest::new();
+        for version in TEST_VERSIONS {
+            let mut checked = Parts::new(version.0, &manifest).unwrap().checked();
+            let parts: Vec<VersionPart> = checked.by_ref().collect();
+            assert_eq!(&parts, Version::from(version.0).unwrap().parts());
+            assert_eq!(checked.finish(), Ok(()));
+        }
+
+        // Errors are returned when finishing
+        for version in TEST_VERSIONS_ERROR {
+            assert!(Parts::new(version.0, &manifest)
+                .and_then(|parts| parts.checked().finish())
+                .is_err());
+        }
+
+        // Numbers that are too large are kept as text
+        let mut checked = Parts::new("1.99999999999999999999.3", &manifest)
+            .unwrap()
+            .checked();
+        assert_eq!(checked.next(), Some(VersionPart::Number(1)));
+        assert_eq!(
+            checked.next(),
+            Some(VersionPart::Text("99999999999999999999".into()))
+        );
+        assert_eq!(checked.next(), Some(VersionPart::Number(3)));
+        assert_eq!(checked.next(), None);
+        assert_eq!(checked.finish(), Ok(()));
+    }
+
+    #[test]
+    fn parts_separators() {
+        let manifest = VersionManifest::new();
+        let parts: Vec<_> = Parts::new("1..2-rc1", &manifest).unwrap().collect();
+        assert_eq!(
+            parts,
+            [
+                (None, 0..1, VersionPart::Number(1)),
+                (Some('.'), 3..4, VersionPart::Number(2)),
+                (Some('-'), 5..8, VersionPart::Text("rc1".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_sets() {
+        for entry in TEST_VERSION_SETS {
+            assert_eq!(
+                compare(entry.0, entry.1),
+                Ok(entry.2.clone()),
+                "Testing that {} is {} {}",
+                &entry.0,
+                &entry.2.sign(),
+                &entry.1
+            );
+        }
+
+        // Both ways of comparing give the same result
+        for entry in TEST_VERSION_SETS_ERROR {
+            let parsed = match (Version::from(entry.0), Version::from(entry.1)) {
+                (Ok(a), Ok(b)) => Some(a.compare(&b)),
+                _ => None,
+            };
+            assert_eq!(compare(entry.0, entry.1).ok(), parsed);
+        }
+    }
+
+    #[test]
+    fn compare_error() {
+        assert_eq!(compare("abc", "1.2"), Err(Side::Left.wrap(Error::NoNumber)));
+        assert_eq!(
+            compare("1.2", "rc.\u{2192}"),
+            Err(Side::Right.wrap(Error::NoNumber))
+        );
+
+        // Other characters are ignored by default
+        assert_eq!(compare("1.2", "1.2\u{2122}"), Ok(CompOp::Eq));
+
+        // Errors after the first difference are still found
+        assert_eq!(
+            compare("2.1", "rc.dev"),
+            Err(Side::Right.wrap(Error::NoNumber))
+        );
+
+        // Numbers that are too large are compared by their value
+        assert_eq!(
+            compare("1.99999999999999999999", "1.100000000000000000000"),
+            Ok(CompOp::Lt)
+        );
+        assert_eq!(
+            compare("1.099999999999999999999", "1.99999999999999999999"),
+            Ok(CompOp::Eq)
+        );
+        assert_eq!(compare("1.99999999999999999999", "1.2"), Ok(CompOp::Gt));
+        assert_eq!(compare("", "1"), Ok(CompOp::Lt));
+    }
+}
@@ -0,0 +1,367 @@
+//! Maven scheme, used by Maven and other JVM build tools.
+//!
+//! Versions are parsed and compared like Maven's
+//! [`ComparableVersion`](https://maven.apache.org/ref/current/maven-artifact/apidocs/org/apache/maven/artifact/versioning/ComparableVersion.html).
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::version::{Parsed, Version};
+use crate::version_part::VersionPart;
+
+/// Well-known qualifiers, ordered from lowest to highest precedence.
+///
+/// The empty qualifier is a release, unknown qualifiers rank above all of these.
+const QUALIFIERS: &[&str] = &["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"];
+
+/// The rank of the release qualifier, which is what a missing item is compared as.
+const RELEASE_RANK: (usize, &str) = (5, "");
+
+/// An item of a Maven version.
+///
+/// Derived equality and hashing is consistent with the ordering, because parsed versions are
+/// normalized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Item {
+    /// A number.
+    Int(u64),
+
+    /// A normalized qualifier, such as `alpha` or `snapshot`.
+    Str(String),
+
+    /// A sub list of items, started by a `-` or a transition between digits and letters.
+    List(Vec<Item>),
+}
+
+/// A parsed Maven version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Maven {
+    /// The normalized list of items.
+    items: Vec<Item>,
+}
+
+impl Maven {
+    /// Parse the given version string.
+    ///
+    /// Any string is a valid Maven version, an error is only returned if a number is too large.
+    pub(crate) fn parse(version: &str) -> Result<Self, Error> {
+        // Stack of lists, items are added to the innermost list
+        let mut stack = vec![Vec::new()];
+        let mut is_digit = false;
+        let mut start = 0;
+
+        for (index, c) in version.char_indices() {
+            let list = stack.last_mut().unwrap();
+            match c {
+                '.' | '-' => {
+                    if index == start {
+                        list.push(Item::Int(0));
+                    } else {
+                        list.push(item(&version[start..index], start, is_digit, false)?);
+                    }
+                    start = index + 1;
+
+                    // A hyphen starts a sub list
+                    if c == '-' {
+                        stack.push(Vec::new());
+                    }
+                }
+                _ if c.is_ascii_digit() => {
+                    // Letters followed by digits start a sub list
+                    if !is_digit && index > start {
+                        list.push(item(&version[start..index], start, false, true)?);
+                        start = index;
+                        stack.push(Vec::new());
+                    }
+                    is_digit = true;
+                }
+                _ => {
+                    // Digits followed by letters start a sub list
+                    if is_digit && index > start {
+                        list.push(item(&version[start..index], start, true, false)?);
+                        start = index;
+                        stack.push(Vec::new());
+                    }
+                    is_digit = false;
+                }
+            }
+        }
+        if version.len() > start {
+            let list = stack.last_mut().unwrap();
+            list.push(item(&version[start..], start, is_digit, false)?);
+        }
+
+        // Normalize the lists from the innermost one, and add them to their parent
+        let mut items = stack.pop().unwrap();
+        normalize(&mut items);
+        while let Some(mut parent) = stack.pop() {
+            parent.push(Item::List(items));
+            normalize(&mut parent);
+            items = parent;
+        }
+
+        Ok(Maven { items })
+    }
+
+    /// Get the version parts.
+    ///
+    /// These are the numbers and normalized qualifiers of the version, in order. Qualifiers that
+    /// are equal to a release, such as `final` or `ga`, and trailing zeros are not included,
+    /// because they're dropped when normalizing the version.
+    pub(crate) fn parts(&self) -> Vec<VersionPart<'static>> {
+        fn flatten(items: &[Item], parts: &mut Vec<VersionPart<'static>>) {
+            for item in items {
+                match item {
+                    Item::Int(number) => parts.push(VersionPart::Number(*number)),
+                    Item::Str(qualifier) => {
+                        parts.push(VersionPart::Text(Cow::Owned(qualifier.clone())))
+                    }
+                    Item::List(list) => flatten(list, parts),
+                }
+            }
+        }
+
+        let mut parts = Vec::new();
+        flatten(&self.items, &mut parts);
+        parts
+    }
+}
+
+impl PartialOrd for Maven {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Maven {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_lists(&self.items, &other.items)
+    }
+}
+
+/// Compare two versions like Maven.
+///
+/// `None` is returned if either version doesn't use the Maven scheme.
+pub(crate) fn compare(version: &Version, other: &Version) -> Option<CompOp> {
+    match (version.parsed(), other.parsed()) {
+        (Parsed::Maven(version), Parsed::Maven(other)) => {
+            Some(CompOp::from_ord(version.cmp(other)))
+        }
+        _ => None,
+    }
+}
+
+/// Create an item from the given string at byte `offset`.
+///
+/// `followed_by_digit` specifies whether a qualifier is directly followed by a number, in which
+/// case `a`, `b` and `m` are short for `alpha`, `beta` and `milestone`.
+fn item(
+    value: &str,
+    offset: usize,
+    is_digit: bool,
+    followed_by_digit: bool,
+) -> Result<Item, Error> {
+    if is_digit {
+        return value
+            .parse()
+            .map(Item::Int)
+            .map_err(|_| Error::NumberOverflow { offset });
+    }
+
+    let value = value.to_lowercase();
+    let value = match value.as_str() {
+        "a" if followed_by_digit => "alpha",
+        "b" if followed_by_digit => "beta",
+        "m" if followed_by_digit => "milestone",
+        "ga" | "final" | "release" => "",
+        "cr" => "rc",
+        value => value,
+    };
+    Ok(Item::Str(value.to_owned()))
+}
+
+/// Check whether an item is equal to nothing, these items are removed when normalizing.
+fn is_null(item: &Item) -> bool {
+    match item {
+        Item::Int(number) => *number == 0,
+        Item::Str(qualifier) => qualifier.is_empty(),
+        Item::List(list) => list.is_empty(),
+    }
+}
+
+/// Normalize a list, by removing null items from the end.
+///
+/// Like Maven, sub lists are skipped, so `1.0-alpha` is normalized to `1-alpha`.
+fn normalize(list: &mut Vec<Item>) {
+    for index in (0..list.len()).rev() {
+        if is_null(&list[index]) {
+            list.remove(index);
+        } else if !matches!(list[index], Item::List(_)) {
+            break;
+        }
+    }
+}
+
+/// The rank of a qualifier, well-known qualifiers by their precedence and unknown qualifiers
+/// lexically above these.
+fn rank(qualifier: &str) -> (usize, &str) {
+    match QUALIFIERS.iter().position(|q| *q == qualifier) {
+        Some(index) => (index, ""),
+        None => (QUALIFIERS.len(), qualifier),
+    }
+}
+
+/// Compare two items, `None` being a missing item.
+///
+/// Numbers are greater than sub lists, which are greater than qualifiers.
+fn compare_item(item: Option<&Item>, other: Option<&Item>) -> Ordering {
+    match (item, other) {
+        (None, None) => Ordering::Equal,
+        (None, other) => compare_item(other, None).reverse(),
+
+        (Some(Item::Int(number)), None) => number.cmp(&0),
+        (Some(Item::Int(number)), Some(Item::Int(other))) => number.cmp(other),
+        (Some(Item::Int(_)), Some(_)) => Ordering::Greater,
+
+        (Some(Item::Str(qualifier)), None) => rank(qualifier).cmp(&RELEASE_RANK),
+        (Some(Item::Str(qualifier)), Some(Item::Str(other))) => rank(qualifier).cmp(&rank(other)),
+        (Some(Item::Str(_)), Some(_)) => Ordering::Less,
+
+        (Some(Item::List(list)), None) => list
+            .iter()
+            .map(|item| compare_item(Some(item), None))
+            .find(|&ord| ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal),
+        (Some(Item::List(list)), Some(Item::List(other))) => compare_lists(list, other),
+        (Some(Item::List(_)), Some(Item::Int(_))) => Ordering::Less,
+        (Some(Item::List(_)), Some(Item::Str(_))) => Ordering::Greater,
+    }
+}
+
+/// Compare two lists item by item, missing items are compared as `None`.
+fn compare_lists(list: &[Item], other: &[Item]) -> Ordering {
+    (0..list.len().max(other.len()))
+        .map(|index| compare_item(list.get(index), other.get(index)))
+        .find(|&ord| ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::test::test_maven::{TEST_MAVEN_ERROR, TEST_MAVEN_SETS, TEST_MAVEN_VERSIONS};
+    use crate::version_part::VersionPart;
+
+    use super::Maven;
+
+    #[test]
+    fn parse() {
+        // Test whether parsing works for each test version
+        for version in TEST_MAVEN_VERSIONS {
+            assert_eq!(
+                Maven::parse(version.0).unwrap().parts().len(),
+                version.1,
+                "Parsing {}",
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for version in TEST_MAVEN_ERROR {
+            assert!(Maven::parse(version).is_err(), "Parsing {}", version);
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            Maven::parse("1.99999999999999999999").unwrap_err(),
+            Error::NumberOverflow { offset: 2 }
+        );
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            Maven::parse("1.0-alpha-1-SNAPSHOT").unwrap().parts(),
+            vec![
+                VersionPart::Number(1),
+                VersionPart::Text("alpha".into()),
+                VersionPart::Number(1),
+                VersionPart::Text("snapshot".into()),
+            ]
+        );
+        assert_eq!(
+            Maven::parse("2.0-M3").unwrap().parts(),
+            vec![
+                VersionPart::Number(2),
+                VersionPart::Text("milestone".into()),
+                VersionPart::Number(3),
+            ]
+        );
+        assert_eq!(
+            Maven::parse("1.0.0.Final").unwrap().parts(),
+            vec![VersionPart::Number(1)]
+        );
+    }
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in TEST_MAVEN_SETS {
+            let version_a = Maven::parse(entry.0).unwrap();
+            let version_b = Maven::parse(entry.1).unwrap();
+
+            assert_eq!(
+                CompOp::from_ord(version_a.cmp(&version_b)),
+                entry.2,
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1
+            );
+            assert_eq!(
+                CompOp::from_ord(version_b.cmp(&version_a)),
+                entry.2.flip(),
+                "Testing that {} is {} {}",
+                entry.1,
+                entry.2.flip().sign(),
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Maven) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Equal versions must have equal hashes
+        for entry in TEST_MAVEN_SETS {
+            if entry.2 == CompOp::Eq {
+                assert_eq!(
+                    Maven::parse(entry.0).unwrap(),
+                    Maven::parse(entry.1).unwrap()
+                );
+                assert_eq!(
+                    hash(&Maven::parse(entry.0).unwrap()),
+                    hash(&Maven::parse(entry.1).unwrap()),
+                    "Testing that {} and {} have an equal hash",
+                    entry.0,
+                    entry.1
+                );
+            }
+        }
+    }
+}
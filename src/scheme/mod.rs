@@ -0,0 +1,257 @@
+//! Module for version schemes.
+//!
+//! A version scheme defines how version strings are parsed and compared, following the rules of a
+//! specific versioning standard. The scheme is configured in a `VersionManifest`, versions are
+//! parsed using the generic scheme by default.
+
+use std::borrow::Cow;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::version_part::VersionPart;
+
+pub(crate) mod calver;
+pub(crate) mod debian;
+pub(crate) mod generic;
+pub(crate) mod maven;
+pub(crate) mod pep440;
+pub(crate) mod rpm;
+pub(crate) mod semver;
+
+pub use self::calver::{CalverDate, CalverFormat};
+
+/// Enum of supported version schemes.
+///
+/// Versions are only compared following the rules of their scheme if both versions use the same
+/// scheme, or are both calendar versions. Otherwise `Version::compare` uses the generic rules,
+/// while equality and ordering order the versions by their scheme, in the order of this enum. So
+/// versions using different schemes are never equal, which keeps the ordering total.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Scheme {
+    /// Generic scheme, which parses any version format on a best effort basis.
+    ///
+    /// Version strings are split into numeric and text parts at the separators configured in the
+    /// manifest, which are any whitespace or ASCII punctuation by default. Other characters that
+    /// aren't alphanumeric are ignored by default, see `VersionManifest::set_other_chars`.
+    #[default]
+    Generic,
+
+    /// Strict [Semantic Versioning 2.0.0](https://semver.org/spec/v2.0.0.html) scheme.
+    ///
+    /// A version must consist of a major, minor and patch number, optionally followed by
+    /// pre-release identifiers (`-alpha.1`) and build metadata (`+build.5`). The parts of a
+    /// version are the major, minor and patch number followed by the pre-release identifiers.
+    /// Build metadata is ignored when comparing versions.
+    Semver,
+
+    /// [PEP 440](https://peps.python.org/pep-0440/) scheme, used by Python packages.
+    ///
+    /// A version consists of an optional epoch (`1!`), release numbers, and an optional
+    /// pre-release (`a1`, `b2`, `rc3`), post-release (`.post1`), development release (`.dev2`)
+    /// and local version label (`+ubuntu1`). Alternative spellings such as `1.0-RC1` are
+    /// accepted, and are equal to their normalized form `1.0rc1`. Versions are displayed in
+    /// normalized form.
+    ///
+    /// The parts of a version are the release numbers, followed by the normalized label and
+    /// number of the pre-release, post-release and development release.
+    Pep440,
+
+    /// [Debian](https://www.debian.org/doc/debian-policy/ch-controlfields.html#version) scheme,
+    /// used by dpkg and apt.
+    ///
+    /// A version has the form `[epoch:]upstream_version[-debian_revision]`. Versions are compared
+    /// exactly like `dpkg --compare-versions`, so a `~` sorts before anything, even the end of
+    /// the version: `1.0~rc1` < `1.0`.
+    ///
+    /// The parts of a version are the alphanumeric runs of the upstream version and Debian
+    /// revision, split into numeric and text parts.
+    Debian,
+
+    /// [RPM](https://rpm-software-management.github.io/rpm/manual/) scheme, used by Fedora, RHEL
+    /// and other RPM based distributions.
+    ///
+    /// A version has the form `[epoch:]version[-release]`. The version and release are compared
+    /// with the `rpmvercmp` algorithm, giving the same results as `rpmdev-vercmp`. A `~` sorts
+    /// before anything, a `^` sorts after the end of the version: `1.0~rc1` < `1.0` < `1.0^git1`
+    /// < `1.0.1`.
+    ///
+    /// The parts of a version are the alphanumeric segments of the version and release, split
+    /// into numeric and text parts.
+    Rpm,
+
+    /// [Maven](https://maven.apache.org/pom.html#version-order-specification) scheme, used by
+    /// Maven and other JVM build tools.
+    ///
+    /// Versions are compared exactly like Maven's `ComparableVersion`. Qualifiers are compared
+    /// case-insensitively and ranked as `alpha` < `beta` < `milestone` < `rc` < `snapshot` <
+    /// release < `sp`, other qualifiers rank above these and are compared lexically. The
+    /// qualifiers `ga`, `final` and `release` are equal to a release, so `1.0.0.Final` equals
+    /// `1.0`.
+    ///
+    /// The parts of a version are its numbers and normalized qualifiers, trailing zeros and
+    /// release qualifiers are dropped: `1.0-M3` has the parts `1`, `milestone` and `3`.
+    Maven,
+
+    /// [Calendar versioning](https://calver.org/) scheme, for versions based on the release date
+    /// such as `2024.03.1` or `24.04`.
+    ///
+    /// The format of versions is given by a `CalverFormat`, such as `YYYY.0M.MICRO`. Date
+    /// segments are validated, so month `13` is rejected, and the date of a version is available
+    /// through `Version::date`. Short years are relative to 2000, so versions with formats that
+    /// only differ in how the year is written can be compared: `24.04` in the `YY.0M` format
+    /// equals `2024.04` in the `YYYY.0M` format. Other formats are compared by the position of
+    /// their numbers, so `2024.04` in the `YYYY.0M` format is less than `2024.15` in the
+    /// `YYYY.WW` format, even though week 15 of 2024 is in April.
+    ///
+    /// The parts of a version are its numbers in order of the format, with full years, followed
+    /// by the modifier.
+    Calver(CalverFormat),
+}
+
+impl Scheme {
+    /// Get the name of this scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CalverFormat, Scheme};
+    ///
+    /// assert_eq!(Scheme::Generic.name(), "generic");
+    /// assert_eq!(Scheme::Semver.name(), "semver");
+    /// assert_eq!(Scheme::Pep440.name(), "pep440");
+    /// assert_eq!(Scheme::Debian.name(), "debian");
+    /// assert_eq!(Scheme::Rpm.name(), "rpm");
+    /// assert_eq!(Scheme::Maven.name(), "maven");
+    /// assert_eq!(Scheme::Calver(CalverFormat::from("YY.0M").unwrap()).name(), "calver");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scheme::Generic => "generic",
+            Scheme::Semver => "semver",
+            Scheme::Pep440 => "pep440",
+            Scheme::Debian => "debian",
+            Scheme::Rpm => "rpm",
+            Scheme::Maven => "maven",
+            Scheme::Calver(_) => "calver",
+        }
+    }
+
+    /// Check whether versions using this and the `other` scheme are compared following the rules
+    /// of the scheme.
+    ///
+    /// This is the case if both schemes are equal, or if both are calendar versioning schemes.
+    pub(crate) fn is_compatible(&self, other: &Scheme) -> bool {
+        self == other || matches!((self, other), (Scheme::Calver(_), Scheme::Calver(_)))
+    }
+
+    /// Get the rank of this scheme, used to order versions with incompatible schemes.
+    ///
+    /// All calendar versioning schemes share the same rank, as they're compatible.
+    pub(crate) fn rank(&self) -> usize {
+        match self {
+            Scheme::Generic => 0,
+            Scheme::Semver => 1,
+            Scheme::Pep440 => 2,
+            Scheme::Debian => 3,
+            Scheme::Rpm => 4,
+            Scheme::Maven => 5,
+            Scheme::Calver(_) => 6,
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Split the given sections of a version string into numeric and text parts.
+///
+/// Each run of digits becomes a numeric part and each run of letters a text part, any other
+/// characters are skipped. The sections must be slices of `version`, which is used to report the
+/// byte offset of numbers that are too large.
+pub(crate) fn split_runs<'a>(
+    version: &str,
+    sections: &[&'a str],
+) -> Result<Vec<VersionPart<'a>>, Error> {
+    let mut parts = Vec::new();
+    for section in sections {
+        let mut rest = *section;
+        while let Some(index) = rest.find(|c: char| c.is_ascii_alphanumeric()) {
+            rest = &rest[index..];
+            let is_digit = rest.as_bytes()[0].is_ascii_digit();
+            let len = rest
+                .bytes()
+                .take_while(|b| b.is_ascii_alphanumeric() && b.is_ascii_digit() == is_digit)
+                .count();
+            let (run, tail) = rest.split_at(len);
+
+            if is_digit {
+                let offset = run.as_ptr() as usize - version.as_ptr() as usize;
+                parts.push(VersionPart::Number(
+                    run.parse().map_err(|_| Error::NumberOverflow { offset })?,
+                ));
+            } else {
+                parts.push(VersionPart::Text(Cow::Borrowed(run)));
+            }
+            rest = tail;
+        }
+    }
+
+    Ok(parts)
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::version_part::VersionPart;
+
+    use super::{split_runs, Scheme};
+
+    #[test]
+    fn default() {
+        assert_eq!(Scheme::default(), Scheme::Generic);
+    }
+
+    #[test]
+    fn split_runs_of() {
+        let version = "1.0rc2-ubuntu~3";
+        assert_eq!(
+            split_runs(version, &[&version[..6], &version[7..]]),
+            Ok(vec![
+                VersionPart::Number(1),
+                VersionPart::Number(0),
+                VersionPart::Text("rc".into()),
+                VersionPart::Number(2),
+                VersionPart::Text("ubuntu".into()),
+                VersionPart::Number(3),
+            ])
+        );
+
+        let version = "1.0-99999999999999999999";
+        assert_eq!(
+            split_runs(version, &[&version[..3], &version[4..]]),
+            Err(Error::NumberOverflow { offset: 4 })
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Scheme::Generic), "generic");
+        assert_eq!(format!("{}", Scheme::Semver), "semver");
+        assert_eq!(format!("{}", Scheme::Pep440), "pep440");
+        assert_eq!(format!("{}", Scheme::Debian), "debian");
+        assert_eq!(format!("{}", Scheme::Rpm), "rpm");
+        assert_eq!(format!("{}", Scheme::Maven), "maven");
+    }
+}
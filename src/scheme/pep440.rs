@@ -0,0 +1,570 @@
+//! PEP 440 scheme, used by Python packages.
+//!
+//! Versions are parsed, normalized and compared following the rules of
+//! [PEP 440](https://peps.python.org/pep-0440/).
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::Scheme;
+use crate::version::{Parsed, Version};
+use crate::version_part::VersionPart;
+
+/// Pre-release labels with their phase, longer labels are listed before their prefixes.
+const PRE_RELEASE_LABELS: &[(&str, Phase)] = &[
+    ("alpha", Phase::Alpha),
+    ("a", Phase::Alpha),
+    ("beta", Phase::Beta),
+    ("b", Phase::Beta),
+    ("preview", Phase::Rc),
+    ("pre", Phase::Rc),
+    ("rc", Phase::Rc),
+    ("c", Phase::Rc),
+];
+
+/// Post-release labels, longer labels are listed before their prefixes.
+const POST_RELEASE_LABELS: &[(&str, ())] = &[("post", ()), ("rev", ()), ("r", ())];
+
+/// Pre-release phase, ordered from lowest to highest precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Phase {
+    Alpha,
+    Beta,
+    Rc,
+}
+
+impl Phase {
+    /// The normalized label of this phase.
+    fn label(self) -> &'static str {
+        match self {
+            Phase::Alpha => "a",
+            Phase::Beta => "b",
+            Phase::Rc => "rc",
+        }
+    }
+}
+
+/// A parsed PEP 440 version.
+#[derive(Debug, Clone)]
+pub(crate) struct Pep440<'a> {
+    /// The epoch, zero if not specified.
+    pub(crate) epoch: u64,
+
+    /// The release segment numbers.
+    release: Vec<u64>,
+
+    /// The pre-release phase and number.
+    pre: Option<(Phase, u64)>,
+
+    /// The post-release number.
+    post: Option<u64>,
+
+    /// The development release number.
+    dev: Option<u64>,
+
+    /// The local version label segments.
+    local: Vec<VersionPart<'a>>,
+}
+
+impl<'a> Pep440<'a> {
+    /// Parse the given version string.
+    ///
+    /// Surrounding whitespace, a leading `v` and alternative spellings and separators are
+    /// accepted, as described by the normalization rules of PEP 440.
+    pub(crate) fn parse(version: &'a str) -> Result<Self, Error> {
+        let mut cursor = Cursor::new(version);
+
+        // Parse the optional epoch, and the release segment
+        cursor.eat_any("vV");
+        let mut epoch = 0;
+        let mut number = cursor.require_number()?;
+        if cursor.eat_any("!") {
+            epoch = number;
+            number = cursor.require_number()?;
+        }
+        let mut release = vec![number];
+        while cursor.peek_number_after(".") {
+            cursor.eat_any(".");
+            release.push(cursor.require_number()?);
+        }
+
+        // Parse the pre-release
+        let start = cursor.pos;
+        cursor.eat_any("-_.");
+        let pre = match cursor.eat_label(PRE_RELEASE_LABELS) {
+            Some(phase) => Some((phase, cursor.implicit_number()?)),
+            None => {
+                cursor.pos = start;
+                None
+            }
+        };
+
+        // Parse the post-release, which may be specified as just a number after a dash
+        let start = cursor.pos;
+        let post = if cursor.peek_number_after("-") {
+            cursor.eat_any("-");
+            Some(cursor.require_number()?)
+        } else {
+            cursor.eat_any("-_.");
+            match cursor.eat_label(POST_RELEASE_LABELS) {
+                Some(()) => Some(cursor.implicit_number()?),
+                None => {
+                    cursor.pos = start;
+                    None
+                }
+            }
+        };
+
+        // Parse the development release
+        let start = cursor.pos;
+        cursor.eat_any("-_.");
+        let dev = match cursor.eat_label(&[("dev", ())]) {
+            Some(()) => Some(cursor.implicit_number()?),
+            None => {
+                cursor.pos = start;
+                None
+            }
+        };
+
+        // Parse the local version label
+        let mut local = Vec::new();
+        if cursor.eat_any("+") {
+            loop {
+                local.push(cursor.require_segment()?);
+                if !cursor.eat_any("-_.") {
+                    break;
+                }
+            }
+        }
+
+        // The whole version string must be consumed
+        if cursor.pos != cursor.end {
+            return Err(invalid(cursor.pos));
+        }
+
+        Ok(Pep440 {
+            epoch,
+            release,
+            pre,
+            post,
+            dev,
+            local,
+        })
+    }
+
+    /// Get the version parts.
+    ///
+    /// These are the release numbers, followed by the normalized label and number of the
+    /// pre-release, post-release and development release. The epoch and local version label are
+    /// not included.
+    pub(crate) fn parts(&self) -> Vec<VersionPart<'a>> {
+        let mut parts: Vec<_> = self
+            .release
+            .iter()
+            .map(|&number| VersionPart::Number(number))
+            .collect();
+
+        let labels = [
+            self.pre.map(|(phase, number)| (phase.label(), number)),
+            self.post.map(|number| ("post", number)),
+            self.dev.map(|number| ("dev", number)),
+        ];
+        for (label, number) in labels.iter().flatten() {
+            parts.push(VersionPart::Text(Cow::Borrowed(label)));
+            parts.push(VersionPart::Number(*number));
+        }
+
+        parts
+    }
+
+    /// Convert into an owned version, that doesn't borrow any data.
+    pub(crate) fn into_owned(self) -> Pep440<'static> {
+        Pep440 {
+            local: self
+                .local
+                .into_iter()
+                .map(VersionPart::into_owned)
+                .collect(),
+            ..self
+        }
+    }
+
+    /// The release numbers, without trailing zeros.
+    fn release_key(&self) -> &[u64] {
+        let len = self
+            .release
+            .iter()
+            .rposition(|&number| number != 0)
+            .map_or(0, |index| index + 1);
+        &self.release[..len]
+    }
+
+    /// The pre-release sort key.
+    ///
+    /// A development release of a final release sorts before all of its pre-releases, a final
+    /// release sorts after them.
+    fn pre_key(&self) -> (u8, Option<(Phase, u64)>) {
+        match self.pre {
+            None if self.post.is_none() && self.dev.is_some() => (0, None),
+            Some(pre) => (1, Some(pre)),
+            None => (2, None),
+        }
+    }
+
+    /// The development release sort key, a release without development number sorts last.
+    fn dev_key(&self) -> (bool, Option<u64>) {
+        (self.dev.is_none(), self.dev)
+    }
+}
+
+impl<'a> PartialEq for Pep440<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Pep440<'a> {}
+
+impl<'a> PartialOrd for Pep440<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Pep440<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| self.release_key().cmp(other.release_key()))
+            .then_with(|| self.pre_key().cmp(&other.pre_key()))
+            .then_with(|| self.post.cmp(&other.post))
+            .then_with(|| self.dev_key().cmp(&other.dev_key()))
+            .then_with(|| compare_local(&self.local, &other.local))
+    }
+}
+
+impl<'a> Hash for Pep440<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.epoch.hash(state);
+        self.release_key().hash(state);
+        self.pre_key().hash(state);
+        self.post.hash(state);
+        self.dev_key().hash(state);
+        state.write_usize(self.local.len());
+        for segment in &self.local {
+            match segment {
+                VersionPart::Number(number) => {
+                    state.write_u8(0);
+                    number.hash(state);
+                }
+                VersionPart::Text(text) => {
+                    state.write_u8(1);
+                    for b in text.bytes() {
+                        state.write_u8(b.to_ascii_lowercase());
+                    }
+                    state.write_u8(0xff);
+                }
+            }
+        }
+    }
+}
+
+/// Display the normalized form of the version.
+impl<'a> fmt::Display for Pep440<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}!", self.epoch)?;
+        }
+        for (index, number) in self.release.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", number)?;
+        }
+        if let Some((phase, number)) = self.pre {
+            write!(f, "{}{}", phase.label(), number)?;
+        }
+        if let Some(number) = self.post {
+            write!(f, ".post{}", number)?;
+        }
+        if let Some(number) = self.dev {
+            write!(f, ".dev{}", number)?;
+        }
+        for (index, segment) in self.local.iter().enumerate() {
+            write!(f, "{}", if index == 0 { "+" } else { "." })?;
+            match segment {
+                VersionPart::Number(number) => write!(f, "{}", number)?,
+                VersionPart::Text(text) => write!(f, "{}", text.to_ascii_lowercase())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compare two versions following PEP 440.
+///
+/// `None` is returned if either version doesn't use the PEP 440 scheme.
+pub(crate) fn compare(version: &Version, other: &Version) -> Option<CompOp> {
+    match (version.parsed(), other.parsed()) {
+        (Parsed::Pep440(version), Parsed::Pep440(other)) => {
+            Some(CompOp::from_ord(version.cmp(other)))
+        }
+        _ => None,
+    }
+}
+
+/// Compare local version labels segment by segment.
+///
+/// Numeric segments are compared numerically and sort after alphanumeric segments, which are
+/// compared case-insensitively. A version without local label sorts first.
+fn compare_local(local: &[VersionPart], other: &[VersionPart]) -> Ordering {
+    local
+        .iter()
+        .zip(other)
+        .map(|(segment, other)| match (segment, other) {
+            (VersionPart::Number(num), VersionPart::Number(other_num)) => num.cmp(other_num),
+            (VersionPart::Number(_), VersionPart::Text(_)) => Ordering::Greater,
+            (VersionPart::Text(_), VersionPart::Number(_)) => Ordering::Less,
+            (VersionPart::Text(text), VersionPart::Text(other_text)) => text
+                .bytes()
+                .map(|b| b.to_ascii_lowercase())
+                .cmp(other_text.bytes().map(|b| b.to_ascii_lowercase())),
+        })
+        .find(|&ord| ord != Ordering::Equal)
+        .unwrap_or_else(|| local.len().cmp(&other.len()))
+}
+
+/// Create an error for a version string that doesn't follow PEP 440 at `offset`.
+fn invalid(offset: usize) -> Error {
+    Error::InvalidFormat {
+        scheme: Scheme::Pep440,
+        offset,
+    }
+}
+
+/// Cursor over a version string, used for parsing.
+struct Cursor<'a> {
+    /// The version string.
+    version: &'a str,
+
+    /// The current byte offset.
+    pos: usize,
+
+    /// The byte offset of the end of the version, without trailing whitespace.
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a cursor for the given version string, skipping surrounding whitespace.
+    fn new(version: &'a str) -> Self {
+        Cursor {
+            version,
+            pos: version.len() - version.trim_start().len(),
+            end: version.trim_end().len(),
+        }
+    }
+
+    /// The remaining part of the version string.
+    fn rest(&self) -> &'a str {
+        &self.version[self.pos.min(self.end)..self.end]
+    }
+
+    /// Consume one character if it's any of the given `chars`.
+    fn eat_any(&mut self, chars: &str) -> bool {
+        match self.rest().chars().next() {
+            Some(c) if chars.contains(c) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether the given separator, followed by a digit, is next.
+    fn peek_number_after(&self, separator: &str) -> bool {
+        self.rest()
+            .strip_prefix(separator)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    }
+
+    /// Consume one of the given `labels` case-insensitively, and return its value.
+    fn eat_label<T: Copy>(&mut self, labels: &[(&str, T)]) -> Option<T> {
+        let rest = self.rest();
+        let &(label, value) = labels.iter().find(|(label, _)| {
+            rest.get(..label.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(label))
+        })?;
+        self.pos += label.len();
+        Some(value)
+    }
+
+    /// Consume a number.
+    fn number(&mut self) -> Result<Option<u64>, Error> {
+        let rest = self.rest();
+        let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return Ok(None);
+        }
+
+        let offset = self.pos;
+        self.pos += len;
+        rest[..len]
+            .parse()
+            .map(Some)
+            .map_err(|_| Error::NumberOverflow { offset })
+    }
+
+    /// Consume a number, which must be present.
+    fn require_number(&mut self) -> Result<u64, Error> {
+        self.number()?.ok_or_else(|| invalid(self.pos))
+    }
+
+    /// Consume the number following a label, with an optional separator. Zero is returned if the
+    /// number is omitted.
+    fn implicit_number(&mut self) -> Result<u64, Error> {
+        self.eat_any("-_.");
+        Ok(self.number()?.unwrap_or(0))
+    }
+
+    /// Consume an alphanumeric local version label segment.
+    fn require_segment(&mut self) -> Result<VersionPart<'a>, Error> {
+        let rest = self.rest();
+        let len = rest.bytes().take_while(u8::is_ascii_alphanumeric).count();
+        if len == 0 {
+            return Err(invalid(self.pos));
+        }
+
+        let segment = &rest[..len];
+        if segment.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(VersionPart::Number(self.require_number()?));
+        }
+        self.pos += len;
+        Ok(VersionPart::Text(Cow::Borrowed(segment)))
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_pep440::{
+        TEST_PEP440_ERROR, TEST_PEP440_NORMALIZED, TEST_PEP440_ORDERED, TEST_PEP440_VERSIONS,
+    };
+    use crate::version_part::VersionPart;
+
+    use super::Pep440;
+
+    #[test]
+    fn parse() {
+        // Test whether parsing works for each test version
+        for version in TEST_PEP440_VERSIONS {
+            assert_eq!(
+                Pep440::parse(version.0).unwrap().parts().len(),
+                version.1,
+                "Parsing {}",
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for version in TEST_PEP440_ERROR {
+            assert!(Pep440::parse(version).is_err(), "Parsing {}", version);
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let invalid = |offset| Error::InvalidFormat {
+            scheme: Scheme::Pep440,
+            offset,
+        };
+
+        assert_eq!(Pep440::parse("").unwrap_err(), invalid(0));
+        assert_eq!(Pep440::parse("1.0-").unwrap_err(), invalid(3));
+        assert_eq!(Pep440::parse("1.0+").unwrap_err(), invalid(4));
+        assert_eq!(Pep440::parse("1.0.alpha1.foo").unwrap_err(), invalid(10));
+        assert_eq!(
+            Pep440::parse("1.99999999999999999999").unwrap_err(),
+            Error::NumberOverflow { offset: 2 }
+        );
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(
+            Pep440::parse("1!2.0-RC1.post2.dev3+ubuntu.1")
+                .unwrap()
+                .parts(),
+            vec![
+                VersionPart::Number(2),
+                VersionPart::Number(0),
+                VersionPart::Text("rc".into()),
+                VersionPart::Number(1),
+                VersionPart::Text("post".into()),
+                VersionPart::Number(2),
+                VersionPart::Text("dev".into()),
+                VersionPart::Number(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        for (version, normalized) in TEST_PEP440_NORMALIZED {
+            let parsed = Pep440::parse(version).unwrap();
+            assert_eq!(&parsed.to_string(), normalized, "Normalizing {}", version);
+
+            // The normalized version must be equal to the original one
+            assert_eq!(parsed, Pep440::parse(normalized).unwrap());
+        }
+    }
+
+    #[test]
+    fn compare() {
+        // Each version must be less than the next one
+        for pair in TEST_PEP440_ORDERED.windows(2) {
+            let version_a = Pep440::parse(pair[0]).unwrap();
+            let version_b = Pep440::parse(pair[1]).unwrap();
+
+            assert!(
+                version_a < version_b,
+                "Testing that {} < {}",
+                pair[0],
+                pair[1]
+            );
+            assert!(
+                version_b > version_a,
+                "Testing that {} > {}",
+                pair[1],
+                pair[0]
+            );
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Pep440) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for (version, normalized) in TEST_PEP440_NORMALIZED {
+            assert_eq!(
+                hash(&Pep440::parse(version).unwrap()),
+                hash(&Pep440::parse(normalized).unwrap()),
+                "Hashing {}",
+                version
+            );
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! RPM scheme, used by Fedora, RHEL and other RPM based distributions.
+//!
+//! Versions have the form `[epoch:]version[-release]`, and the version and release are compared
+//! with the `rpmvercmp` algorithm, giving the same results as `rpmdev-vercmp`.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::{split_runs, Scheme};
+use crate::version::Version;
+use crate::version_part::VersionPart;
+
+/// A parsed RPM version.
+#[derive(Debug)]
+pub(crate) struct Rpm<'a> {
+    /// The original version string.
+    version: &'a str,
+
+    /// The epoch, zero if not specified.
+    pub(crate) epoch: u64,
+
+    /// The version, without epoch and release.
+    upstream: &'a str,
+
+    /// The release, if specified.
+    pub(crate) release: Option<&'a str>,
+}
+
+impl<'a> Rpm<'a> {
+    /// Parse the given version string.
+    ///
+    /// Surrounding whitespace is ignored. The epoch is the leading number before a colon, the
+    /// release follows the last hyphen.
+    pub(crate) fn parse(version: &'a str) -> Result<Self, Error> {
+        let start = version.len() - version.trim_start().len();
+        let trimmed = version.trim();
+        if trimmed.is_empty() {
+            return Err(invalid(start));
+        }
+
+        // The version may not contain embedded whitespace
+        if let Some((offset, ch)) = trimmed.char_indices().find(|(_, c)| c.is_whitespace()) {
+            return Err(Error::InvalidChar {
+                offset: start + offset,
+                ch,
+            });
+        }
+
+        // Parse the epoch, which is only present if the leading digits are followed by a colon
+        let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+        let (epoch, rest, rest_offset) = match trimmed[digits..].strip_prefix(':') {
+            Some(rest) => {
+                let epoch = match digits {
+                    0 => 0,
+                    _ => trimmed[..digits]
+                        .parse()
+                        .map_err(|_| Error::NumberOverflow { offset: start })?,
+                };
+                (epoch, rest, start + digits + 1)
+            }
+            None => (0, trimmed, start),
+        };
+
+        // Split the release at the last hyphen
+        let (upstream, release) = match rest.rfind('-') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+        if upstream.is_empty() {
+            return Err(invalid(rest_offset));
+        }
+        if release == Some("") {
+            return Err(invalid(rest_offset + rest.len()));
+        }
+
+        Ok(Rpm {
+            version,
+            epoch,
+            upstream,
+            release,
+        })
+    }
+
+    /// Get the version parts.
+    ///
+    /// These are the alphanumeric segments of the version and release, split into numeric and
+    /// text parts. The epoch is not included.
+    pub(crate) fn parts(&self) -> Result<Vec<VersionPart<'a>>, Error> {
+        let sections: Vec<_> = [Some(self.upstream), self.release]
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        split_runs(self.version, &sections)
+    }
+}
+
+impl<'a> PartialEq for Rpm<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Rpm<'a> {}
+
+impl<'a> PartialOrd for Rpm<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare the epoch, version and release, like `rpmdev-vercmp`.
+///
+/// A version without release sorts before the same version with any release.
+impl<'a> Ord for Rpm<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| rpmvercmp(self.upstream, other.upstream))
+            .then_with(|| match (self.release, other.release) {
+                (Some(release), Some(other_release)) => rpmvercmp(release, other_release),
+                (release, other_release) => release.is_some().cmp(&other_release.is_some()),
+            })
+    }
+}
+
+impl<'a> Hash for Rpm<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.epoch.hash(state);
+        for segment in Segments(self.upstream) {
+            segment.hash(state);
+        }
+        match self.release {
+            Some(release) => {
+                state.write_u8(1);
+                for segment in Segments(release) {
+                    segment.hash(state);
+                }
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+/// Compare two versions like `rpmdev-vercmp`.
+///
+/// `None` is returned if either version isn't a valid RPM version.
+pub(crate) fn compare(version: &Version, other: &Version) -> Option<CompOp> {
+    let version = Rpm::parse(version.as_str()).ok()?;
+    let other = Rpm::parse(other.as_str()).ok()?;
+    Some(CompOp::from_ord(version.cmp(&other)))
+}
+
+/// A segment of a version or release, as used by `rpmvercmp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Segment<'a> {
+    /// A tilde, which sorts before anything, even the end of the version.
+    Tilde,
+
+    /// A caret, which sorts after the end of the version, but before anything else.
+    Caret,
+
+    /// A run of digits, without leading zeros.
+    Number(&'a str),
+
+    /// A run of letters.
+    Alpha(&'a str),
+}
+
+/// Iterator over the segments of a version or release.
+///
+/// Characters other than letters, digits, `~` and `^` are separators, and are skipped.
+struct Segments<'a>(&'a str);
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        let start = self
+            .0
+            .find(|c: char| c.is_ascii_alphanumeric() || c == '~' || c == '^')?;
+        let rest = &self.0[start..];
+
+        let (segment, len) = match rest.as_bytes()[0] {
+            b'~' => (Segment::Tilde, 1),
+            b'^' => (Segment::Caret, 1),
+            b if b.is_ascii_digit() => {
+                let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+                (Segment::Number(rest[..len].trim_start_matches('0')), len)
+            }
+            _ => {
+                let len = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+                (Segment::Alpha(&rest[..len]), len)
+            }
+        };
+
+        self.0 = &rest[len..];
+        Some(segment)
+    }
+}
+
+/// Compare two versions or releases, using the `rpmvercmp` algorithm.
+///
+/// Segments are compared one by one. Numeric segments are compared numerically and are newer
+/// than alphabetic segments, which are compared lexically. A `~` sorts before anything, a `^`
+/// sorts after the end of the other version but before any other segment. If all segments are
+/// equal, the version with segments left is newer.
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    let mut a = Segments(a);
+    let mut b = Segments(b);
+
+    loop {
+        let ord = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (Some(Segment::Tilde), Some(Segment::Tilde)) => continue,
+            (Some(Segment::Tilde), _) => Ordering::Less,
+            (_, Some(Segment::Tilde)) => Ordering::Greater,
+            (Some(Segment::Caret), Some(Segment::Caret)) => continue,
+            (Some(Segment::Caret), None) => Ordering::Greater,
+            (None, Some(Segment::Caret)) => Ordering::Less,
+            (Some(Segment::Caret), _) => Ordering::Less,
+            (_, Some(Segment::Caret)) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(Segment::Number(_)), Some(Segment::Alpha(_))) => Ordering::Greater,
+            (Some(Segment::Alpha(_)), Some(Segment::Number(_))) => Ordering::Less,
+            (Some(Segment::Number(num)), Some(Segment::Number(other_num))) => num
+                .len()
+                .cmp(&other_num.len())
+                .then_with(|| num.cmp(other_num)),
+            (Some(Segment::Alpha(text)), Some(Segment::Alpha(other_text))) => text.cmp(other_text),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+/// Create an error for a version string that doesn't follow the RPM format at `offset`.
+fn invalid(offset: usize) -> Error {
+    Error::InvalidFormat {
+        scheme: Scheme::Rpm,
+        offset,
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_rpm::{TEST_RPM_ERROR, TEST_RPM_SETS, TEST_RPM_VERSIONS};
+
+    use super::Rpm;
+
+    #[test]
+    fn parse() {
+        // Test whether parsing works for each test version
+        for version in TEST_RPM_VERSIONS {
+            assert_eq!(
+                Rpm::parse(version.0).unwrap().parts().unwrap().len(),
+                version.1,
+                "Parsing {}",
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for version in TEST_RPM_ERROR {
+            assert!(Rpm::parse(version).is_err(), "Parsing {}", version);
+        }
+    }
+
+    #[test]
+    fn parse_error() {
+        let invalid = |offset| Error::InvalidFormat {
+            scheme: Scheme::Rpm,
+            offset,
+        };
+
+        assert_eq!(Rpm::parse("").unwrap_err(), invalid(0));
+        assert_eq!(Rpm::parse("1:").unwrap_err(), invalid(2));
+        assert_eq!(Rpm::parse("1.0-").unwrap_err(), invalid(4));
+        assert_eq!(
+            Rpm::parse("1.0 1").unwrap_err(),
+            Error::InvalidChar { offset: 3, ch: ' ' }
+        );
+        assert_eq!(
+            Rpm::parse("99999999999999999999:1.0").unwrap_err(),
+            Error::NumberOverflow { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn parse_sections() {
+        let version = Rpm::parse("2:1.0~rc1-3.fc39").unwrap();
+        assert_eq!(version.epoch, 2);
+        assert_eq!(version.upstream, "1.0~rc1");
+        assert_eq!(version.release, Some("3.fc39"));
+
+        // The epoch must be numeric
+        let version = Rpm::parse("a:1.0").unwrap();
+        assert_eq!(version.epoch, 0);
+        assert_eq!(version.upstream, "a:1.0");
+        assert_eq!(version.release, None);
+
+        let version = Rpm::parse(":1.0").unwrap();
+        assert_eq!(version.epoch, 0);
+        assert_eq!(version.upstream, "1.0");
+    }
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in TEST_RPM_SETS {
+            let version_a = Rpm::parse(entry.0).unwrap();
+            let version_b = Rpm::parse(entry.1).unwrap();
+
+            assert_eq!(
+                CompOp::from_ord(version_a.cmp(&version_b)),
+                entry.2,
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1
+            );
+            assert_eq!(
+                CompOp::from_ord(version_b.cmp(&version_a)),
+                entry.2.flip(),
+                "Testing that {} is {} {}",
+                entry.1,
+                entry.2.flip().sign(),
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Rpm) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Equal versions must have equal hashes
+        for entry in TEST_RPM_SETS {
+            if entry.2 == CompOp::Eq {
+                assert_eq!(
+                    hash(&Rpm::parse(entry.0).unwrap()),
+                    hash(&Rpm::parse(entry.1).unwrap()),
+                    "Testing that {} and {} have an equal hash",
+                    entry.0,
+                    entry.1
+                );
+            }
+        }
+    }
+}
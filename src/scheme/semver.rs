@@ -0,0 +1,315 @@
+//! Semantic Versioning 2.0.0 scheme.
+//!
+//! Versions are parsed and compared following the rules of the
+//! [specification](https://semver.org/spec/v2.0.0.html).
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::Scheme;
+use crate::version::Version;
+use crate::version_part::VersionPart;
+
+/// The number of parts in the version core: major, minor and patch.
+const CORE_PARTS: usize = 3;
+
+/// Split a version string into its version core, pre-release and build metadata sections.
+///
+/// The `-` and `+` delimiters aren't included in the returned sections.
+pub(crate) fn sections(version: &str) -> (&str, Option<&str>, Option<&str>) {
+    let (rest, build) = match version.find('+') {
+        Some(index) => (&version[..index], Some(&version[index + 1..])),
+        None => (version, None),
+    };
+    let (core, pre_release) = match rest.find('-') {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+    };
+    (core, pre_release, build)
+}
+
+/// Parse the given semver string into its version parts.
+///
+/// The parts are the major, minor and patch number, followed by the pre-release identifiers.
+/// Build metadata is validated, but not included in the parts.
+pub(crate) fn split(version: &str) -> Result<Vec<VersionPart<'_>>, Error> {
+    // Make sure the version string only contains allowed characters
+    if let Some((offset, ch)) = version
+        .char_indices()
+        .find(|&(_, c)| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '-' | '+'))
+    {
+        return Err(Error::InvalidChar { offset, ch });
+    }
+
+    let (core, pre_release, build) = sections(version);
+    let mut parts = Vec::with_capacity(CORE_PARTS);
+
+    // Parse the major, minor and patch number
+    for (offset, identifier) in identifiers(core, 0) {
+        if parts.len() == CORE_PARTS || !is_numeric(identifier) {
+            return Err(invalid(offset));
+        }
+        parts.push(number(identifier, offset)?);
+    }
+    if parts.len() < CORE_PARTS {
+        return Err(invalid(core.len()));
+    }
+
+    // Parse the pre-release identifiers
+    let mut offset = core.len() + 1;
+    if let Some(pre_release) = pre_release {
+        for (offset, identifier) in identifiers(pre_release, offset) {
+            if identifier.is_empty() {
+                return Err(invalid(offset));
+            }
+            if is_numeric(identifier) {
+                parts.push(number(identifier, offset)?);
+            } else {
+                parts.push(VersionPart::Text(Cow::Borrowed(identifier)));
+            }
+        }
+        offset += pre_release.len() + 1;
+    }
+
+    // Validate the build metadata identifiers, which may have leading zeros
+    if let Some(build) = build {
+        for (offset, identifier) in identifiers(build, offset) {
+            if identifier.is_empty() {
+                return Err(invalid(offset));
+            }
+            if let Some(index) = identifier.find('+') {
+                return Err(Error::InvalidChar {
+                    offset: offset + index,
+                    ch: '+',
+                });
+            }
+        }
+    }
+
+    Ok(parts)
+}
+
+/// Compare two semver versions by their precedence.
+///
+/// Build metadata is ignored. This method returns `Lt`, `Eq` or `Gt`.
+pub(crate) fn compare(version: &Version, other: &Version) -> CompOp {
+    let (core, pre_release) = split_parts(version.parts());
+    let (other_core, other_pre_release) = split_parts(other.parts());
+
+    // Compare the version core, then the pre-release identifiers
+    let ord = compare_identifiers(core, other_core)
+        .then_with(|| compare_pre_release(pre_release, other_pre_release));
+
+    CompOp::from_ord(ord)
+}
+
+/// Compare two lists of pre-release identifiers by their precedence.
+///
+/// A version without pre-release identifiers has higher precedence than one with identifiers.
+pub(crate) fn compare_pre_release(pre_release: &[VersionPart], other: &[VersionPart]) -> Ordering {
+    match (pre_release.is_empty(), other.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => compare_identifiers(pre_release, other),
+    }
+}
+
+/// Split version parts into the version core and pre-release identifiers.
+fn split_parts<'a, 'b>(
+    parts: &'b [VersionPart<'a>],
+) -> (&'b [VersionPart<'a>], &'b [VersionPart<'a>]) {
+    parts.split_at(parts.len().min(CORE_PARTS))
+}
+
+/// Compare two lists of identifiers one by one, a larger set of identifiers has a higher
+/// precedence if all preceding identifiers are equal.
+fn compare_identifiers(identifiers: &[VersionPart], other: &[VersionPart]) -> Ordering {
+    identifiers
+        .iter()
+        .zip(other)
+        .map(|(identifier, other)| compare_identifier(identifier, other))
+        .find(|&ord| ord != Ordering::Equal)
+        .unwrap_or_else(|| identifiers.len().cmp(&other.len()))
+}
+
+/// Compare two identifiers.
+///
+/// Numeric identifiers are compared numerically and always have lower precedence than
+/// alphanumeric identifiers, which are compared lexically in ASCII sort order.
+fn compare_identifier(identifier: &VersionPart, other: &VersionPart) -> Ordering {
+    match (identifier, other) {
+        (VersionPart::Number(num), VersionPart::Number(other_num)) => num.cmp(other_num),
+        (VersionPart::Number(_), VersionPart::Text(_)) => Ordering::Less,
+        (VersionPart::Text(_), VersionPart::Number(_)) => Ordering::Greater,
+        (VersionPart::Text(text), VersionPart::Text(other_text)) => text.cmp(other_text),
+    }
+}
+
+/// Iterate over the dot separated identifiers in a section, along with their byte offset.
+///
+/// The `offset` of the section in the version string is added to each identifier offset.
+pub(crate) fn identifiers(section: &str, offset: usize) -> impl Iterator<Item = (usize, &str)> {
+    section.split('.').scan(offset, |offset, identifier| {
+        let start = *offset;
+        *offset += identifier.len() + 1;
+        Some((start, identifier))
+    })
+}
+
+/// Check whether the given identifier is numeric.
+fn is_numeric(identifier: &str) -> bool {
+    !identifier.is_empty() && identifier.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse a numeric identifier at the given byte `offset`, which must not have leading zeros.
+fn number(identifier: &str, offset: usize) -> Result<VersionPart<'static>, Error> {
+    if identifier.len() > 1 && identifier.starts_with('0') {
+        return Err(invalid(offset));
+    }
+
+    identifier
+        .parse()
+        .map(VersionPart::Number)
+        .map_err(|_| Error::NumberOverflow { offset })
+}
+
+/// Create an error for a version string that doesn't follow the semver format at `offset`.
+fn invalid(offset: usize) -> Error {
+    Error::InvalidFormat {
+        scheme: Scheme::Semver,
+        offset,
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::Scheme;
+    use crate::test::test_semver::{TEST_SEMVER_ERROR, TEST_SEMVER_SETS, TEST_SEMVER_VERSIONS};
+    use crate::version::Version;
+    use crate::version_part::VersionPart;
+
+    use super::sections;
+
+    #[test]
+    fn sections_of() {
+        assert_eq!(sections("1.2.3"), ("1.2.3", None, None));
+        assert_eq!(sections("1.2.3-rc.1"), ("1.2.3", Some("rc.1"), None));
+        assert_eq!(
+            sections("1.2.3-rc-1+build-5"),
+            ("1.2.3", Some("rc-1"), Some("build-5"))
+        );
+        assert_eq!(sections("1.2.3+build-5"), ("1.2.3", None, Some("build-5")));
+    }
+
+    #[test]
+    fn split() {
+        // Test whether parsing works for each test version
+        for version in TEST_SEMVER_VERSIONS {
+            assert_eq!(
+                super::split(version.0).unwrap().len(),
+                version.1,
+                "Parsing {}",
+                version.0
+            );
+        }
+
+        // Test whether parsing fails for each invalid test version
+        for version in TEST_SEMVER_ERROR {
+            assert!(super::split(version).is_err(), "Parsing {}", version);
+        }
+    }
+
+    #[test]
+    fn split_parts() {
+        assert_eq!(
+            super::split("1.0.0-alpha.1+001").unwrap(),
+            vec![
+                VersionPart::Number(1),
+                VersionPart::Number(0),
+                VersionPart::Number(0),
+                VersionPart::Text("alpha".into()),
+                VersionPart::Number(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_error() {
+        let invalid = |offset| Error::InvalidFormat {
+            scheme: Scheme::Semver,
+            offset,
+        };
+
+        assert_eq!(super::split("1.2"), Err(invalid(3)));
+        assert_eq!(super::split("1.2.3.4"), Err(invalid(6)));
+        assert_eq!(super::split("1.02.3"), Err(invalid(2)));
+        assert_eq!(super::split("1.2.3-alpha..1"), Err(invalid(12)));
+        assert_eq!(super::split("1.2.3-01"), Err(invalid(6)));
+        assert_eq!(super::split("1.2.3+"), Err(invalid(6)));
+        assert_eq!(
+            super::split("1.2.3+a+b"),
+            Err(Error::InvalidChar { offset: 7, ch: '+' })
+        );
+        assert_eq!(
+            super::split("1.2.3_4"),
+            Err(Error::InvalidChar { offset: 5, ch: '_' })
+        );
+        assert_eq!(
+            super::split("1.2.99999999999999999999"),
+            Err(Error::NumberOverflow { offset: 4 })
+        );
+    }
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in TEST_SEMVER_SETS {
+            let version_a = Version::from_scheme(entry.0, Scheme::Semver).unwrap();
+            let version_b = Version::from_scheme(entry.1, Scheme::Semver).unwrap();
+
+            assert_eq!(
+                version_a.compare(&version_b),
+                entry.2,
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1
+            );
+            assert_eq!(
+                version_b.compare(&version_a),
+                entry.2.flip(),
+                "Testing that {} is {} {}",
+                entry.1,
+                entry.2.flip().sign(),
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn compare_precedence() {
+        // The precedence example from the specification
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+
+        for pair in versions.windows(2) {
+            let version_a = Version::from_scheme(pair[0], Scheme::Semver).unwrap();
+            let version_b = Version::from_scheme(pair[1], Scheme::Semver).unwrap();
+            assert_eq!(version_a.compare(&version_b), CompOp::Lt);
+        }
+    }
+}
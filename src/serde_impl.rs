@@ -0,0 +1,185 @@
+//! Serde module, with serialization support for versions and their configuration.
+//!
+//! This module is only available with the `serde` feature. Versions, comparison operators and
+//! calendar versioning formats are serialized as string, and are parsed again when deserialized.
+//! Version parts, schemes and manifests derive their implementations.
+
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::scheme::CalverFormat;
+use crate::version::Version;
+
+/// Visitor that parses a string using the given function.
+struct ParseVisitor<T> {
+    /// What the string should hold, used in error messages.
+    expecting: &'static str,
+
+    /// The function that parses the string.
+    parse: fn(&str) -> Result<T, Error>,
+}
+
+impl<'de, T> Visitor<'de> for ParseVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        (self.parse)(value).map_err(E::custom)
+    }
+}
+
+/// Serialize a version as its original string, see `Version::as_str`.
+impl<'a> Serialize for Version<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserialize a version by parsing it following the generic scheme.
+///
+/// The deserialized version owns its version string, so it doesn't borrow from the input.
+impl<'de, 'a> Deserialize<'de> for Version<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expecting: "a version string",
+            parse: |version| Version::from_string(version.to_owned()),
+        })
+    }
+}
+
+/// Serialize a comparison operator as its sign, see `CompOp::sign`.
+impl Serialize for CompOp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.sign())
+    }
+}
+
+/// Deserialize a comparison operator from its sign or name, such as `<=` or `le`.
+impl<'de> Deserialize<'de> for CompOp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expecting: "a comparison operator sign or name",
+            parse: |op| CompOp::from_sign(op).or_else(|_| CompOp::from_name(op)),
+        })
+    }
+}
+
+/// Serialize a calendar versioning format as its format string, see `CalverFormat::as_str`.
+impl Serialize for CalverFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserialize a calendar versioning format by parsing its format string.
+impl<'de> Deserialize<'de> for CalverFormat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expecting: "a calver format string",
+            parse: CalverFormat::from,
+        })
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::comp_op::CompOp;
+    use crate::scheme::{CalverFormat, Scheme};
+    use crate::test::test_version::TEST_VERSIONS;
+    use crate::version::Version;
+    use crate::version_manifest::{CharPolicy, VersionManifest};
+    use crate::version_part::VersionPart;
+
+    #[test]
+    fn version() {
+        for version in TEST_VERSIONS {
+            let ver = Version::from(version.0).unwrap();
+            let json = serde_json::to_string(&ver).unwrap();
+            assert_eq!(json, serde_json::to_string(version.0).unwrap());
+
+            let deserialized: Version = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized.as_str(), version.0);
+            assert_eq!(deserialized.parts(), ver.parts());
+        }
+    }
+
+    #[test]
+    fn version_error() {
+        let err = serde_json::from_str::<Version>("\"abc\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("version does not contain a numeric part"));
+
+        assert!(serde_json::from_str::<Version>("1.2").is_err());
+    }
+
+    #[test]
+    fn version_part() {
+        let parts = Version::from("1.2-rc1").unwrap().parts().to_vec();
+        let json = serde_json::to_string(&parts).unwrap();
+        assert_eq!(json, "[1,2,\"rc1\"]");
+
+        let deserialized: Vec<VersionPart> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, parts);
+    }
+
+    #[test]
+    fn comp_op() {
+        assert_eq!(serde_json::to_string(&CompOp::Le).unwrap(), "\"<=\"");
+        assert_eq!(serde_json::to_string(&CompOp::Eq).unwrap(), "\"==\"");
+
+        assert_eq!(
+            serde_json::from_str::<CompOp>("\"<=\"").unwrap(),
+            CompOp::Le
+        );
+        assert_eq!(
+            serde_json::from_str::<CompOp>("\"ne\"").unwrap(),
+            CompOp::Ne
+        );
+        assert_eq!(
+            serde_json::from_str::<CompOp>("\"GT\"").unwrap(),
+            CompOp::Gt
+        );
+        assert!(serde_json::from_str::<CompOp>("\"=>\"").is_err());
+    }
+
+    #[test]
+    fn manifest() {
+        let manifest = VersionManifest::new()
+            .with_max_depth(Some(3))
+            .with_separators(Some(&['.', '-']))
+            .with_other_chars(CharPolicy::Ignore)
+            .with_scheme(Scheme::Calver(CalverFormat::from("YYYY.0M").unwrap()));
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(json.contains("\"scheme\":{\"calver\":\"YYYY.0M\"}"));
+        assert!(json.contains("\"other_chars\":\"ignore\""));
+        assert_eq!(
+            serde_json::from_str::<VersionManifest>(&json).unwrap(),
+            manifest
+        );
+
+        // Options that aren't specified keep their default value
+        let manifest: VersionManifest =
+            serde_json::from_str("{ \"scheme\": \"semver\", \"ignore_text\": true }").unwrap();
+        assert_eq!(manifest, VersionManifest::semver().with_ignore_text(true));
+        assert_eq!(
+            serde_json::from_str::<VersionManifest>("{}").unwrap(),
+            VersionManifest::new()
+        );
+
+        // Invalid options are rejected
+        assert!(serde_json::from_str::<VersionManifest>("{ \"scheme\": \"npm\" }").is_err());
+        assert!(
+            serde_json::from_str::<VersionManifest>("{ \"scheme\": { \"calver\": \"MM\" } }")
+                .is_err()
+        );
+    }
+}
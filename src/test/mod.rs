@@ -1,2 +1,12 @@
+pub mod test_calver;
+pub mod test_cargo_req;
+pub mod test_debian;
+pub mod test_maven;
+pub mod test_maven_range;
+pub mod test_pep440;
+pub mod test_rpm;
+pub mod test_semver;
 pub mod test_version;
+pub mod test_version_find;
+pub mod test_version_range;
 pub mod test_version_set;
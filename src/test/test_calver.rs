@@ -0,0 +1,185 @@
+use crate::comp_op::CompOp;
+
+/// Struct containing a calendar version format, a version and the number of its parts, for
+/// dynamic tests
+#[derive(Debug)]
+pub struct TestCalverVersion(pub &'static str, pub &'static str, pub usize);
+
+/// Struct containing two calendar versions with their formats and the expected comparison
+/// operator, for dynamic tests
+#[derive(Debug)]
+pub struct TestCalverSet(
+    pub &'static str,
+    pub &'static str,
+    pub &'static str,
+    pub &'static str,
+    pub CompOp,
+);
+
+/// List of calendar versions with their format and number of parts, for dynamic tests
+pub const TEST_CALVER_VERSIONS: &[TestCalverVersion] = &[
+    TestCalverVersion("YYYY.0M.MICRO", "2024.03.1", 3),
+    TestCalverVersion("YYYY.0M.MICRO", "2024.03", 2),
+    TestCalverVersion("YY.0M", "24.04", 2),
+    TestCalverVersion("YY.MM", "6.1", 2),
+    TestCalverVersion("0Y.0M", "06.01", 2),
+    TestCalverVersion("YYYY.0M.0D-MICRO", "2023.10.15-1", 4),
+    TestCalverVersion("YYYY.MM.DD", "2024.2.29", 3),
+    TestCalverVersion("YYYY.0W", "2024.53", 2),
+    TestCalverVersion("YY.WW.MICRO", "24.9.0", 3),
+    TestCalverVersion("YYYY_0M_0D", "2024_01_31", 3),
+    TestCalverVersion("MAJOR.YYYY.MINOR", "3.2024.1", 3),
+    TestCalverVersion("YYYY.MINOR.MICRO-MODIFIER", "2024.1.0-rc1", 4),
+    TestCalverVersion("YYYY.MINOR.MICRO-MODIFIER", "2024.1-dev", 3),
+    TestCalverVersion("YYYY.MINOR.MICRO-MODIFIER", "2024", 1),
+];
+
+/// List of calendar versions that don't follow their format, for dynamic tests
+pub const TEST_CALVER_ERROR: &[(&str, &str)] = &[
+    ("YYYY.0M", ""),
+    ("YYYY.0M", "2024"),
+    ("YYYY.0M", "2024.13"),
+    ("YYYY.0M", "2024.00"),
+    ("YYYY.0M", "2024.3"),
+    ("YYYY.MM", "2024.03"),
+    ("YYYY.0M", "24.03"),
+    ("YY.0M", "024.03"),
+    ("0Y.0M", "6.03"),
+    ("YYYY.0M.0D", "2024.04.31"),
+    ("YYYY.0M.0D", "2023.02.29"),
+    ("YYYY.0M.0D", "2024.01.00"),
+    ("YYYY.0W", "2024.54"),
+    ("YYYY.0M.MICRO", "2024.03."),
+    ("YYYY.0M.MICRO", "2024.03-1"),
+    ("YYYY.0M.MICRO", "2024.03.1.2"),
+    ("YYYY.0M.MICRO", "2024.03.a"),
+    ("YYYY.MICRO-MODIFIER", "2024.1-"),
+    ("YYYY.MICRO-MODIFIER", "2024.1-rc.1"),
+];
+
+/// List of invalid calendar version formats, for dynamic tests
+pub const TEST_CALVER_FORMATS_ERROR: &[&str] = &[
+    "",
+    "MAJOR.MINOR",
+    "YYYY.YY",
+    "YYYY.MM.0M",
+    "YYYY.DD",
+    "YYYY.MM.WW",
+    "YYYY.WW.DD",
+    "YYYY.MODIFIER.MICRO",
+    "YYYY.MONTH",
+    "YYYY..MM",
+    "YYYY.",
+    "YYYY/MM",
+    "yyyy.mm",
+];
+
+/// List of calendar version sets with their formats, for dynamic tests
+pub const TEST_CALVER_SETS: &[TestCalverSet] = &[
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.03.1",
+        "YYYY.0M.MICRO",
+        "2024.03.1",
+        CompOp::Eq,
+    ),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.03",
+        "YYYY.0M.MICRO",
+        "2024.03.0",
+        CompOp::Eq,
+    ),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.03",
+        "YYYY.0M.MICRO",
+        "2024.03.1",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.03.9",
+        "YYYY.0M.MICRO",
+        "2024.04",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2023.12.10",
+        "YYYY.0M.MICRO",
+        "2024.01",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.03.2",
+        "YYYY.0M.MICRO",
+        "2024.03.10",
+        CompOp::Lt,
+    ),
+    TestCalverSet("YY.0M", "24.04", "YYYY.0M", "2024.04", CompOp::Eq),
+    TestCalverSet("YY.MM", "24.4", "0Y.0M", "24.04", CompOp::Eq),
+    TestCalverSet("YY.0M", "24.04", "YYYY.0M.MICRO", "2024.04.0", CompOp::Eq),
+    TestCalverSet("YY.0M", "99.12", "YYYY.0M", "2100.01", CompOp::Lt),
+    TestCalverSet("YY.0M", "106.01", "YYYY.0M", "2105.12", CompOp::Gt),
+    TestCalverSet(
+        "YYYY.0M.0D-MICRO",
+        "2023.10.15-1",
+        "YYYY.MM.DD",
+        "2023.10.15",
+        CompOp::Gt,
+    ),
+    TestCalverSet(
+        "YYYY.0M.0D-MICRO",
+        "2023.10.15-1",
+        "YYYY.0M.0D-MICRO",
+        "2023.10.16",
+        CompOp::Lt,
+    ),
+    TestCalverSet("YYYY.0W", "2024.09", "YY.WW", "24.9", CompOp::Eq),
+    // Other mixed formats are compared by position, not by date
+    TestCalverSet("YYYY.0M", "2024.04", "YYYY.0W", "2024.15", CompOp::Lt),
+    TestCalverSet(
+        "YYYY.0M.MICRO",
+        "2024.04.1",
+        "YYYY.0M.0D",
+        "2024.04.01",
+        CompOp::Eq,
+    ),
+    TestCalverSet(
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-dev",
+        "YYYY.MICRO-MODIFIER",
+        "2024.1",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-alpha",
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-beta",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-rc1",
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-rc2",
+        CompOp::Lt,
+    ),
+    TestCalverSet(
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-RC1",
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-rc1",
+        CompOp::Eq,
+    ),
+    TestCalverSet(
+        "YYYY.MICRO-MODIFIER",
+        "2024.1-rc1",
+        "YYYY.MICRO-MODIFIER",
+        "2024.0",
+        CompOp::Gt,
+    ),
+];
@@ -0,0 +1,144 @@
+use crate::test::test_version_range::TestVersionRange;
+
+/// List of Cargo requirements with versions for dynamic tests, with results of Cargo
+pub const TEST_CARGO_REQS: &[TestVersionRange] = &[
+    // Wildcards
+    TestVersionRange("*", "0.0.1", true),
+    TestVersionRange("*", "1.2.3", true),
+    TestVersionRange("*", "1.2.3-alpha", false),
+    TestVersionRange("x", "9.0.0", true),
+    TestVersionRange("1.*", "1.9.0", true),
+    TestVersionRange("1.*", "2.0.0", false),
+    TestVersionRange("1.*.*", "1.0.3", true),
+    TestVersionRange("1.2.*", "1.2.9", true),
+    TestVersionRange("1.2.*", "1.3.0", false),
+    TestVersionRange("1.2.X", "1.2.0-rc.1", false),
+    TestVersionRange(">=1.*", "3.0.0", true),
+    TestVersionRange("<1.*", "0.9.0", true),
+    TestVersionRange("<1.*", "1.0.0", false),
+    // Default caret requirements
+    TestVersionRange("1.2", "1.2.0", true),
+    TestVersionRange("1.2", "1.9.0", true),
+    TestVersionRange("1.2", "1.1.9", false),
+    TestVersionRange("1.2", "2.0.0", false),
+    TestVersionRange("1.2.3", "1.2.3", true),
+    TestVersionRange("1.2.3", "1.2.4", true),
+    TestVersionRange("1.2.3", "1.2.2", false),
+    TestVersionRange("1", "1.9.9", true),
+    TestVersionRange("1", "2.0.0", false),
+    // Caret requirements
+    TestVersionRange("^1.2.3", "1.8.0", true),
+    TestVersionRange("^1.2.3", "2.0.0", false),
+    TestVersionRange("^1.2.3", "1.2.3-beta", false),
+    TestVersionRange("^0.3", "0.3.0", true),
+    TestVersionRange("^0.3", "0.3.99", true),
+    TestVersionRange("^0.3", "0.4.0", false),
+    TestVersionRange("^0.3", "0.2.9", false),
+    TestVersionRange("^0.2.3", "0.2.3", true),
+    TestVersionRange("^0.2.3", "0.2.9", true),
+    TestVersionRange("^0.2.3", "0.2.2", false),
+    TestVersionRange("^0.2.3", "0.3.0", false),
+    TestVersionRange("^0.0.3", "0.0.3", true),
+    TestVersionRange("^0.0.3", "0.0.4", false),
+    TestVersionRange("^0.0", "0.0.9", true),
+    TestVersionRange("^0.0", "0.1.0", false),
+    TestVersionRange("^0", "0.9.9", true),
+    TestVersionRange("^0", "1.0.0", false),
+    TestVersionRange("^ 1.2", "1.3.0", true),
+    // Tilde requirements
+    TestVersionRange("~1.2.3", "1.2.3", true),
+    TestVersionRange("~1.2.3", "1.2.9", true),
+    TestVersionRange("~1.2.3", "1.2.2", false),
+    TestVersionRange("~1.2.3", "1.3.0", false),
+    TestVersionRange("~1.2", "1.2.0", true),
+    TestVersionRange("~1.2", "1.3.0", false),
+    TestVersionRange("~1", "1.9.0", true),
+    TestVersionRange("~1", "2.0.0", false),
+    TestVersionRange("~0.2.3", "0.2.5", true),
+    TestVersionRange("~1.2.3-beta", "1.2.3-beta.2", true),
+    TestVersionRange("~1.2.3-beta", "1.2.3-alpha", false),
+    TestVersionRange("~1.2.3-beta", "1.2.4", true),
+    // Exact requirements
+    TestVersionRange("=1.0.0", "1.0.0", true),
+    TestVersionRange("=1.0.0", "1.0.1", false),
+    TestVersionRange("=1.0.0", "1.0.0-rc.1", false),
+    TestVersionRange("=1.0.0", "1.0.0+build.5", true),
+    TestVersionRange("=1.0", "1.0.7", true),
+    TestVersionRange("=1.0", "1.1.0", false),
+    TestVersionRange("=1", "1.4.0", true),
+    TestVersionRange("=0.1.0-beta2.a", "0.1.0-beta2.a", true),
+    TestVersionRange("=1.2.3+build", "1.2.3", true),
+    // Comparison requirements
+    TestVersionRange(">1.2.3", "1.2.4", true),
+    TestVersionRange(">1.2.3", "1.2.3", false),
+    TestVersionRange(">1.2", "1.2.9", false),
+    TestVersionRange(">1.2", "1.3.0", true),
+    TestVersionRange(">1", "1.9.0", false),
+    TestVersionRange(">1", "2.0.0", true),
+    TestVersionRange(">=1.2.3", "1.2.3", true),
+    TestVersionRange(">=1.2", "1.2.0", true),
+    TestVersionRange(">=1.2", "1.1.9", false),
+    TestVersionRange("<1.2.3", "1.2.2", true),
+    TestVersionRange("<1.2.3", "1.2.3-rc.1", false),
+    TestVersionRange("<1.2", "1.2.0", false),
+    TestVersionRange("<1.2", "1.1.9", true),
+    TestVersionRange("<=1.2", "1.2.9", true),
+    TestVersionRange("<=1.2", "1.3.0", false),
+    TestVersionRange("<=1.2.3", "1.2.3", true),
+    TestVersionRange(">= 1.2.3", "1.3.0", true),
+    // Comparator sets
+    TestVersionRange(">=1.2, <1.5", "1.2.0", true),
+    TestVersionRange(">=1.2, <1.5", "1.4.9", true),
+    TestVersionRange(">=1.2, <1.5", "1.5.0", false),
+    TestVersionRange(">=1.2, <1.5", "1.1.0", false),
+    TestVersionRange(">= 1.2 , < 1.5", "1.3.0", true),
+    TestVersionRange(">=1.2,<1.5", "1.3.0", true),
+    TestVersionRange(">=1.0.0, <2.0.0", "1.5.0-alpha", false),
+    // Pre-release opt-in
+    TestVersionRange(">=1.0.0-alpha", "1.0.0-beta", true),
+    TestVersionRange(">=1.0.0-alpha", "1.0.0", true),
+    TestVersionRange(">=1.0.0-alpha", "1.0.1-beta", false),
+    TestVersionRange(">=1.0.0-alpha", "1.0.0-alpha", true),
+    TestVersionRange(">1.0.0-alpha", "1.0.0-alpha.1", true),
+    TestVersionRange(">1.0.0-alpha", "1.0.0-alpha", false),
+    TestVersionRange("<1.0.0-rc.1", "1.0.0-beta.11", true),
+    TestVersionRange("<1.0.0-rc.1", "1.0.0-rc.1", false),
+    TestVersionRange(">=1.0.0-rc.2, <2.0.0", "1.0.0-rc.10", true),
+    TestVersionRange(">=1.0.0-rc.2, <2.0.0", "1.0.0-rc.1", false),
+    TestVersionRange(">=1.0.0-rc.2, <2.0.0", "1.5.0-rc.1", false),
+    TestVersionRange(">=1.0.0, <2.0.0-0", "1.9.9", true),
+    TestVersionRange(">=1.0.0, <2.0.0-0", "2.0.0-alpha", false),
+    TestVersionRange("^1.2.3-alpha", "1.2.3-beta", true),
+    TestVersionRange("^1.2.3-alpha", "1.2.4-beta", false),
+    TestVersionRange("^1.2.3-alpha", "1.3.0", true),
+    TestVersionRange("^0.0.1-alpha", "0.0.1", true),
+    // Invalid semver versions never match
+    TestVersionRange("*", "1.2", false),
+    TestVersionRange("^1.2", "1.2.3.4", false),
+];
+
+/// List of invalid Cargo requirements for dynamic tests
+pub const TEST_CARGO_REQS_ERROR: &[&str] = &[
+    "",
+    " ",
+    "abc",
+    "*, 1.2",
+    "1.2, *",
+    "x.1",
+    ">=",
+    "=>1.2",
+    "1.2 <2",
+    "1.2 || 2",
+    "1.2,",
+    "1.*.3",
+    "1.2-alpha",
+    "1.2.3-",
+    "1.2.3-a..b",
+    "1.2.3+",
+    "01.2",
+    "1.2.03",
+    "1.2.3-01",
+    "v1.2.3",
+    "1.2.3.4",
+    "!=1.2",
+];
@@ -0,0 +1,95 @@
+use crate::comp_op::CompOp;
+use crate::test::test_version::TestVersion;
+use crate::test::test_version_set::TestVersionSet;
+
+/// List of Debian version numbers with the number of parts, for dynamic tests
+pub const TEST_DEBIAN_VERSIONS: &[TestVersion] = &[
+    TestVersion("0", 1),
+    TestVersion("1.2.3", 3),
+    TestVersion("1:1.2.3", 3),
+    TestVersion("1.2.3-1", 4),
+    TestVersion("1.2.3-rc1-1", 6),
+    TestVersion("2:1.0~rc1+dfsg-0ubuntu1", 8),
+    TestVersion("1:2:3", 2),
+    TestVersion("3.8.GA-1", 4),
+    TestVersion("a-0", 2),
+    TestVersion(" 1.0 ", 2),
+];
+
+/// List of invalid Debian version numbers for dynamic tests
+pub const TEST_DEBIAN_ERROR: &[&str] = &[
+    "", " ", "1:", ":1.0", "a:1.0", "-1:1.0", "1.0-a:b", "-1", "1.0-", "1.0-1-", "1.0 beta",
+];
+
+/// List of Debian version sets for dynamic tests, with results of `dpkg --compare-versions`
+pub const TEST_DEBIAN_SETS: &[TestVersionSet] = &[
+    TestVersionSet("0", "0", CompOp::Eq),
+    TestVersionSet("0-0", "00-0", CompOp::Eq),
+    TestVersionSet("1:2-3", "1:2-3", CompOp::Eq),
+    TestVersionSet("0-0", "1:0-0", CompOp::Lt),
+    TestVersionSet("1:0-0", "0-0", CompOp::Gt),
+    TestVersionSet("a-0", "b-0", CompOp::Lt),
+    TestVersionSet("0-a", "0-b", CompOp::Lt),
+    TestVersionSet("1.0-1", "2.0-2", CompOp::Lt),
+    TestVersionSet("2.2~rc-4", "2.2-1", CompOp::Lt),
+    TestVersionSet("2.2-1", "2.2~rc-4", CompOp::Gt),
+    TestVersionSet("1.0000-1", "1.0-1", CompOp::Eq),
+    TestVersionSet("1", "0:1", CompOp::Eq),
+    TestVersionSet("0", "0:0-0", CompOp::Eq),
+    TestVersionSet("2:2.5", "1:7.5", CompOp::Gt),
+    TestVersionSet("1:0foo", "0foo", CompOp::Gt),
+    TestVersionSet("0:0foo", "0foo", CompOp::Eq),
+    TestVersionSet("0foo", "0foo", CompOp::Eq),
+    TestVersionSet("0foo-0", "0foo", CompOp::Eq),
+    TestVersionSet("0foo", "0foo-0", CompOp::Eq),
+    TestVersionSet("0foo", "0fo", CompOp::Gt),
+    TestVersionSet("0foo-0", "0foo+", CompOp::Lt),
+    TestVersionSet("0foo~1", "0foo", CompOp::Lt),
+    TestVersionSet("0foo~foo+Bar", "0foo~foo+bar", CompOp::Lt),
+    TestVersionSet("0foo~~", "0foo~", CompOp::Lt),
+    TestVersionSet("1~", "1", CompOp::Lt),
+    TestVersionSet(
+        "12345+that-really-is-some-ver-0",
+        "12345+that-really-is-some-ver-10",
+        CompOp::Lt,
+    ),
+    TestVersionSet("0foo-0", "0foo-01", CompOp::Lt),
+    TestVersionSet("0foo.bar", "0foobar", CompOp::Gt),
+    TestVersionSet("0foo.bar", "0foo1bar", CompOp::Gt),
+    TestVersionSet("0foo.bar", "0foo0bar", CompOp::Gt),
+    TestVersionSet("0foo1bar-1", "0foobar-1", CompOp::Lt),
+    TestVersionSet("0foo2.0", "0foo2", CompOp::Gt),
+    TestVersionSet("0foo2.0.0", "0foo2.10.0", CompOp::Lt),
+    TestVersionSet("0foo2.0", "0foo2.0.0", CompOp::Lt),
+    TestVersionSet("0foo2.0", "0foo2.10", CompOp::Lt),
+    TestVersionSet("0foo2.1", "0foo2.10", CompOp::Lt),
+    TestVersionSet("1.09", "1.9", CompOp::Eq),
+    TestVersionSet("1.0.8+nmu1", "1.0.8", CompOp::Gt),
+    TestVersionSet("3.11", "3.10+nmu1", CompOp::Gt),
+    TestVersionSet("0.9j-20080306-4", "0.9i-20080306-4", CompOp::Gt),
+    TestVersionSet("1.2.0~b7-1", "1.2.0~b6-1", CompOp::Gt),
+    TestVersionSet("1.011-1", "1.06-2", CompOp::Gt),
+    TestVersionSet("0.0.9+dfsg1-1", "0.0.8+dfsg1-3", CompOp::Gt),
+    TestVersionSet("4.6.99+svn6582-1", "4.6.99+svn6496-1", CompOp::Gt),
+    TestVersionSet("53", "52", CompOp::Gt),
+    TestVersionSet("0.9.9~pre122-1", "0.9.9~pre111-1", CompOp::Gt),
+    TestVersionSet("2:2.3.2-2+lenny2", "2:2.3.2-2", CompOp::Gt),
+    TestVersionSet("1:3.8.1-1", "3.8.GA-1", CompOp::Gt),
+    TestVersionSet("1.0.1+gpl-1", "1.0.1-2", CompOp::Gt),
+    TestVersionSet("1a", "1000a", CompOp::Lt),
+    TestVersionSet("1.0", "1.0a", CompOp::Lt),
+    TestVersionSet("1.0", "1.0a0", CompOp::Lt),
+    TestVersionSet("1.0a", "1.0+", CompOp::Lt),
+    TestVersionSet("1.2.3", "1.2.3.0", CompOp::Lt),
+    TestVersionSet("1.0~rc1", "1.0", CompOp::Lt),
+    TestVersionSet("1.0~~", "1.0~~a", CompOp::Lt),
+    TestVersionSet("1.0-1ubuntu1", "1.0-1", CompOp::Gt),
+    TestVersionSet("1.0", "1.0-0", CompOp::Eq),
+    TestVersionSet("1.0-0ubuntu1", "1.0-1", CompOp::Lt),
+    TestVersionSet("1:1.0", "2.0", CompOp::Gt),
+    TestVersionSet("2.30-0ubuntu1", "2.4-1", CompOp::Gt),
+    TestVersionSet("7.6p2-4", "7.6-0", CompOp::Gt),
+    TestVersionSet("1.0.3-3", "1.0-1", CompOp::Gt),
+    TestVersionSet("1.3", "1.2.2-2", CompOp::Gt),
+    TestVersionSet("1.3", "1.2.2", CompOp::Gt),
+];
@@ -0,0 +1,146 @@
+use crate::comp_op::CompOp;
+use crate::test::test_version::TestVersion;
+use crate::test::test_version_set::TestVersionSet;
+
+/// List of Maven version numbers with the number of parts, for dynamic tests
+pub const TEST_MAVEN_VERSIONS: &[TestVersion] = &[
+    TestVersion("", 0),
+    TestVersion("1", 1),
+    TestVersion("1.0", 1),
+    TestVersion("1.2.3", 3),
+    TestVersion("1.0-SNAPSHOT", 2),
+    TestVersion("1.0.0.Final", 1),
+    TestVersion("1.0.RELEASE", 1),
+    TestVersion("2.0-M3", 3),
+    TestVersion("1.0-alpha-1", 3),
+    TestVersion("1.0-alpha-1-SNAPSHOT", 4),
+    TestVersion("6.1H.5-beta", 5),
+    TestVersion("1.0.0-X2", 3),
+];
+
+/// List of invalid Maven version numbers for dynamic tests
+pub const TEST_MAVEN_ERROR: &[&str] = &["1.99999999999999999999", "1-a99999999999999999999"];
+
+/// List of Maven version sets for dynamic tests, with results of `ComparableVersion`
+///
+/// Includes the test cases of the Maven `ComparableVersionTest` suite.
+pub const TEST_MAVEN_SETS: &[TestVersionSet] = &[
+    // Equal versions
+    TestVersionSet("1", "1", CompOp::Eq),
+    TestVersionSet("1", "1.0", CompOp::Eq),
+    TestVersionSet("1", "1.0.0", CompOp::Eq),
+    TestVersionSet("1.0", "1.0.0", CompOp::Eq),
+    TestVersionSet("1", "1-0", CompOp::Eq),
+    TestVersionSet("1", "1.0-0", CompOp::Eq),
+    TestVersionSet("1.0", "1.0-0", CompOp::Eq),
+    TestVersionSet("1a", "1-a", CompOp::Eq),
+    TestVersionSet("1a", "1.0-a", CompOp::Eq),
+    TestVersionSet("1a", "1.0.0-a", CompOp::Eq),
+    TestVersionSet("1.0a", "1-a", CompOp::Eq),
+    TestVersionSet("1.0.0a", "1-a", CompOp::Eq),
+    TestVersionSet("1x", "1-x", CompOp::Eq),
+    TestVersionSet("1x", "1.0-x", CompOp::Eq),
+    TestVersionSet("1x", "1.0.0-x", CompOp::Eq),
+    TestVersionSet("1.0x", "1-x", CompOp::Eq),
+    TestVersionSet("1.0.0x", "1-x", CompOp::Eq),
+    TestVersionSet("1ga", "1", CompOp::Eq),
+    TestVersionSet("1release", "1", CompOp::Eq),
+    TestVersionSet("1final", "1", CompOp::Eq),
+    TestVersionSet("1cr", "1rc", CompOp::Eq),
+    TestVersionSet("1a1", "1-alpha-1", CompOp::Eq),
+    TestVersionSet("1b2", "1-beta-2", CompOp::Eq),
+    TestVersionSet("1m3", "1-milestone-3", CompOp::Eq),
+    TestVersionSet("1X", "1x", CompOp::Eq),
+    TestVersionSet("1A", "1a", CompOp::Eq),
+    TestVersionSet("1GA", "1", CompOp::Eq),
+    TestVersionSet("1RELeaSE", "1", CompOp::Eq),
+    TestVersionSet("1FinaL", "1", CompOp::Eq),
+    TestVersionSet("1Cr", "1Rc", CompOp::Eq),
+    TestVersionSet("1m3", "1MileStone3", CompOp::Eq),
+    TestVersionSet("1.0.0.Final", "1.0", CompOp::Eq),
+    TestVersionSet("1.0.RELEASE", "1.0-ga", CompOp::Eq),
+    // Qualifiers
+    TestVersionSet("1-alpha2snapshot", "1-alpha2", CompOp::Lt),
+    TestVersionSet("1-alpha2", "1-alpha-123", CompOp::Lt),
+    TestVersionSet("1-alpha-123", "1-beta-2", CompOp::Lt),
+    TestVersionSet("1-beta-2", "1-beta123", CompOp::Lt),
+    TestVersionSet("1-beta123", "1-m2", CompOp::Lt),
+    TestVersionSet("1-m2", "1-m11", CompOp::Lt),
+    TestVersionSet("1-m11", "1-rc", CompOp::Lt),
+    TestVersionSet("1-rc", "1-cr2", CompOp::Lt),
+    TestVersionSet("1-cr2", "1-rc123", CompOp::Lt),
+    TestVersionSet("1-rc123", "1-SNAPSHOT", CompOp::Lt),
+    TestVersionSet("1-SNAPSHOT", "1", CompOp::Lt),
+    TestVersionSet("1", "1-sp", CompOp::Lt),
+    TestVersionSet("1-sp", "1-sp2", CompOp::Lt),
+    TestVersionSet("1-sp2", "1-sp123", CompOp::Lt),
+    TestVersionSet("1-sp123", "1-abc", CompOp::Lt),
+    TestVersionSet("1-abc", "1-def", CompOp::Lt),
+    TestVersionSet("1-def", "1-pom-1", CompOp::Lt),
+    TestVersionSet("1-pom-1", "1-1-snapshot", CompOp::Lt),
+    TestVersionSet("1-1-snapshot", "1-1", CompOp::Lt),
+    TestVersionSet("1-1", "1-2", CompOp::Lt),
+    TestVersionSet("1-2", "1-123", CompOp::Lt),
+    // Numbers
+    TestVersionSet("2.0", "2-1", CompOp::Lt),
+    TestVersionSet("2-1", "2.0.a", CompOp::Lt),
+    TestVersionSet("2.0.a", "2.0.0.a", CompOp::Lt),
+    TestVersionSet("2.0.0.a", "2.0.2", CompOp::Lt),
+    TestVersionSet("2.0.2", "2.0.123", CompOp::Lt),
+    TestVersionSet("2.0.123", "2.1.0", CompOp::Lt),
+    TestVersionSet("2.1.0", "2.1-a", CompOp::Lt),
+    TestVersionSet("2.1-a", "2.1b", CompOp::Lt),
+    TestVersionSet("2.1b", "2.1-c", CompOp::Lt),
+    TestVersionSet("2.1-c", "2.1-1", CompOp::Lt),
+    TestVersionSet("2.1-1", "2.1.0.1", CompOp::Lt),
+    TestVersionSet("2.1.0.1", "2.2", CompOp::Lt),
+    TestVersionSet("2.2", "2.123", CompOp::Lt),
+    TestVersionSet("2.123", "11.a2", CompOp::Lt),
+    TestVersionSet("11.a2", "11.a11", CompOp::Lt),
+    TestVersionSet("11.a11", "11.b2", CompOp::Lt),
+    TestVersionSet("11.b2", "11.b11", CompOp::Lt),
+    TestVersionSet("11.b11", "11.m2", CompOp::Lt),
+    TestVersionSet("11.m2", "11.m11", CompOp::Lt),
+    TestVersionSet("11.m11", "11", CompOp::Lt),
+    TestVersionSet("11", "11.a", CompOp::Lt),
+    TestVersionSet("11.a", "11b", CompOp::Lt),
+    TestVersionSet("11b", "11c", CompOp::Lt),
+    TestVersionSet("11c", "11m", CompOp::Lt),
+    // Common versions
+    TestVersionSet("1", "2", CompOp::Lt),
+    TestVersionSet("1.5", "2", CompOp::Lt),
+    TestVersionSet("1", "2.5", CompOp::Lt),
+    TestVersionSet("1.0", "1.1", CompOp::Lt),
+    TestVersionSet("1.1", "1.2", CompOp::Lt),
+    TestVersionSet("1.0.0", "1.1", CompOp::Lt),
+    TestVersionSet("1.0.1", "1.1", CompOp::Lt),
+    TestVersionSet("1.1", "1.2.0", CompOp::Lt),
+    TestVersionSet("1.0-alpha-1", "1.0", CompOp::Lt),
+    TestVersionSet("1.0-alpha-1", "1.0-alpha-2", CompOp::Lt),
+    TestVersionSet("1.0-alpha-1", "1.0-beta-1", CompOp::Lt),
+    TestVersionSet("1.0-beta-1", "1.0-SNAPSHOT", CompOp::Lt),
+    TestVersionSet("1.0-SNAPSHOT", "1.0", CompOp::Lt),
+    TestVersionSet("1.0-alpha-1-SNAPSHOT", "1.0-alpha-1", CompOp::Lt),
+    TestVersionSet("1.0", "1.0-1", CompOp::Lt),
+    TestVersionSet("1.0-1", "1.0-2", CompOp::Lt),
+    TestVersionSet("1.0.0", "1.0-1", CompOp::Lt),
+    TestVersionSet("2.0-1", "2.0.1", CompOp::Lt),
+    TestVersionSet("2.0.1-klm", "2.0.1-lmn", CompOp::Lt),
+    TestVersionSet("2.0.1", "2.0.1-xyz", CompOp::Lt),
+    TestVersionSet("2.0.1", "2.0.1-123", CompOp::Lt),
+    TestVersionSet("2.0.1-xyz", "2.0.1-123", CompOp::Lt),
+    TestVersionSet("2.0-M3", "2.0-RC1", CompOp::Lt),
+    TestVersionSet("1.0.0.Final", "1.0-sp1", CompOp::Lt),
+    TestVersionSet("1.0-sp1", "1.0.1", CompOp::Lt),
+    // Regressions reported against Maven
+    TestVersionSet("6.1.0rc3", "6.1.0", CompOp::Lt),
+    TestVersionSet("6.1.0", "6.1H.5-beta", CompOp::Lt),
+    TestVersionSet("6.1.0rc3", "6.1H.5-beta", CompOp::Lt),
+    TestVersionSet("20190126.230843", "1234567890.12345", CompOp::Lt),
+    TestVersionSet("1234567890.12345", "123456789012345.1H.5-beta", CompOp::Lt),
+    TestVersionSet(
+        "123456789012345.1H.5-beta",
+        "12345678901234567890.1H.5-beta",
+        CompOp::Lt,
+    ),
+];
@@ -0,0 +1,70 @@
+use crate::test::test_version_range::TestVersionRange;
+
+/// List of Maven ranges with versions for dynamic tests
+pub const TEST_MAVEN_RANGES: &[TestVersionRange] = &[
+    // Soft requirements
+    TestVersionRange("1.0", "1.0", true),
+    TestVersionRange("1.0", "0.5", true),
+    TestVersionRange(" 2.0-SNAPSHOT ", "3.0", true),
+    // Exact versions
+    TestVersionRange("[1.2]", "1.2", true),
+    TestVersionRange("[1.2]", "1.2.0", true),
+    TestVersionRange("[1.2]", "1.2.0.Final", true),
+    TestVersionRange("[1.2]", "1.2.1", false),
+    TestVersionRange("[1.2]", "1.2-SNAPSHOT", false),
+    TestVersionRange("[ 1.2 ]", "1.2", true),
+    // Bounded ranges
+    TestVersionRange("[1.0,2.0)", "1.0", true),
+    TestVersionRange("[1.0,2.0)", "1.5", true),
+    TestVersionRange("[1.0,2.0)", "1.9.9-sp1", true),
+    TestVersionRange("[1.0,2.0)", "2.0", false),
+    TestVersionRange("[1.0,2.0)", "2.0-SNAPSHOT", true),
+    TestVersionRange("[1.0,2.0)", "1.0-SNAPSHOT", false),
+    TestVersionRange("[1.0,2.0]", "2.0", true),
+    TestVersionRange("[1.0,2.0]", "2.0.GA", true),
+    TestVersionRange("(1.0,2.0)", "1.0", false),
+    TestVersionRange("(1.0,2.0)", "1.0-sp", true),
+    TestVersionRange("(1.0,2.0]", "2.0", true),
+    TestVersionRange("[1.0,1.0]", "1.0", true),
+    TestVersionRange("[ 1.0 , 2.0 ]", "1.5", true),
+    // Unbounded ranges
+    TestVersionRange("(,1.5]", "1.5", true),
+    TestVersionRange("(,1.5]", "0.1", true),
+    TestVersionRange("(,1.5]", "1.5.1", false),
+    TestVersionRange("(,1.5)", "1.5", false),
+    TestVersionRange("[1.5,)", "1.5", true),
+    TestVersionRange("[1.5,)", "99", true),
+    TestVersionRange("(1.5,)", "1.5", false),
+    TestVersionRange("(,)", "1.0", true),
+    // Unions
+    TestVersionRange("(,1.0],[1.2,)", "1.0", true),
+    TestVersionRange("(,1.0],[1.2,)", "1.1", false),
+    TestVersionRange("(,1.0],[1.2,)", "1.2", true),
+    TestVersionRange("(,1.0],[1.2,)", "3.0", true),
+    TestVersionRange("(,1.1),(1.1,)", "1.1", false),
+    TestVersionRange("(,1.1),(1.1,)", "1.1-SNAPSHOT", true),
+    TestVersionRange("[1.0,2.0],[2.0,3.0]", "2.0", true),
+    TestVersionRange("[1.0],[1.2] , [1.4]", "1.2", true),
+    TestVersionRange("[1.0],[1.2] , [1.4]", "1.3", false),
+    TestVersionRange("[1.0][1.2]", "1.2", true),
+];
+
+/// List of invalid Maven ranges for dynamic tests
+pub const TEST_MAVEN_RANGES_ERROR: &[&str] = &[
+    "",
+    " ",
+    "[",
+    "[1.0",
+    "(1.0)",
+    "[1.0)",
+    "[]",
+    "[,]x",
+    "[1.0,2.0,3.0]",
+    "[2.0,1.0]",
+    "(1.0,1.0]",
+    "[1.0,2.0],",
+    "[1.0,2.0],1.5",
+    "[1.0,2.0],[1.5,3.0]",
+    "[1.0,),[2.0,3.0]",
+    "[1.2],[1.0]",
+];
@@ -0,0 +1,106 @@
+use crate::test::test_version::TestVersion;
+
+/// List of PEP 440 version numbers with the number of parts, for dynamic tests
+pub const TEST_PEP440_VERSIONS: &[TestVersion] = &[
+    TestVersion("1", 1),
+    TestVersion("1.2.3", 3),
+    TestVersion("v1.2", 2),
+    TestVersion("1!2.0", 2),
+    TestVersion("1.0a1", 4),
+    TestVersion("1.0-RC1", 4),
+    TestVersion("1.0.post2", 4),
+    TestVersion("1.0-2", 4),
+    TestVersion("1.0.dev3", 4),
+    TestVersion("1.0a1.post2.dev3", 8),
+    TestVersion("1.0+ubuntu1", 2),
+    TestVersion("1.0+ubuntu-1.2_3", 2),
+    TestVersion(" 1.0 ", 2),
+    TestVersion("2012.10", 2),
+];
+
+/// List of invalid PEP 440 version numbers for dynamic tests
+pub const TEST_PEP440_ERROR: &[&str] = &[
+    "",
+    "abc",
+    "1.0-",
+    "1.0+",
+    "1.0+ubuntu..1",
+    "1.0+ubuntu_",
+    "1.0.foo",
+    "1.0a1a2",
+    "1.0.dev1.post1",
+    "1.0-SNAPSHOT",
+    "1!",
+    "1.2.3.x",
+    "1.0 beta",
+];
+
+/// List of PEP 440 version numbers with their normalized form
+pub const TEST_PEP440_NORMALIZED: &[(&str, &str)] = &[
+    ("1.0", "1.0"),
+    ("v1.0", "1.0"),
+    ("V1.0", "1.0"),
+    (" 1.0\n", "1.0"),
+    ("0!1.0", "1.0"),
+    ("01.002.0003", "1.2.3"),
+    ("1.0-RC1", "1.0rc1"),
+    ("1.0rc1", "1.0rc1"),
+    ("1.0c1", "1.0rc1"),
+    ("1.0.pre1", "1.0rc1"),
+    ("1.0-preview_1", "1.0rc1"),
+    ("1.0alpha1", "1.0a1"),
+    ("1.0.a.1", "1.0a1"),
+    ("1.0Beta2", "1.0b2"),
+    ("1.0b", "1.0b0"),
+    ("1.0.post2", "1.0.post2"),
+    ("1.0post2", "1.0.post2"),
+    ("1.0-post-2", "1.0.post2"),
+    ("1.0-2", "1.0.post2"),
+    ("1.0.rev2", "1.0.post2"),
+    ("1.0r2", "1.0.post2"),
+    ("1.0.post", "1.0.post0"),
+    ("1.0.dev3", "1.0.dev3"),
+    ("1.0-dev3", "1.0.dev3"),
+    ("1.0dev", "1.0.dev0"),
+    ("1!2.0a1.post2.dev3", "1!2.0a1.post2.dev3"),
+    ("1.0+Ubuntu-1_01", "1.0+ubuntu.1.1"),
+];
+
+/// List of PEP 440 version numbers in ascending order
+pub const TEST_PEP440_ORDERED: &[&str] = &[
+    // Implicit epoch of 0
+    "1.0.dev456",
+    "1.0a1",
+    "1.0a2.dev456",
+    "1.0a12.dev456",
+    "1.0a12",
+    "1.0b1.dev456",
+    "1.0b2",
+    "1.0b2.post345.dev456",
+    "1.0b2.post345",
+    "1.0b2-346",
+    "1.0c1.dev456",
+    "1.0c1",
+    "1.0rc2",
+    "1.0c3",
+    "1.0",
+    "1.0.post456.dev34",
+    "1.0.post456",
+    "1.1.dev1",
+    "1.2+123abc",
+    "1.2+123abc456",
+    "1.2+abc",
+    "1.2+abc123",
+    "1.2+abc123def",
+    "1.2+1234.abc",
+    "1.2+123456",
+    "1.2.r32+123456",
+    "1.2.rev33+123456",
+    // Explicit epoch of 1
+    "1!1.0.dev456",
+    "1!1.0a1",
+    "1!1.0b2.post345",
+    "1!1.0",
+    "1!1.0.post456",
+    "1!1.2+123456",
+];
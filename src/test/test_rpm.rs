@@ -0,0 +1,129 @@
+use crate::comp_op::CompOp;
+use crate::test::test_version::TestVersion;
+use crate::test::test_version_set::TestVersionSet;
+
+/// List of RPM version numbers with the number of parts, for dynamic tests
+pub const TEST_RPM_VERSIONS: &[TestVersion] = &[
+    TestVersion("1", 1),
+    TestVersion("1.2.3", 3),
+    TestVersion("1:1.2.3", 3),
+    TestVersion("1.2.3-1", 4),
+    TestVersion("1.2.3-1.fc39", 6),
+    TestVersion("2:1.0~rc1^git2-3.el9_2", 10),
+    TestVersion("a:1.0", 3),
+    TestVersion("_", 0),
+    TestVersion(" 1.0 ", 2),
+];
+
+/// List of invalid RPM version numbers for dynamic tests
+pub const TEST_RPM_ERROR: &[&str] = &["", " ", "1:", "-1", "1.0-", "1.0-1-", "1.0 1"];
+
+/// List of RPM version sets for dynamic tests, with results of `rpmdev-vercmp`
+///
+/// Includes the `rpmvercmp` test cases of the RPM test suite.
+pub const TEST_RPM_SETS: &[TestVersionSet] = &[
+    TestVersionSet("1.0", "1.0", CompOp::Eq),
+    TestVersionSet("1.0", "2.0", CompOp::Lt),
+    TestVersionSet("2.0", "1.0", CompOp::Gt),
+    TestVersionSet("2.0.1", "2.0.1", CompOp::Eq),
+    TestVersionSet("2.0", "2.0.1", CompOp::Lt),
+    TestVersionSet("2.0.1", "2.0", CompOp::Gt),
+    TestVersionSet("2.0.1a", "2.0.1a", CompOp::Eq),
+    TestVersionSet("2.0.1a", "2.0.1", CompOp::Gt),
+    TestVersionSet("2.0.1", "2.0.1a", CompOp::Lt),
+    TestVersionSet("5.5p1", "5.5p1", CompOp::Eq),
+    TestVersionSet("5.5p1", "5.5p2", CompOp::Lt),
+    TestVersionSet("5.5p2", "5.5p1", CompOp::Gt),
+    TestVersionSet("5.5p10", "5.5p10", CompOp::Eq),
+    TestVersionSet("5.5p1", "5.5p10", CompOp::Lt),
+    TestVersionSet("5.5p10", "5.5p1", CompOp::Gt),
+    TestVersionSet("10xyz", "10.1xyz", CompOp::Lt),
+    TestVersionSet("10.1xyz", "10xyz", CompOp::Gt),
+    TestVersionSet("xyz10", "xyz10", CompOp::Eq),
+    TestVersionSet("xyz10", "xyz10.1", CompOp::Lt),
+    TestVersionSet("xyz10.1", "xyz10", CompOp::Gt),
+    TestVersionSet("xyz.4", "xyz.4", CompOp::Eq),
+    TestVersionSet("xyz.4", "8", CompOp::Lt),
+    TestVersionSet("8", "xyz.4", CompOp::Gt),
+    TestVersionSet("xyz.4", "2", CompOp::Lt),
+    TestVersionSet("2", "xyz.4", CompOp::Gt),
+    TestVersionSet("5.5p2", "5.6p1", CompOp::Lt),
+    TestVersionSet("5.6p1", "5.5p2", CompOp::Gt),
+    TestVersionSet("5.6p1", "6.5p1", CompOp::Lt),
+    TestVersionSet("6.5p1", "5.6p1", CompOp::Gt),
+    TestVersionSet("6.0.rc1", "6.0", CompOp::Gt),
+    TestVersionSet("6.0", "6.0.rc1", CompOp::Lt),
+    TestVersionSet("10b2", "10a1", CompOp::Gt),
+    TestVersionSet("10a2", "10b2", CompOp::Lt),
+    TestVersionSet("1.0aa", "1.0aa", CompOp::Eq),
+    TestVersionSet("1.0a", "1.0aa", CompOp::Lt),
+    TestVersionSet("1.0aa", "1.0a", CompOp::Gt),
+    TestVersionSet("10.0001", "10.0001", CompOp::Eq),
+    TestVersionSet("10.0001", "10.1", CompOp::Eq),
+    TestVersionSet("10.1", "10.0001", CompOp::Eq),
+    TestVersionSet("10.0001", "10.0039", CompOp::Lt),
+    TestVersionSet("10.0039", "10.0001", CompOp::Gt),
+    TestVersionSet("4.999.9", "5.0", CompOp::Lt),
+    TestVersionSet("5.0", "4.999.9", CompOp::Gt),
+    TestVersionSet("20101121", "20101121", CompOp::Eq),
+    TestVersionSet("20101121", "20101122", CompOp::Lt),
+    TestVersionSet("20101122", "20101121", CompOp::Gt),
+    TestVersionSet("2_0", "2_0", CompOp::Eq),
+    TestVersionSet("2.0", "2_0", CompOp::Eq),
+    TestVersionSet("2_0", "2.0", CompOp::Eq),
+    TestVersionSet("a", "a", CompOp::Eq),
+    TestVersionSet("a+", "a+", CompOp::Eq),
+    TestVersionSet("a+", "a_", CompOp::Eq),
+    TestVersionSet("a_", "a+", CompOp::Eq),
+    TestVersionSet("+a", "+a", CompOp::Eq),
+    TestVersionSet("+a", "_a", CompOp::Eq),
+    TestVersionSet("_a", "+a", CompOp::Eq),
+    TestVersionSet("+_", "+_", CompOp::Eq),
+    TestVersionSet("_+", "+_", CompOp::Eq),
+    TestVersionSet("_+", "_", CompOp::Eq),
+    TestVersionSet("+", "_", CompOp::Eq),
+    TestVersionSet("_", "+", CompOp::Eq),
+    TestVersionSet("1.0~rc1", "1.0~rc1", CompOp::Eq),
+    TestVersionSet("1.0~rc1", "1.0", CompOp::Lt),
+    TestVersionSet("1.0", "1.0~rc1", CompOp::Gt),
+    TestVersionSet("1.0~rc1", "1.0~rc2", CompOp::Lt),
+    TestVersionSet("1.0~rc2", "1.0~rc1", CompOp::Gt),
+    TestVersionSet("1.0~rc1~git123", "1.0~rc1~git123", CompOp::Eq),
+    TestVersionSet("1.0~rc1~git123", "1.0~rc1", CompOp::Lt),
+    TestVersionSet("1.0~rc1", "1.0~rc1~git123", CompOp::Gt),
+    TestVersionSet("1.0^", "1.0^", CompOp::Eq),
+    TestVersionSet("1.0^", "1.0", CompOp::Gt),
+    TestVersionSet("1.0", "1.0^", CompOp::Lt),
+    TestVersionSet("1.0^git1", "1.0^git1", CompOp::Eq),
+    TestVersionSet("1.0^git1", "1.0", CompOp::Gt),
+    TestVersionSet("1.0", "1.0^git1", CompOp::Lt),
+    TestVersionSet("1.0^git1", "1.0^git2", CompOp::Lt),
+    TestVersionSet("1.0^git2", "1.0^git1", CompOp::Gt),
+    TestVersionSet("1.0^git1", "1.01", CompOp::Lt),
+    TestVersionSet("1.01", "1.0^git1", CompOp::Gt),
+    TestVersionSet("1.0^20160101", "1.0^20160101", CompOp::Eq),
+    TestVersionSet("1.0^20160101", "1.0.1", CompOp::Lt),
+    TestVersionSet("1.0.1", "1.0^20160101", CompOp::Gt),
+    TestVersionSet("1.0^20160101^git1", "1.0^20160101^git1", CompOp::Eq),
+    TestVersionSet("1.0^20160102", "1.0^20160101^git1", CompOp::Gt),
+    TestVersionSet("1.0^20160101^git1", "1.0^20160102", CompOp::Lt),
+    TestVersionSet("1.0~rc1^git1", "1.0~rc1^git1", CompOp::Eq),
+    TestVersionSet("1.0~rc1^git1", "1.0~rc1", CompOp::Gt),
+    TestVersionSet("1.0~rc1", "1.0~rc1^git1", CompOp::Lt),
+    TestVersionSet("1.0^git1~pre", "1.0^git1~pre", CompOp::Eq),
+    TestVersionSet("1.0^git1", "1.0^git1~pre", CompOp::Gt),
+    TestVersionSet("1.0^git1~pre", "1.0^git1", CompOp::Lt),
+    TestVersionSet("0:1.0", "1.0", CompOp::Eq),
+    TestVersionSet("1:1.0", "2.0", CompOp::Gt),
+    TestVersionSet("1:1.0", "2:0.1", CompOp::Lt),
+    TestVersionSet("1.0-1", "1.0-1", CompOp::Eq),
+    TestVersionSet("1.0-1", "1.0-2", CompOp::Lt),
+    TestVersionSet("1.0-10", "1.0-9", CompOp::Gt),
+    TestVersionSet("2.0-1", "1.0-10", CompOp::Gt),
+    TestVersionSet("1.0", "1.0-1", CompOp::Lt),
+    TestVersionSet("1.0-1.fc38", "1.0-1.fc39", CompOp::Lt),
+    TestVersionSet("1.0-1.el9", "1.0-1.el9_2", CompOp::Lt),
+    TestVersionSet("1.0-1.el9_2.1", "1.0-1.el9_2", CompOp::Gt),
+    TestVersionSet("1.0~rc1-1", "1.0-0", CompOp::Lt),
+    TestVersionSet("1:0.9-1", "1.0-1", CompOp::Gt),
+];
@@ -0,0 +1,69 @@
+use crate::comp_op::CompOp;
+use crate::test::test_version::TestVersion;
+use crate::test::test_version_set::TestVersionSet;
+
+/// List of semver version numbers with the number of parts, for dynamic tests
+pub const TEST_SEMVER_VERSIONS: &[TestVersion] = &[
+    TestVersion("0.0.0", 3),
+    TestVersion("1.2.3", 3),
+    TestVersion("10.20.30", 3),
+    TestVersion("1.0.0-alpha", 4),
+    TestVersion("1.0.0-alpha.1", 5),
+    TestVersion("1.0.0-0.3.7", 6),
+    TestVersion("1.0.0-x.7.z.92", 7),
+    TestVersion("1.0.0-x-y-z.--", 5),
+    TestVersion("1.0.0-alpha+001", 4),
+    TestVersion("1.0.0+20130313144700", 3),
+    TestVersion("1.0.0-beta+exp.sha.5114f85", 4),
+    TestVersion("1.0.0+21AF26D3----117B344092BD", 3),
+    TestVersion("1.0.0-rc.1+build.0010", 5),
+    TestVersion("18446744073709551615.0.0", 3),
+];
+
+/// List of invalid semver version numbers for dynamic tests
+pub const TEST_SEMVER_ERROR: &[&str] = &[
+    "",
+    "1",
+    "1.2",
+    "1.2.3.4",
+    "01.1.1",
+    "1.01.1",
+    "1.1.01",
+    "v1.2.3",
+    "1.2.3-",
+    "1.2.3+",
+    "1.2.3-0123",
+    "1.2.3-alpha..1",
+    "1.2.3-alpha_1",
+    "1.2.3+build+5",
+    "1.2.3 ",
+    "-1.2.3",
+    "1.2-SNAPSHOT",
+    "1.2.x",
+];
+
+/// List of semver version sets for dynamic tests
+pub const TEST_SEMVER_SETS: &[TestVersionSet] = &[
+    TestVersionSet("1.0.0", "1.0.0", CompOp::Eq),
+    TestVersionSet("1.0.0", "2.0.0", CompOp::Lt),
+    TestVersionSet("2.0.0", "2.1.0", CompOp::Lt),
+    TestVersionSet("2.1.0", "2.1.1", CompOp::Lt),
+    TestVersionSet("1.10.0", "1.9.0", CompOp::Gt),
+    TestVersionSet("1.0.0-alpha", "1.0.0", CompOp::Lt),
+    TestVersionSet("1.0.0-alpha", "1.0.0-alpha.1", CompOp::Lt),
+    TestVersionSet("1.0.0-alpha.1", "1.0.0-alpha.beta", CompOp::Lt),
+    TestVersionSet("1.0.0-alpha.beta", "1.0.0-beta", CompOp::Lt),
+    TestVersionSet("1.0.0-beta", "1.0.0-beta.2", CompOp::Lt),
+    TestVersionSet("1.0.0-beta.2", "1.0.0-beta.11", CompOp::Lt),
+    TestVersionSet("1.0.0-beta.11", "1.0.0-rc.1", CompOp::Lt),
+    TestVersionSet("1.0.0-rc.1", "1.0.0", CompOp::Lt),
+    TestVersionSet("1.0.0-rc.1", "1.0.0-rc1", CompOp::Lt),
+    TestVersionSet("1.0.0-alpha.0", "1.0.0-alpha", CompOp::Gt),
+    TestVersionSet("1.0.0-RC.1", "1.0.0-rc.1", CompOp::Lt),
+    TestVersionSet("1.0.0-Beta", "1.0.0-alpha", CompOp::Lt),
+    TestVersionSet("1.0.0-1", "1.0.0-a", CompOp::Lt),
+    TestVersionSet("1.0.0-x-y", "1.0.0-x", CompOp::Gt),
+    TestVersionSet("1.0.0+build.1", "1.0.0+build.2", CompOp::Eq),
+    TestVersionSet("1.0.0-rc.1+build.1", "1.0.0-rc.1", CompOp::Eq),
+    TestVersionSet("1.0.0+build", "1.0.0-rc.1", CompOp::Gt),
+];
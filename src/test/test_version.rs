@@ -8,7 +8,7 @@
 pub struct TestVersion(pub &'static str, pub usize);
 
 /// List of version numbers with metadata for dynamic tests
-pub const TEST_VERSIONS: &'static [TestVersion] = &[
+pub const TEST_VERSIONS: &[TestVersion] = &[
     TestVersion("1", 1),
     TestVersion("1.2", 2),
     TestVersion("1.2.3.4", 4),
@@ -17,6 +17,8 @@ pub const TEST_VERSIONS: &'static [TestVersion] = &[
     TestVersion("0.0.0", 3),
     TestVersion("1.0.0", 3),
     TestVersion("0.0.1", 3),
+    TestVersion("2023101512345", 1),
+    TestVersion("1.0.18446744073709551615", 3),
     TestVersion("", 0),
     TestVersion(".", 0),
     TestVersion("...", 0),
@@ -26,12 +28,15 @@ pub const TEST_VERSIONS: &'static [TestVersion] = &[
     TestVersion("1.2-alpha-4", 4),
     TestVersion("snapshot.1.2", 3),
     TestVersion("snapshot-1.2", 3),
-    // TODO: inspect and fix this case
-    // TestVersion("version-compare 2.1.8.1 / build 209", 4),
+    TestVersion("version-compare 2.1.8.1 / build 209", 4),
+    TestVersion("release-1.2", 2),
+    TestVersion("v1.2.3", 3),
+    TestVersion("1 2 3", 3),
+    TestVersion("Version 1.2 rc1", 3),
 ];
 
 /// List of version numbers that contain errors with metadata for dynamic tests
-pub const TEST_VERSIONS_ERROR: &'static [TestVersion] = &[
+pub const TEST_VERSIONS_ERROR: &[TestVersion] = &[
     TestVersion("abc", 1),
     TestVersion("alpha.dev.snapshot", 3),
     TestVersion("test. .snapshot", 3),
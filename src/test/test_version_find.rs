@@ -0,0 +1,27 @@
+/// Struct containing a free-form text and the versions that should be found in it, for dynamic
+/// tests
+#[derive(Debug)]
+pub struct TestVersionFind(pub &'static str, pub &'static [&'static str]);
+
+/// List of texts with the versions found in them, for dynamic tests
+pub const TEST_VERSION_FINDS: &[TestVersionFind] = &[
+    TestVersionFind("git version 2.43.0", &["2.43.0"]),
+    TestVersionFind("OpenSSL 3.0.2 15 Mar 2022", &["3.0.2"]),
+    TestVersionFind(
+        "Python 3.11.4 (main, Jun  7 2023, 00:00:00) [GCC 12.2.0]",
+        &["3.11.4", "12.2.0"],
+    ),
+    TestVersionFind(
+        "rustc 1.75.0-nightly (abc1234 2023-10-15)",
+        &["1.75.0-nightly"],
+    ),
+    TestVersionFind("go version go1.21.5 linux/amd64", &["1.21.5"]),
+    TestVersionFind("Released v1.2.3.", &["1.2.3"]),
+    TestVersionFind("Upgrade from 1.2 to 1.10, see notes", &["1.2", "1.10"]),
+    TestVersionFind("Windows 10.0.19045.3803", &["10.0.19045.3803"]),
+    TestVersionFind("version 1.0.0.1 and 2.0.0.0", &["1.0.0.1", "2.0.0.0"]),
+    TestVersionFind("Listening on 192.168.1.20:8080", &[]),
+    TestVersionFind("Built on 15.03.2022 and 2022.03.15", &[]),
+    TestVersionFind("Nothing here, only 42 and 2024", &[]),
+    TestVersionFind("", &[]),
+];
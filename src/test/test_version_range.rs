@@ -0,0 +1,114 @@
+/// Struct containing a version range, a version, and whether the version matches the range.
+/// Such a set can be used for testing.
+///
+/// # Arguments
+///
+/// - `0`: The version range.
+/// - `1`: The version.
+/// - `2`: Whether the version matches the range.
+pub struct TestVersionRange(pub &'static str, pub &'static str, pub bool);
+
+/// List of version ranges with versions for dynamic tests
+pub const TEST_VERSION_RANGES: &[TestVersionRange] = &[
+    // Wildcards
+    TestVersionRange("", "1.2.3", true),
+    TestVersionRange("*", "1.2.3", true),
+    TestVersionRange("x", "0", true),
+    TestVersionRange(">=*", "0.1", true),
+    // Exact versions
+    TestVersionRange("1.2.3", "1.2.3", true),
+    TestVersionRange("=1.2.3", "1.2.3.0", true),
+    TestVersionRange("==1.2.3", "1.2.4", false),
+    TestVersionRange("v1.2.3", "1.2.3", true),
+    TestVersionRange("1.2.3.4", "1.2.3", false),
+    TestVersionRange("1.2.3-beta", "1.2.3-beta", true),
+    TestVersionRange("1.2.3-beta", "1.2.3", false),
+    // Comparison operators
+    TestVersionRange(">1.2.3", "1.2.4", true),
+    TestVersionRange(">1.2.3", "1.2.3", false),
+    TestVersionRange(">=1.2.3", "1.2.3", true),
+    TestVersionRange("<1.2.3", "1.2.2", true),
+    TestVersionRange("<1.2.3", "1.2.3-rc1", true),
+    TestVersionRange("<=1.2.3", "1.2.3", true),
+    TestVersionRange("<=1.2.3", "1.2.4", false),
+    TestVersionRange("!=1.2.3", "1.2.4", true),
+    TestVersionRange("!=1.2.3", "1.2.3", false),
+    TestVersionRange(">= 1.2.3", "1.3", true),
+    // Partial versions
+    TestVersionRange("1", "1.9.9", true),
+    TestVersionRange("1", "2.0.0", false),
+    TestVersionRange("1.x", "1.0.0", true),
+    TestVersionRange("1.x", "0.9", false),
+    TestVersionRange("1.x", "2.0.0-rc.1", false),
+    TestVersionRange("1.2.x", "1.2.9", true),
+    TestVersionRange("1.2.*", "1.3.0", false),
+    TestVersionRange("1.2", "1.2.5", true),
+    TestVersionRange(">1.2", "1.2.9", false),
+    TestVersionRange(">1.2", "1.3.0", true),
+    TestVersionRange(">=1.2", "1.2.0", true),
+    TestVersionRange("<1.2", "1.2.0", false),
+    TestVersionRange("<1.2", "1.1.9", true),
+    TestVersionRange("<2.0", "2.0-beta", false),
+    TestVersionRange("<2.0", "1.9-beta", true),
+    TestVersionRange("<=1.2", "1.3.0-rc1", false),
+    TestVersionRange("<=1.2", "1.2.9", true),
+    TestVersionRange("<=1.2", "1.3.0", false),
+    // Caret ranges
+    TestVersionRange("^1.4", "1.4.0", true),
+    TestVersionRange("^1.4", "1.9.3", true),
+    TestVersionRange("^1.4", "1.3.9", false),
+    TestVersionRange("^1.4", "2.0.0", false),
+    TestVersionRange("^1.4", "2.0.0-rc.1", false),
+    TestVersionRange("^1.4", "1.9.9-rc.1", true),
+    TestVersionRange("^1.2.3", "1.2.3", true),
+    TestVersionRange("^0.2.3", "0.2.9", true),
+    TestVersionRange("^0.2.3", "0.3.0", false),
+    TestVersionRange("^0.0.3", "0.0.3", true),
+    TestVersionRange("^0.0.3", "0.0.4", false),
+    TestVersionRange("^0.0", "0.0.9", true),
+    TestVersionRange("^0.0", "0.1.0", false),
+    TestVersionRange("^0", "0.9.9", true),
+    TestVersionRange("^0", "1.0.0", false),
+    TestVersionRange("^1.x", "1.9.0", true),
+    TestVersionRange("^1.x", "2.0.0", false),
+    // Tilde ranges
+    TestVersionRange("~1.4.2", "1.4.2", true),
+    TestVersionRange("~1.4.2", "1.4.9", true),
+    TestVersionRange("~1.4.2", "1.4.1", false),
+    TestVersionRange("~1.4.2", "1.5.0", false),
+    TestVersionRange("~1.4.2", "1.5.0-alpha", false),
+    TestVersionRange("~1.4", "1.4.9", true),
+    TestVersionRange("~1", "1.9.9", true),
+    TestVersionRange("~1", "2.0", false),
+    TestVersionRange("~>1.4.2", "1.4.5", true),
+    // Hyphen ranges
+    TestVersionRange("1.2 - 1.8", "1.2.0", true),
+    TestVersionRange("1.2 - 1.8", "1.8.9", true),
+    TestVersionRange("1.2 - 1.8", "1.9.0", false),
+    TestVersionRange("1.2 - 1.8", "1.1.9", false),
+    TestVersionRange("1.2.3 - 2.3.4", "2.3.4", true),
+    TestVersionRange("1.2.3 - 2.3.4", "2.3.5", false),
+    // Comparator sets
+    TestVersionRange(">=1.2, <2.0", "1.2", true),
+    TestVersionRange(">=1.2, <2.0", "1.9.9", true),
+    TestVersionRange(">=1.2, <2.0", "2.0", false),
+    TestVersionRange(">=1.2 <2.0", "1.1", false),
+    TestVersionRange(">=1.2 <2.0 !=1.5", "1.5.0", false),
+    // Alternatives
+    TestVersionRange("1.2.7 || >=1.2.9 <2.0.0", "1.2.7", true),
+    TestVersionRange("1.2.7 || >=1.2.9 <2.0.0", "1.2.8", false),
+    TestVersionRange("1.2.7 || >=1.2.9 <2.0.0", "1.4.6", true),
+    TestVersionRange("^1.4 || ^3", "2.1", false),
+    TestVersionRange("^1.4 || ^3", "3.1", true),
+];
+
+/// List of invalid version ranges for dynamic tests
+pub const TEST_VERSION_RANGES_ERROR: &[&str] = &[
+    "abc",
+    ">=",
+    ">>1.2",
+    "=>1.2",
+    "<*",
+    "1.2 - ",
+    ">=1.2 || <>=2",
+];
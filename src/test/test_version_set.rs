@@ -11,7 +11,7 @@ use crate::comp_op::CompOp;
 pub struct TestVersionSet(pub &'static str, pub &'static str, pub CompOp);
 
 /// List of version sets for dynamic tests
-pub const TEST_VERSION_SETS: &'static [TestVersionSet] = &[
+pub const TEST_VERSION_SETS: &[TestVersionSet] = &[
     TestVersionSet("1", "1", CompOp::Eq),
     TestVersionSet("1.0.0.0", "1", CompOp::Eq),
     TestVersionSet("1", "1.0.0.0", CompOp::Eq),
@@ -33,14 +33,36 @@ pub const TEST_VERSION_SETS: &'static [TestVersionSet] = &[
     TestVersionSet("2", "1.2.3.4", CompOp::Gt),
     TestVersionSet("123", "123", CompOp::Eq),
     TestVersionSet("123", "1.2.3", CompOp::Gt),
+    TestVersionSet("2023101512345", "2023101512344", CompOp::Gt),
+    TestVersionSet("1.2023101512345", "1.2023101512345.0", CompOp::Eq),
+    TestVersionSet("1.4294967296", "1.4294967295", CompOp::Gt),
+    TestVersionSet("18446744073709551615", "18446744073709551614", CompOp::Gt),
+    TestVersionSet(
+        "1.00018446744073709551615",
+        "1.18446744073709551615",
+        CompOp::Eq,
+    ),
     TestVersionSet("1.2.3", "123", CompOp::Lt),
     TestVersionSet("1.1.2", "1.1.30-dev", CompOp::Lt),
     TestVersionSet("1.2.3", "1.2.3.alpha", CompOp::Gt),
     TestVersionSet("1.2.3", "1.2.3-dev", CompOp::Gt),
-    TestVersionSet("1.2.3.dev", "1.2.3.alpha", CompOp::Eq),
-    TestVersionSet("1.2.3-dev", "1.2.3-alpha", CompOp::Eq),
-    TestVersionSet("1.2.3.dev.1", "1.2.3.alpha", CompOp::Gt),
-    TestVersionSet("1.2.3-dev-1", "1.2.3-alpha", CompOp::Gt),
+    TestVersionSet("1.2.3.dev", "1.2.3.alpha", CompOp::Lt),
+    TestVersionSet("1.2.3-dev", "1.2.3-alpha", CompOp::Lt),
+    TestVersionSet("1.2.3.dev.1", "1.2.3.alpha", CompOp::Lt),
+    TestVersionSet("1.2.3-dev-1", "1.2.3-alpha", CompOp::Lt),
+    TestVersionSet("1.2.3.alpha.1", "1.2.3.alpha", CompOp::Gt),
+    TestVersionSet("1.2.3.1", "1.2.3.alpha", CompOp::Gt),
+    TestVersionSet("1.2.alpha", "1.2.beta", CompOp::Lt),
+    TestVersionSet("1.2.beta", "1.2.rc", CompOp::Lt),
+    TestVersionSet("1.2.rc", "1.2.release", CompOp::Lt),
+    TestVersionSet("1.2.release", "1.2", CompOp::Lt),
+    TestVersionSet("1.2.a", "1.2.alpha", CompOp::Eq),
+    TestVersionSet("1.2.snapshot", "1.2.dev", CompOp::Lt),
+    TestVersionSet("1.0-rc1", "1.0-rc2", CompOp::Lt),
+    TestVersionSet("1.0-rc10", "1.0-rc2", CompOp::Gt),
+    TestVersionSet("1.0-RC1", "1.0-rc1", CompOp::Eq),
+    TestVersionSet("1.0-beta2", "1.0-rc1", CompOp::Lt),
+    TestVersionSet("1.0-foo", "1.0-bar", CompOp::Gt),
     TestVersionSet("version-compare 3.2.0 / build 0932", "3.2.5", CompOp::Lt),
     TestVersionSet("version-compare 3.2.0 / build 0932", "3.1.1", CompOp::Gt),
     TestVersionSet(
@@ -53,13 +75,21 @@ pub const TEST_VERSION_SETS: &'static [TestVersionSet] = &[
         "version-compare 1.4.1 / build 0043",
         CompOp::Lt,
     ),
-    // TODO: inspect these cases
+    TestVersionSet("1.2 / build 5", "1.2.0 / build 5.0", CompOp::Eq),
+    TestVersionSet("1.2 (beta)", "1.2.1 (alpha)", CompOp::Lt),
+    TestVersionSet("1.0 rc1", "1.0", CompOp::Lt),
+    TestVersionSet("1.0 beta", "1.0", CompOp::Lt),
+    TestVersionSet("Version 1.2 rc1", "1.2", CompOp::Lt),
+    TestVersionSet("1 2 3", "1.2.3", CompOp::Eq),
+    TestVersionSet("MyApp 3.2.0", "OtherApp v3.2", CompOp::Eq),
+    TestVersionSet("release-1.2", "v1.2.0", CompOp::Eq),
+    TestVersionSet("release-1.2", "1.2.release", CompOp::Gt),
     TestVersionSet("snapshot.1.2.3", "1.2.3.alpha", CompOp::Lt),
     TestVersionSet("snapshot-1.2.3", "1.2.3-alpha", CompOp::Lt),
 ];
 
 /// List of invalid version sets for dynamic tests
-pub const TEST_VERSION_SETS_ERROR: &'static [TestVersionSet] = &[
+pub const TEST_VERSION_SETS_ERROR: &[TestVersionSet] = &[
     TestVersionSet("1.2.3", "1.2.3", CompOp::Lt),
     TestVersionSet("1.2", "1.2.0.0", CompOp::Ne),
     TestVersionSet("1.2.3.dev", "dev", CompOp::Eq),
@@ -4,60 +4,119 @@
 //! is made. This struct provides many methods and features for easy comparison, probing and other
 //! things.
 
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
 use std::fmt;
-use std::iter::Peekable;
-use std::slice::Iter;
+use std::hash::{Hash, Hasher};
+use std::iter::{self, Peekable};
+use std::ops::{Range, RangeInclusive};
 
+use crate::bump;
 use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::part_vec::PartVec;
+use crate::scheme::calver::{self, Calver, CalverDate};
+use crate::scheme::debian::{self, Debian};
+use crate::scheme::generic;
+use crate::scheme::maven::{self, Maven};
+use crate::scheme::pep440::{self, Pep440};
+use crate::scheme::rpm::{self, Rpm};
+use crate::scheme::{semver, Scheme};
 use crate::version_manifest::VersionManifest;
 use crate::version_part::VersionPart;
 
 /// Version struct, which is a representation for a parsed version string.
 ///
 /// A version in string format can be parsed using methods like `Version::from("1.2.3");`.
-/// These methods return a `Result` holding the parsed version or an error on failure.
+/// These methods return a `Result` holding the parsed version or an `Error` on failure.
 ///
 /// The original version string is stored in the struct, and can be accessed using the
 /// `version.as_str()` method. Note, that when the version wasn't parsed from a string
 /// representation, the returned value is generated.
 ///
+/// A version may borrow the string it was parsed from. Use `Version::into_owned` or
+/// `Version::from_string` to get a `Version<'static>` which owns all of its data, so it can be
+/// stored in long-lived structures or sent across threads.
+///
 /// The struct provides many methods for comparison and probing.
+#[derive(Clone)]
 pub struct Version<'a> {
-    version: &'a str,
-    parts: Vec<VersionPart<'a>>,
-    manifest: Option<&'a VersionManifest>,
+    version: Cow<'a, str>,
+    parts: PartVec<'a>,
+    parsed: Parsed<'a>,
+    manifest: Option<Cow<'a, VersionManifest>>,
+}
+
+/// What's kept of a parsed version string besides its parts, so versions can be compared and
+/// hashed without parsing them again.
+#[derive(Clone)]
+pub(crate) enum Parsed<'a> {
+    /// The layout of a version following the generic scheme.
+    Generic(generic::Layout<'a>),
+
+    /// A PEP 440 version.
+    Pep440(Pep440<'a>),
+
+    /// A Maven version.
+    Maven(Maven),
+
+    /// A calendar version.
+    Calver(Calver<'a>),
+
+    /// A version following another scheme, which is compared by its parts or by its version
+    /// string, or a version that wasn't parsed from its version string.
+    Other,
+}
+
+impl<'a> Parsed<'a> {
+    /// Convert into owned data, that doesn't borrow anything.
+    fn into_owned(self) -> Parsed<'static> {
+        match self {
+            Parsed::Generic(layout) => Parsed::Generic(layout.into_owned()),
+            Parsed::Pep440(version) => Parsed::Pep440(version.into_owned()),
+            Parsed::Maven(version) => Parsed::Maven(version),
+            Parsed::Calver(version) => Parsed::Calver(version.into_owned()),
+            Parsed::Other => Parsed::Other,
+        }
+    }
 }
 
 impl<'a> Version<'a> {
     /// Create a `Version` instance from a version string.
     ///
     /// The version string should be passed to the `version` parameter.
+    /// An error is returned if the version string couldn't be parsed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use version_compare::{CompOp, Version};
+    /// use version_compare::{CompOp, Error, Version};
     ///
     /// let ver = Version::from("1.2.3").unwrap();
     ///
     /// assert_eq!(ver.compare(&Version::from("1.2.3").unwrap()), CompOp::Eq);
+    /// assert_eq!(Version::from("abc").unwrap_err(), Error::NoNumber);
     /// ```
-    pub fn from(version: &'a str) -> Option<Self> {
-        // Split the version string
-        let parts = Self::split_version_str(version, None);
-
-        // Return nothing if the parts are none
-        if parts.is_none() {
-            return None;
-        }
+    pub fn from(version: &'a str) -> Result<Self, Error> {
+        Self::parse(Cow::Borrowed(version), None)
+    }
 
-        // Create and return the object
-        Some(Version {
-            version: version,
-            parts: parts.unwrap(),
-            manifest: None,
-        })
+    /// Create an owned `Version` instance from a version string.
+    ///
+    /// This takes ownership of the given `version` string, the returned version doesn't borrow
+    /// any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Version};
+    ///
+    /// let ver: Version<'static> = Version::from_string(String::from("1.2.3")).unwrap();
+    ///
+    /// assert_eq!(ver.compare(&Version::from("1.2.3").unwrap()), CompOp::Eq);
+    /// ```
+    pub fn from_string(version: String) -> Result<Version<'static>, Error> {
+        Version::parse(Cow::Owned(version), None)
     }
 
     /// Create a `Version` instance from already existing parts
@@ -70,8 +129,10 @@ impl<'a> Version<'a> {
     /// ```
     pub fn from_parts(version: &'a str, version_parts: Vec<VersionPart<'a>>) -> Self {
         Version {
-            version: version,
-            parts: version_parts,
+            version: Cow::Borrowed(version),
+            parts: PartVec::from(version_parts),
+            parsed: generic::parse(version, &VersionManifest::new())
+                .map_or(Parsed::Other, |(_, layout)| Parsed::Generic(layout)),
             manifest: None,
         }
     }
@@ -90,23 +151,171 @@ impl<'a> Version<'a> {
     ///
     /// assert_eq!(ver.compare(&Version::from("1.2.3").unwrap()), CompOp::Eq);
     /// ```
-    pub fn from_manifest(version: &'a str, manifest: &'a VersionManifest) -> Option<Self> {
-        // Split the version string
-        let parts = Self::split_version_str(version, Some(&manifest));
+    pub fn from_manifest(version: &'a str, manifest: &'a VersionManifest) -> Result<Self, Error> {
+        Self::parse(Cow::Borrowed(version), Some(Cow::Borrowed(manifest)))
+    }
 
-        // Return nothing if the parts are none
-        if parts.is_none() {
-            return None;
-        }
+    /// Create a `Version` instance from a version string, using the given version `scheme`.
+    ///
+    /// This is a shorthand for parsing the version with a manifest that only sets the scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Scheme, Version};
+    ///
+    /// let ver_a = Version::from_scheme("1.0.0-alpha.1+build.5", Scheme::Semver).unwrap();
+    /// let ver_b = Version::from_scheme("1.0.0-alpha.beta", Scheme::Semver).unwrap();
+    ///
+    /// assert_eq!(ver_a.compare(&ver_b), CompOp::Lt);
+    /// assert!(Version::from_scheme("1.2", Scheme::Semver).is_err());
+    /// ```
+    pub fn from_scheme(version: &'a str, scheme: Scheme) -> Result<Self, Error> {
+        let mut manifest = VersionManifest::new();
+        manifest.set_scheme(scheme);
+
+        Self::parse(Cow::Borrowed(version), Some(Cow::Owned(manifest)))
+    }
+
+    /// Parse the given version string following the `manifest`, and create the version.
+    fn parse(
+        version: Cow<'a, str>,
+        manifest: Option<Cow<'a, VersionManifest>>,
+    ) -> Result<Self, Error> {
+        let mut version = Version {
+            version,
+            parts: PartVec::new(),
+            parsed: Parsed::Other,
+            manifest: None,
+        };
+        version.set_manifest_cow(manifest)?;
+        Ok(version)
+    }
+
+    /// Find all versions in the given free-form text, such as the output of `git --version`.
+    ///
+    /// Each version is returned with its byte span in the text, and is parsed following the
+    /// generic scheme. A candidate starts with two numbers separated by a dot, and continues over
+    /// alphanumeric characters and `.`, `-`, `_`, `+` or `~`. Candidates that look like a date,
+    /// such as `15.03.2022`, or like an IPv4 address, such as `192.168.1.20`, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let text = "Python 3.11.4 (main, Jun  7 2023) [GCC 12.2.0]";
+    /// let found = Version::find_all(text);
+    ///
+    /// assert_eq!(found.len(), 2);
+    /// assert_eq!(found[0].0, 7..13);
+    /// assert_eq!(found[0].1, Version::from("3.11.4").unwrap());
+    /// assert_eq!(found[1].1.as_str(), "12.2.0");
+    /// ```
+    pub fn find_all(text: &'a str) -> Vec<(Range<usize>, Self)> {
+        Self::find_iter(text).collect()
+    }
+
+    /// Find the first version in the given free-form text, see `find_all`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let (span, ver) = Version::find_first("git version 2.43.0").unwrap();
+    ///
+    /// assert_eq!(span, 12..18);
+    /// assert_eq!(ver.as_str(), "2.43.0");
+    /// assert!(Version::find_first("OpenSSL built on 15.03.2022").is_none());
+    /// ```
+    pub fn find_first(text: &'a str) -> Option<(Range<usize>, Self)> {
+        Self::find_iter(text).next()
+    }
 
-        // Create and return the object
-        Some(Version {
-            version: version,
-            parts: parts.unwrap(),
-            manifest: Some(&manifest),
+    /// Iterate over the versions found in the given free-form text, see `find_all`.
+    fn find_iter(text: &'a str) -> impl Iterator<Item = (Range<usize>, Version<'a>)> {
+        let bytes = text.as_bytes();
+        let is_candidate_byte =
+            |b: &u8| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_' | b'+' | b'~');
+
+        let mut offset = 0;
+        iter::from_fn(move || loop {
+            // Find the next digit, which starts a candidate
+            let start = offset + bytes[offset..].iter().position(u8::is_ascii_digit)?;
+
+            // Find the end of the candidate, without trailing punctuation
+            offset = bytes[start..]
+                .iter()
+                .position(|b| !is_candidate_byte(b))
+                .map_or(bytes.len(), |len| start + len);
+            let end = start
+                + bytes[start..offset]
+                    .iter()
+                    .rposition(u8::is_ascii_alphanumeric)
+                    .map_or(0, |index| index + 1);
+
+            // Parse the candidate if it looks like a version
+            let candidate = &text[start..end];
+            if !Self::is_version_like(candidate) {
+                continue;
+            }
+            if let Ok(version) = Version::from(candidate) {
+                return Some((start..end, version));
+            }
         })
     }
 
+    /// Check whether the given candidate found in free-form text looks like a version.
+    ///
+    /// The candidate must start with two numbers separated by a dot. Candidates with three
+    /// numbers that form a date with a year between 1900 and 2099 first or last, and candidates
+    /// with four numbers up to 255 that form an IPv4 address not starting below 10, aren't
+    /// considered a version.
+    fn is_version_like(candidate: &str) -> bool {
+        let is_number =
+            |segment: &str| !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit());
+        let segments: Vec<&str> = candidate.split('.').collect();
+
+        // The candidate must start with two numbers separated by a dot
+        if segments.len() < 2
+            || !is_number(segments[0])
+            || !segments[1].starts_with(|c: char| c.is_ascii_digit())
+        {
+            return false;
+        }
+
+        // Dates and addresses only consist of numbers
+        if !segments.iter().all(|segment| is_number(segment)) {
+            return true;
+        }
+        let in_range = |segment: &str, max_len: usize, range: RangeInclusive<u32>| {
+            segment.len() <= max_len
+                && segment
+                    .parse::<u32>()
+                    .is_ok_and(|number| range.contains(&number))
+        };
+        let is_date = |year: &str, month: &str, day: &str| {
+            year.len() == 4
+                && in_range(year, 4, 1900..=2099)
+                && in_range(month, 2, 1..=12)
+                && in_range(day, 2, 1..=31)
+        };
+
+        match segments[..] {
+            // Skip dates, such as `2022.03.15` and `15.03.2022`
+            [year, month, day] if is_date(year, month, day) => false,
+            [day, month, year] if is_date(year, month, day) => false,
+
+            // Skip IPv4 addresses, such as `192.168.1.20`
+            [first, _, _, _] => {
+                !(in_range(first, 3, 10..=255)
+                    && segments.iter().all(|segment| in_range(segment, 3, 0..=255)))
+            }
+            _ => true,
+        }
+    }
+
     /// Get the version manifest, if available.
     ///
     /// # Examples
@@ -126,7 +335,7 @@ impl<'a> Version<'a> {
     /// }
     /// ```
     pub fn manifest(&self) -> Option<&VersionManifest> {
-        self.manifest
+        self.manifest.as_deref()
     }
 
     /// Check whether this version has a manifest.
@@ -150,82 +359,313 @@ impl<'a> Version<'a> {
 
     /// Set the version manifest.
     ///
+    /// The version string is parsed again following the new manifest, so the parts and
+    /// comparisons reflect it. An error is returned if the version string isn't valid with the
+    /// new manifest, in which case the version is left unchanged.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use version_compare::{Version, VersionManifest};
+    /// use version_compare::{Scheme, Version, VersionManifest};
     ///
-    /// let manifest = VersionManifest::new();
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_max_depth_number(2);
     /// let mut version = Version::from("1.2.3").unwrap();
     ///
-    /// version.set_manifest(Some(&manifest));
+    /// version.set_manifest(Some(&manifest)).unwrap();
+    /// assert_eq!(version.part_count(), 2);
+    ///
+    /// let mut semver = VersionManifest::new();
+    /// semver.set_scheme(Scheme::Semver);
+    /// let mut version = Version::from("1.2").unwrap();
+    ///
+    /// assert!(version.set_manifest(Some(&semver)).is_err());
+    /// assert_eq!(version.manifest(), None);
+    /// ```
+    pub fn set_manifest(&mut self, manifest: Option<&'a VersionManifest>) -> Result<(), Error> {
+        self.set_manifest_cow(manifest.map(Cow::Borrowed))
+    }
+
+    /// Get this version with the given `manifest`.
+    ///
+    /// The version string is parsed again following the manifest, like `set_manifest`. An error
+    /// is returned if the version string isn't valid with the manifest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Scheme, Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_scheme(Scheme::Semver);
+    ///
+    /// let ver_a = Version::from("1.0.0-rc.11").unwrap().with_manifest(&manifest).unwrap();
+    /// let ver_b = Version::from("1.0.0-rc.2").unwrap().with_manifest(&manifest).unwrap();
+    ///
+    /// assert_eq!(ver_a.compare(&ver_b), CompOp::Gt);
     /// ```
-    pub fn set_manifest(&mut self, manifest: Option<&'a VersionManifest>) {
+    pub fn with_manifest(mut self, manifest: &'a VersionManifest) -> Result<Self, Error> {
+        self.set_manifest(Some(manifest))?;
+        Ok(self)
+    }
+
+    /// Set the manifest, and parse the version string again following it.
+    ///
+    /// The version is unchanged if the version string isn't valid with the manifest.
+    fn set_manifest_cow(
+        &mut self,
+        manifest: Option<Cow<'a, VersionManifest>>,
+    ) -> Result<(), Error> {
+        // Re-parse the version string, because the manifest might change its parts
+        let (parts, parsed) = match &self.version {
+            Cow::Borrowed(version) => Self::split_version_str(version, manifest.as_deref())?,
+
+            // Parts can't borrow from an owned version string, so take ownership of them
+            Cow::Owned(version) => {
+                let (parts, parsed) = Self::split_version_str(version, manifest.as_deref())?;
+                (parts.into_owned(), parsed.into_owned())
+            }
+        };
+        self.parts = parts;
+        self.parsed = parsed;
         self.manifest = manifest;
+        Ok(())
+    }
+
+    /// Get the version scheme of this version.
+    ///
+    /// This is the scheme set in the manifest, or the generic scheme if there is no manifest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// assert_eq!(Version::from("1.2.3").unwrap().scheme(), &Scheme::Generic);
+    /// assert_eq!(Version::from_scheme("1.2.3", Scheme::Semver).unwrap().scheme(), &Scheme::Semver);
+    /// ```
+    pub fn scheme(&self) -> &Scheme {
+        self.manifest()
+            .map_or(&Scheme::Generic, VersionManifest::scheme)
+    }
 
-        // TODO: Re-parse the version string, because the manifest might have changed.
+    /// Get the build metadata of this version, without the leading `+`.
+    ///
+    /// Build metadata is only recognized by the semver scheme, `None` is returned for other
+    /// schemes or if the version has no build metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// let ver = Version::from_scheme("1.0.0-rc.1+build.5", Scheme::Semver).unwrap();
+    ///
+    /// assert_eq!(ver.build_metadata(), Some("build.5"));
+    /// ```
+    pub fn build_metadata(&self) -> Option<&str> {
+        match self.scheme() {
+            Scheme::Semver => semver::sections(self.as_str()).2,
+            _ => None,
+        }
     }
 
-    /// Split the given version string, in it's version parts.
-    /// TODO: Move this method to some sort of helper class, maybe as part of `VersionPart`.
-    fn split_version_str(
-        version: &'a str,
-        manifest: Option<&'a VersionManifest>,
-    ) -> Option<Vec<VersionPart<'a>>> {
-        // Split the version string, and create a vector to put the parts in
-        // TODO: split at specific separators instead
-        let split = version.split(|c| !char::is_alphanumeric(c));
-        let mut parts = Vec::new();
+    /// Get the epoch of this version.
+    ///
+    /// An epoch is only recognized by schemes that support it: PEP 440, Debian and RPM. Zero
+    /// is returned if the version doesn't specify an epoch, and `None` is returned for other
+    /// schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// assert_eq!(Version::from_scheme("1!2.0", Scheme::Pep440).unwrap().epoch(), Some(1));
+    /// assert_eq!(Version::from_scheme("2.0", Scheme::Pep440).unwrap().epoch(), Some(0));
+    /// assert_eq!(Version::from("1!2.0").unwrap().epoch(), None);
+    /// ```
+    pub fn epoch(&self) -> Option<u64> {
+        match self.scheme() {
+            Scheme::Pep440 => match &self.parsed {
+                Parsed::Pep440(version) => Some(version.epoch),
+                _ => None,
+            },
+            Scheme::Debian => Debian::parse(self.as_str()).ok().map(|v| v.epoch),
+            Scheme::Rpm => Rpm::parse(self.as_str()).ok().map(|v| v.epoch),
+            _ => None,
+        }
+    }
 
-        // Get the manifest to follow
-        let mut used_manifest = &VersionManifest::new();
-        if manifest.is_some() {
-            used_manifest = manifest.unwrap();
+    /// Get the revision of this version.
+    ///
+    /// A revision is only recognized by schemes that support it: the Debian revision or RPM
+    /// release after the last hyphen. `None` is returned if the version doesn't specify a
+    /// revision, or for other schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// let ver = Version::from_scheme("1:2.30-0ubuntu1", Scheme::Debian).unwrap();
+    ///
+    /// assert_eq!(ver.epoch(), Some(1));
+    /// assert_eq!(ver.revision(), Some("0ubuntu1"));
+    /// ```
+    pub fn revision(&self) -> Option<&str> {
+        match self.scheme() {
+            Scheme::Debian => Debian::parse(self.as_str()).ok()?.revision,
+            Scheme::Rpm => Rpm::parse(self.as_str()).ok()?.release,
+            _ => None,
         }
+    }
 
-        // Flag to determine whether this version number contains any number part
-        let mut has_number = false;
+    /// Get the date of this version.
+    ///
+    /// A date is only recognized by the calendar versioning scheme, `None` is returned for other
+    /// schemes. Short years are relative to 2000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CalverFormat, Scheme, Version};
+    ///
+    /// let format = CalverFormat::from("YY.0M").unwrap();
+    /// let date = Version::from_scheme("24.04", Scheme::Calver(format)).unwrap().date().unwrap();
+    ///
+    /// assert_eq!(date.year, 2024);
+    /// assert_eq!(date.month, Some(4));
+    /// assert_eq!(Version::from("24.04").unwrap().date(), None);
+    /// ```
+    pub fn date(&self) -> Option<CalverDate> {
+        calver::parsed(self).map(|version| version.date)
+    }
 
-        // Loop over the parts, and parse them
-        for part in split {
-            // We may not go over the maximum depth
-            if used_manifest.max_depth().is_some()
-                && parts.len() >= used_manifest.max_depth_number()
-            {
-                break;
-            }
+    /// Get the prefix before the version core, such as `release-` in `release-1.2`.
+    ///
+    /// Prefixes are only recognized by the generic scheme. This may be a product name followed by
+    /// whitespace, a known keyword such as `release-` or `version-`, or a leading `v` or `V` if
+    /// allowed by the manifest. The prefix isn't used when comparing versions. `None` is returned
+    /// if the version has no prefix, or for other schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// assert_eq!(Version::from("MyApp 3.2.0").unwrap().prefix(), Some("MyApp "));
+    /// assert_eq!(Version::from("release-1.2").unwrap().prefix(), Some("release-"));
+    /// assert_eq!(Version::from("v1.2").unwrap().prefix(), Some("v"));
+    /// assert_eq!(Version::from("1.2").unwrap().prefix(), None);
+    /// ```
+    pub fn prefix(&self) -> Option<&str> {
+        let range = &self.layout()?.core;
+        Some(&self.as_str()[..range.start]).filter(|prefix| !prefix.is_empty())
+    }
 
-            // Skip empty parts
-            if part.is_empty() {
-                continue;
-            }
+    /// Get the suffix after the version core, such as ` / build 0932` in
+    /// `MyApp 3.2.0 / build 0932`.
+    ///
+    /// Suffixes are only recognized by the generic scheme, the suffix starts at the whitespace
+    /// before a word starting with `/` or `(`. Other words after the version core are part of
+    /// it, so `1.2 rc1` has no suffix. The suffix is only compared if the version cores are
+    /// equal. `None` is returned if the version has no suffix, or for other schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("MyApp 3.2.0 / build 0932").unwrap();
+    ///
+    /// assert_eq!(ver.suffix(), Some(" / build 0932"));
+    /// assert!(ver < Version::from("3.2.0 / build 0933").unwrap());
+    /// assert!(ver > Version::from("3.1").unwrap());
+    /// ```
+    pub fn suffix(&self) -> Option<&str> {
+        let range = &self.layout()?.core;
+        Some(&self.as_str()[range.end..]).filter(|suffix| !suffix.is_empty())
+    }
 
-            // Try to parse the value as an number
-            match part.parse::<i32>() {
-                Ok(number) => {
-                    // Push the number part to the vector, and set the has number flag
-                    parts.push(VersionPart::Number(number));
-                    has_number = true;
-                }
-                Err(_) => {
-                    // Ignore text parts if specified
-                    if used_manifest.ignore_text() {
-                        continue;
-                    }
+    /// Get the parts of the suffix, which are empty if this version doesn't use the generic
+    /// scheme.
+    fn suffix_parts(&self) -> &[VersionPart<'a>] {
+        self.layout().map_or(&[], |layout| &layout.suffix)
+    }
 
-                    // Push the text part to the vector
-                    parts.push(VersionPart::Text(part))
-                }
-            }
+    /// Get the layout of the version string, or `None` if this version doesn't use the generic
+    /// scheme.
+    fn layout(&self) -> Option<&generic::Layout<'a>> {
+        match &self.parsed {
+            Parsed::Generic(layout) => Some(layout),
+            _ => None,
         }
+    }
 
-        // The version must contain a number part, if any part was parsed
-        if !has_number && !parts.is_empty() {
-            return None;
-        }
+    /// Get what's kept of the parsed version string, to compare it following its scheme.
+    pub(crate) fn parsed(&self) -> &Parsed<'a> {
+        &self.parsed
+    }
+
+    /// Split the given version string, in it's version parts.
+    ///
+    /// The version string is parsed following the rules of the scheme in the manifest. The rest
+    /// of this description applies to the generic scheme.
+    ///
+    /// Parts are separated by the separators configured in the manifest, which are any whitespace
+    /// or ASCII punctuation by default. Other characters that aren't alphanumeric are treated
+    /// following the character policy of the manifest, by default they're ignored.
+    ///
+    /// TODO: Move this method to some sort of helper class, maybe as part of `VersionPart`.
+    fn split_version_str<'b>(
+        version: &'b str,
+        manifest: Option<&VersionManifest>,
+    ) -> Result<(PartVec<'b>, Parsed<'b>), Error> {
+        // Get the manifest to follow
+        let default_manifest = VersionManifest::new();
+        let used_manifest = manifest.unwrap_or(&default_manifest);
+
+        // Parse the version string following the rules of the scheme, and keep what's needed to
+        // compare it
+        let (parts, parsed) = match used_manifest.scheme() {
+            Scheme::Generic => {
+                let (parts, layout) = generic::parse(version, used_manifest)?;
+                return Ok((parts, Parsed::Generic(layout)));
+            }
+            Scheme::Semver => (semver::split(version)?, Parsed::Other),
+            Scheme::Pep440 => {
+                let version = Pep440::parse(version)?;
+                (version.parts(), Parsed::Pep440(version))
+            }
+            Scheme::Debian => (Debian::parse(version)?.parts()?, Parsed::Other),
+            Scheme::Rpm => (Rpm::parse(version)?.parts()?, Parsed::Other),
+            Scheme::Maven => {
+                let version = Maven::parse(version)?;
+                (version.parts(), Parsed::Maven(version))
+            }
+            Scheme::Calver(format) => {
+                let version = Calver::parse(version, format)?;
+                (version.parts(), Parsed::Calver(version))
+            }
+        };
+        Ok((PartVec::from(parts), parsed))
+    }
+
+    /// Get the number of parts, without trailing zeros.
+    fn trimmed_len(&self) -> usize {
+        self.parts
+            .iter()
+            .rposition(|part| *part != VersionPart::Number(0))
+            .map_or(0, |index| index + 1)
+    }
 
-        // Return the list of parts
-        Some(parts)
+    /// Get the rank of the separator before each part, if separators are significant.
+    ///
+    /// `None` is returned if the version doesn't use the generic scheme, or if separators aren't
+    /// significant in its manifest.
+    fn separator_ranks(&self) -> Option<&[Option<usize>]> {
+        self.layout()?.separator_ranks.as_deref()
     }
 
     /// Get the original version string.
@@ -249,25 +689,29 @@ impl<'a> Version<'a> {
     /// # Examples
     ///
     /// ```
-    /// use version_compare::{Version, VersionPart};
+    /// use version_compare::{Error, Version, VersionPart};
     ///
     /// let ver = Version::from("1.2.3").unwrap();
     ///
     /// assert_eq!(ver.part(0), Ok(&VersionPart::Number(1)));
     /// assert_eq!(ver.part(1), Ok(&VersionPart::Number(2)));
     /// assert_eq!(ver.part(2), Ok(&VersionPart::Number(3)));
+    /// assert_eq!(ver.part(3), Err(Error::DepthExceeded { index: 3, depth: 3 }));
     /// ```
-    pub fn part(&self, index: usize) -> Result<&VersionPart<'a>, ()> {
+    pub fn part(&self, index: usize) -> Result<&VersionPart<'a>, Error> {
         // Make sure the index is in-bound
         if index >= self.parts.len() {
-            return Err(());
+            return Err(Error::DepthExceeded {
+                index,
+                depth: self.parts.len(),
+            });
         }
 
         // Return the requested part
         Ok(&self.parts[index])
     }
 
-    /// Get a vector of all version parts.
+    /// Get a slice of all version parts.
     ///
     /// # Examples
     ///
@@ -276,13 +720,13 @@ impl<'a> Version<'a> {
     ///
     /// let ver = Version::from("1.2.3").unwrap();
     ///
-    /// assert_eq!(ver.parts(), &vec![
+    /// assert_eq!(ver.parts(), &[
     ///     VersionPart::Number(1),
     ///     VersionPart::Number(2),
     ///     VersionPart::Number(3)
     /// ]);
     /// ```
-    pub fn parts(&self) -> &Vec<VersionPart<'a>> {
+    pub fn parts(&self) -> &[VersionPart<'a>] {
         &self.parts
     }
 
@@ -313,6 +757,17 @@ impl<'a> Version<'a> {
     ///
     /// Other comparison operators can be used when comparing, but aren't returned by this method.
     ///
+    /// If both versions use the same version scheme, they're compared following the rules of
+    /// that scheme. Otherwise the generic rules are used, which are described below.
+    ///
+    /// Parts are compared one by one using `VersionPart::compare`. Text parts, such as
+    /// pre-release tags, are less than a missing part, so `1.2-rc1` is less than `1.2`. A leading
+    /// product name, such as `MyApp` in `MyApp 1.2`, is skipped. A suffix, such as ` (stable)` in
+    /// `1.2 (stable)`, is only compared the same way if all parts are equal.
+    ///
+    /// Comparison options of the manifest, such as case sensitivity, are used if both versions
+    /// enable them. Equality and ordering with `==` and `<` don't use these options.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -322,10 +777,91 @@ impl<'a> Version<'a> {
     /// assert_eq!(Version::from("1.9").unwrap().compare(&Version::from("1.9").unwrap()), CompOp::Eq);
     /// assert_eq!(Version::from("0.3.0.0").unwrap().compare(&Version::from("0.3").unwrap()), CompOp::Eq);
     /// assert_eq!(Version::from("2").unwrap().compare(&Version::from("1.7.3").unwrap()), CompOp::Gt);
+    /// assert_eq!(Version::from("1.0-rc1").unwrap().compare(&Version::from("1.0-rc2").unwrap()), CompOp::Lt);
+    /// assert_eq!(Version::from("1.0-rc2").unwrap().compare(&Version::from("1.0").unwrap()), CompOp::Lt);
     /// ```
-    pub fn compare(&self, other: &'a Version) -> CompOp {
+    pub fn compare(&self, other: &Version) -> CompOp {
+        self.compare_with(other, true)
+    }
+
+    /// Compare this version to the given `other` version, see `compare`.
+    ///
+    /// The comparison options of the manifests, such as case sensitivity, are only used if
+    /// `options` is true. Equality and ordering don't use them, as they must stay transitive
+    /// when comparing versions with different manifests.
+    fn compare_with(&self, other: &Version, options: bool) -> CompOp {
+        // Compare following the rules of the version scheme, if both versions share it
+        if self.scheme().is_compatible(other.scheme()) {
+            let result = match self.scheme() {
+                Scheme::Generic => None,
+                Scheme::Semver => Some(semver::compare(self, other)),
+                Scheme::Pep440 => pep440::compare(self, other),
+                Scheme::Debian => debian::compare(self, other),
+                Scheme::Rpm => rpm::compare(self, other),
+                Scheme::Maven => maven::compare(self, other),
+                Scheme::Calver(_) => calver::compare(self, other),
+            };
+            if let Some(result) = result {
+                return result;
+            }
+        }
+
+        // Trailing zeros are significant, if enabled for both versions
+        let trailing_zeros = options
+            && self
+                .manifest()
+                .is_some_and(VersionManifest::significant_trailing_zeros)
+            && other
+                .manifest()
+                .is_some_and(VersionManifest::significant_trailing_zeros);
+
+        // Compare the separators before the parts, if they're significant for both versions.
+        // Insignificant trailing zeros are skipped, so `1-0` is still equal to `1` and `1.0`.
+        if let (true, Some(ranks), Some(other_ranks)) =
+            (options, self.separator_ranks(), other.separator_ranks())
+        {
+            let (ranks, other_ranks) = match trailing_zeros {
+                true => (ranks, other_ranks),
+                false => (
+                    &ranks[..self.trimmed_len()],
+                    &other_ranks[..other.trimmed_len()],
+                ),
+            };
+            for (index, (rank, other_rank)) in ranks.iter().zip(other_ranks).enumerate() {
+                if rank != other_rank {
+                    return CompOp::from_ord(rank.cmp(other_rank));
+                }
+                if self.parts[index].compare(&other.parts[index]) != CompOp::Eq {
+                    break;
+                }
+            }
+        }
+
+        // Text parts are compared case-sensitively, if enabled for both versions
+        let case_sensitive = options
+            && self.manifest().is_some_and(VersionManifest::case_sensitive)
+            && other
+                .manifest()
+                .is_some_and(VersionManifest::case_sensitive);
+
         // Compare the versions with their peekable iterators
-        Self::compare_iter(self.parts.iter().peekable(), other.parts.iter().peekable())
+        let result = Self::compare_iter(
+            self.parts.iter().peekable(),
+            other.parts.iter().peekable(),
+            case_sensitive,
+            trailing_zeros,
+        );
+        if result != CompOp::Eq {
+            return result;
+        }
+
+        // Compare the suffixes if the versions are equal, such as the builds in `1.0 / build 42`
+        Self::compare_iter(
+            self.suffix_parts().iter().peekable(),
+            other.suffix_parts().iter().peekable(),
+            case_sensitive,
+            trailing_zeros,
+        )
     }
 
     /// Compare this version to the given `other` version,
@@ -344,211 +880,1193 @@ impl<'a> Version<'a> {
     /// assert!(Version::from("1.2").unwrap().compare_to(&Version::from("1.2").unwrap(), &CompOp::Le));
     /// ```
     pub fn compare_to(&self, other: &Version, operator: &CompOp) -> bool {
-        // Get the comparison result
-        let result = self.compare(&other);
-
-        // Match the result against the given operator
-        match result {
-            CompOp::Eq => match operator {
-                &CompOp::Eq | &CompOp::Le | &CompOp::Ge => true,
-                _ => false,
-            },
-            CompOp::Lt => match operator {
-                &CompOp::Ne | &CompOp::Lt | &CompOp::Le => true,
-                _ => false,
-            },
-            CompOp::Gt => match operator {
-                &CompOp::Ne | &CompOp::Gt | &CompOp::Ge => true,
-                _ => false,
-            },
-            _ => unreachable!(),
+        // Match the comparison result against the given operator
+        operator.holds_for(self.compare(other))
+    }
+
+    /// Convert into an owned version, that doesn't borrow any data.
+    ///
+    /// Borrowed data, such as the version string, text parts and the manifest, is cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// fn parse(version: &str) -> Version<'static> {
+    ///     Version::from(version).unwrap().into_owned()
+    /// }
+    ///
+    /// let buffer = String::from("1.2.3-rc1");
+    /// let ver = parse(&buffer);
+    /// drop(buffer);
+    ///
+    /// assert_eq!(ver.as_str(), "1.2.3-rc1");
+    /// ```
+    pub fn into_owned(self) -> Version<'static> {
+        Version {
+            version: Cow::Owned(self.version.into_owned()),
+            parts: self.parts.into_owned(),
+            parsed: self.parsed.into_owned(),
+            manifest: self
+                .manifest
+                .map(|manifest| Cow::Owned(manifest.into_owned())),
         }
     }
 
-    /// Compare two version numbers based on the iterators of their version parts.
+    /// Bump the major version, the first part, and reset the parts after it to zero.
     ///
-    /// This method returns one of the following comparison operators:
+    /// See `Version::bump_part` for details.
     ///
-    /// * `Lt`
-    /// * `Eq`
-    /// * `Gt`
+    /// # Examples
     ///
-    /// Other comparison operators can be used when comparing, but aren't returned by this method.
-    fn compare_iter(
-        mut iter: Peekable<Iter<VersionPart<'a>>>,
-        mut other_iter: Peekable<Iter<VersionPart<'a>>>,
-    ) -> CompOp {
-        // Iterate through the parts of this version
-        let mut other_part: Option<&VersionPart>;
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("v1.2.3").unwrap();
+    ///
+    /// assert_eq!(ver.bump_major().unwrap().as_str(), "v2.0.0");
+    /// assert_eq!(Version::from("2.0.0-rc.1").unwrap().bump_major().unwrap().as_str(), "2.0.0");
+    /// ```
+    pub fn bump_major(&self) -> Result<Version<'static>, Error> {
+        self.bump_part(0)
+    }
+
+    /// Bump the minor version, the second part, and reset the parts after it to zero.
+    ///
+    /// See `Version::bump_part` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("1.2.3").unwrap();
+    ///
+    /// assert_eq!(ver.bump_minor().unwrap().as_str(), "1.3.0");
+    /// assert_eq!(Version::from("1").unwrap().bump_minor().unwrap().as_str(), "1.1");
+    /// ```
+    pub fn bump_minor(&self) -> Result<Version<'static>, Error> {
+        self.bump_part(1)
+    }
+
+    /// Bump the patch version, the third part, and reset the parts after it to zero.
+    ///
+    /// See `Version::bump_part` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("MyApp 3.2.0 / build 0932").unwrap();
+    ///
+    /// assert_eq!(ver.bump_patch().unwrap().as_str(), "MyApp 3.2.1 / build 0932");
+    /// ```
+    pub fn bump_patch(&self) -> Result<Version<'static>, Error> {
+        self.bump_part(2)
+    }
+
+    /// Bump the numeric part at the given `index`, and reset the numeric parts after it to zero.
+    ///
+    /// Only the leading numeric parts are bumped, such as `1.2.0` in `1.2.0-rc.1`. Missing parts
+    /// are added as zero, so bumping index `2` of `1` gives `1.0.1`. The pre-release, everything
+    /// from the first text part on, is removed. If this version is a pre-release and the parts
+    /// after `index` are zero, it's only finalized because that's the next version, so bumping the
+    /// minor part of `1.3.0-rc.1` gives `1.3.0`. Build metadata after a `+`, such as `build.5` in
+    /// `1.2.3+build.5`, is kept.
+    ///
+    /// Semver versions are split following the semver scheme: the release is the major, minor and
+    /// patch number, and the pre-release is everything after the `-`, so `1.2.3-0` is a
+    /// pre-release too. Only these three parts can be bumped.
+    ///
+    /// A new owned version with the same manifest is returned. The version string is edited in
+    /// place, keeping its prefix, suffix, separators and zero padding. This is supported for
+    /// versions following the generic or semver scheme, `Error::CannotBump` is returned for other
+    /// schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("2024.09.1").unwrap();
+    ///
+    /// assert_eq!(ver.bump_part(1).unwrap().as_str(), "2024.10.0");
+    /// assert_eq!(ver.bump_part(3).unwrap().as_str(), "2024.09.1.1");
+    /// assert!(ver.bump_part(1).unwrap() > ver);
+    /// assert_eq!(Version::from("1.2.3+build.5").unwrap().bump_patch().unwrap().as_str(), "1.2.4+build.5");
+    /// ```
+    pub fn bump_part(&self, index: usize) -> Result<Version<'static>, Error> {
+        self.bumped(|version, manifest| bump::bump_part(version, manifest, index))
+    }
+
+    /// Bump the pre-release counter, or start a pre-release with the given `label`.
+    ///
+    /// If the pre-release has the given `label`, its counter is bumped, such as from `1.2.0-rc.1`
+    /// to `1.2.0-rc.2` or from `1.2.0-rc1` to `1.2.0-rc2`. Otherwise the pre-release is replaced,
+    /// or started if this version isn't a pre-release, with the `label` and counter `1`. Note that
+    /// a started pre-release, such as `1.2.0-rc.1` for `1.2.0`, is less than the original version,
+    /// so bump the release first. The label must only contain letters, or `Error::InvalidLabel` is
+    /// returned.
+    ///
+    /// A new owned version with the same manifest is returned, see `Version::bump_part`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("1.2.0-rc.1").unwrap();
+    /// assert_eq!(ver.bump_pre_release("rc").unwrap().as_str(), "1.2.0-rc.2");
+    ///
+    /// let ver = Version::from("v1.2.0").unwrap();
+    /// let next = ver.bump_minor().unwrap().bump_pre_release("beta").unwrap();
+    /// assert_eq!(next.as_str(), "v1.3.0-beta.1");
+    /// ```
+    pub fn bump_pre_release(&self, label: &str) -> Result<Version<'static>, Error> {
+        self.bumped(|version, manifest| bump::bump_pre_release(version, manifest, label))
+    }
+
+    /// Finalize a pre-release, by removing the pre-release and keeping any build metadata.
+    ///
+    /// A new owned version with the same manifest is returned, see `Version::bump_part`. A version
+    /// that isn't a pre-release is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("release-1.2.0-rc.2").unwrap();
+    ///
+    /// assert_eq!(ver.finalize().unwrap().as_str(), "release-1.2.0");
+    /// ```
+    pub fn finalize(&self) -> Result<Version<'static>, Error> {
+        self.bumped(bump::finalize)
+    }
+
+    /// Edit the version string with `edit`, and parse the result as new owned version with the
+    /// same manifest.
+    ///
+    /// The version string is split following the generic or semver scheme, an error is returned
+    /// for other schemes.
+    fn bumped(
+        &self,
+        edit: impl FnOnce(&str, &VersionManifest) -> Result<String, Error>,
+    ) -> Result<Version<'static>, Error> {
+        let default_manifest = VersionManifest::new();
+        let version = edit(self.as_str(), self.manifest().unwrap_or(&default_manifest))?;
+
+        Version::parse(
+            Cow::Owned(version),
+            self.manifest
+                .as_ref()
+                .map(|manifest| Cow::Owned(manifest.clone().into_owned())),
+        )
+    }
+
+    /// Compare two version numbers based on the iterators of their version parts.
+    ///
+    /// This method returns one of the following comparison operators:
+    ///
+    /// * `Lt`
+    /// * `Eq`
+    /// * `Gt`
+    ///
+    /// Other comparison operators can be used when comparing, but aren't returned by this method.
+    ///
+    /// If `case_sensitive` is true, text parts that only differ in case are ordered by their
+    /// characters. If `trailing_zeros` is true, trailing zero parts aren't skipped, so a version
+    /// with more parts is greater.
+    ///
+    /// The iterators may yield references to parts, or parts themselves, so version strings can
+    /// be compared while splitting them.
+    pub(crate) fn compare_iter<'p, P, I>(
+        mut iter: Peekable<I>,
+        mut other_iter: Peekable<I>,
+        case_sensitive: bool,
+        trailing_zeros: bool,
+    ) -> CompOp
+    where
+        P: Borrow<VersionPart<'p>>,
+        I: Iterator<Item = P>,
+    {
+        // Iterate over the iterator, without consuming it
+        for part in iter.by_ref() {
+            let part = part.borrow();
+
+            // Get the part for the other version
+            let other_part = match other_iter.next() {
+                Some(other_part) => other_part,
+
+                // If there are no parts left in the other version, try to determine the result
+                None => match part {
+                    // In the main version: if the current part is zero, continue to the next one
+                    VersionPart::Number(0) if !trailing_zeros => continue,
+
+                    // The main version is greater
+                    VersionPart::Number(_) => return CompOp::Gt,
+
+                    // Text parts, such as pre-release tags, are less than nothing
+                    VersionPart::Text(_) => return CompOp::Lt,
+                },
+            };
+            let other_part = other_part.borrow();
+
+            // Compare both parts, continue to the next ones if they're equal
+            match part.compare(other_part) {
+                CompOp::Eq if case_sensitive => match (part, other_part) {
+                    (VersionPart::Text(text), VersionPart::Text(other_text))
+                        if text != other_text =>
+                    {
+                        return CompOp::from_ord(text.cmp(other_text))
+                    }
+                    _ => continue,
+                },
+                CompOp::Eq => continue,
+                result => return result,
+            }
+        }
+
+        // Check whether we should iterate over the other iterator, if it has any items left
+        match other_iter.peek() {
+            // Compare based on the other iterator
+            Some(_) => {
+                Self::compare_iter(other_iter, iter, case_sensitive, trailing_zeros).as_flipped()
+            }
+
+            // Nothing more to iterate over, the versions should be equal
+            None => CompOp::Eq,
+        }
+    }
+}
+
+/// Display the version string.
+///
+/// PEP 440 versions are displayed in their normalized form.
+impl<'a> fmt::Display for Version<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Parsed::Pep440(version) = &self.parsed {
+            return write!(f, "{}", version);
+        }
+
+        write!(f, "{}", self.version)
+    }
+}
+
+// Show just the version component parts as debug output
+impl<'a> fmt::Debug for Version<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.parts)
+        } else {
+            write!(f, "{:?}", self.parts)
+        }
+    }
+}
+
+/// Implement the partial ordering trait for the version struct, to easily allow version comparison.
+impl<'a> PartialOrd for Version<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implement the total ordering trait for the version struct, to allow sorting versions and using
+/// them as key in ordered collections.
+///
+/// The comparison options of the manifests, such as significant trailing zeros, aren't used, so
+/// the ordering stays total when versions with different manifests are mixed. Use
+/// `Version::compare` to compare with these options.
+///
+/// Versions using different schemes are ordered by their scheme, see `Scheme`, instead of
+/// falling back to the generic rules like `Version::compare` does.
+impl<'a> Ord for Version<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Order by scheme first, the rules of different schemes don't agree with each other
+        if !self.scheme().is_compatible(other.scheme()) {
+            return self.scheme().rank().cmp(&other.scheme().rank());
+        }
+
+        self.compare_with(other, false).ord().unwrap()
+    }
+}
+
+/// Implement the partial equality trait for the version struct, to easily allow version comparison.
+///
+/// Equality is consistent with the ordering, and doesn't use the comparison options of the
+/// manifests either.
+impl<'a> PartialEq for Version<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Implement the equality trait for the version struct, versions are always comparable.
+impl<'a> Eq for Version<'a> {}
+
+/// Implement the hash trait for the version struct, consistent with version equality.
+///
+/// Versions are only equal if their schemes are compatible, and are then compared following the
+/// rules of their scheme or the generic rules. Only what all these rules agree on is hashed, which
+/// are the parts without trailing zeros, both at the end of the release numbers and at the end of
+/// the version.
+/// So `1.0.0` and `1` are hashed equally, and so are `1.0a1` and `1.0.0a1` in the PEP 440 scheme.
+/// The parts of a suffix, such as ` / build 0932`, are hashed after them without trailing zeros.
+impl<'a> Hash for Version<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Split the parts in the release numbers and the rest, without trailing zeros
+        let parts = &self.parts[..self.trimmed_len()];
+        let release = parts
+            .iter()
+            .take_while(|part| matches!(part, VersionPart::Number(_)))
+            .count();
+        let release_len = parts[..release]
+            .iter()
+            .rposition(|part| *part != VersionPart::Number(0))
+            .map_or(0, |index| index + 1);
+
+        state.write_usize(release_len + parts.len() - release);
+        for part in parts[..release_len].iter().chain(&parts[release..]) {
+            part.hash(state);
+        }
+
+        // Hash the parts of the suffix the same way, if it has any parts besides zeros
+        let suffix = self.suffix_parts();
+        let suffix_len = suffix
+            .iter()
+            .rposition(|part| *part != VersionPart::Number(0))
+            .map_or(0, |index| index + 1);
+        if suffix_len > 0 {
+            state.write_usize(suffix_len);
+            suffix[..suffix_len]
+                .iter()
+                .for_each(|part| part.hash(state));
+        }
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::cmp;
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeMap, HashSet};
+    use std::hash::{Hash, Hasher};
+    use std::thread;
+
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::scheme::{CalverFormat, Scheme};
+    use crate::test::test_calver::TEST_CALVER_SETS;
+    use crate::test::test_debian::TEST_DEBIAN_SETS;
+    use crate::test::test_maven::TEST_MAVEN_SETS;
+    use crate::test::test_pep440::TEST_PEP440_NORMALIZED;
+    use crate::test::test_rpm::TEST_RPM_SETS;
+    use crate::test::test_semver::{TEST_SEMVER_SETS, TEST_SEMVER_VERSIONS};
+    use crate::test::test_version::{TEST_VERSIONS, TEST_VERSIONS_ERROR};
+    use crate::test::test_version_find::TEST_VERSION_FINDS;
+    use crate::test::test_version_set::TEST_VERSION_SETS;
+    use crate::version_manifest::{CharPolicy, VersionManifest};
+    use crate::version_part::VersionPart;
+
+    use super::Version;
+
+    #[test]
+    // TODO: This doesn't really test whether this method fully works
+    fn from() {
+        // Test whether parsing works for each test version
+        for version in TEST_VERSIONS {
+            assert!(Version::from(version.0).is_ok());
+        }
+
+        // Test whether parsing works for each test invalid version
+        for version in TEST_VERSIONS_ERROR {
+            assert!(Version::from(version.0).is_err());
+        }
+    }
+
+    #[test]
+    fn from_error() {
+        assert_eq!(Version::from("abc").unwrap_err(), Error::NoNumber);
+        assert_eq!(
+            Version::from("test. .snapshot").unwrap_err(),
+            Error::NoNumber
+        );
+
+        // Other characters are only invalid if the manifest rejects them
+        let manifest = VersionManifest::new().with_other_chars(CharPolicy::Error);
+        assert_eq!(
+            Version::from_manifest("1.2\u{0}3", &manifest).unwrap_err(),
+            Error::InvalidChar {
+                offset: 3,
+                ch: '\u{0}'
+            }
+        );
+        assert_eq!(
+            Version::from_manifest("1.2 \u{2192} 1.3", &manifest).unwrap_err(),
+            Error::InvalidChar {
+                offset: 4,
+                ch: '\u{2192}'
+            }
+        );
+        assert_eq!(
+            Version::from("1.2\u{2122}").unwrap(),
+            Version::from("1.2").unwrap()
+        );
+    }
+
+    #[test]
+    // TODO: This doesn't really test whether this method fully works
+    fn from_manifest() {
+        // Create a manifest
+        let manifest = VersionManifest::new();
+
+        // Test whether parsing works for each test version
+        for version in TEST_VERSIONS {
+            assert_eq!(
+                Version::from_manifest(version.0, &manifest)
+                    .unwrap()
+                    .manifest(),
+                Some(&manifest)
+            );
+        }
+
+        // Test whether parsing works for each test invalid version
+        for version in TEST_VERSIONS_ERROR {
+            assert!(Version::from_manifest(version.0, &manifest).is_err());
+        }
+    }
+
+    #[test]
+    fn find_all() {
+        for entry in TEST_VERSION_FINDS {
+            let found: Vec<&str> = Version::find_all(entry.0)
+                .into_iter()
+                .map(|(span, version)| {
+                    assert_eq!(&entry.0[span.clone()], version.as_str());
+                    &entry.0[span]
+                })
+                .collect();
+            assert_eq!(found, entry.1, "Finding versions in {:?}", entry.0);
+        }
+    }
+
+    #[test]
+    fn find_first() {
+        for entry in TEST_VERSION_FINDS {
+            assert_eq!(
+                Version::find_first(entry.0).map(|(span, _)| &entry.0[span]),
+                entry.1.first().copied(),
+                "Finding first version in {:?}",
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn from_string() {
+        // Test whether parsing works for each test version
+        for version in TEST_VERSIONS {
+            let ver = Version::from_string(version.0.to_owned()).unwrap();
+            assert_eq!(ver.as_str(), version.0);
+            assert_eq!(ver.part_count(), version.1);
+        }
+
+        // Test whether parsing works for each test invalid version
+        for version in TEST_VERSIONS_ERROR {
+            assert!(Version::from_string(version.0.to_owned()).is_err());
+        }
+    }
+
+    #[test]
+    fn into_owned() {
+        // Parse versions from a temporary buffer, and send them to another thread
+        let versions: Vec<Version<'static>> = TEST_VERSIONS
+            .iter()
+            .map(|version| {
+                let buffer = version.0.to_owned();
+                Version::from(&buffer).unwrap().into_owned()
+            })
+            .collect();
+        let versions = thread::spawn(move || versions).join().unwrap();
+
+        for (ver, version) in versions.iter().zip(TEST_VERSIONS) {
+            assert_eq!(ver.as_str(), version.0);
+            assert_eq!(ver.parts(), Version::from(version.0).unwrap().parts());
+        }
+
+        // The manifest must be kept
+        let manifest = VersionManifest::new();
+        let ver = Version::from_manifest("1.2", &manifest).unwrap();
+        assert_eq!(ver.into_owned().manifest(), Some(&manifest));
+    }
+
+    #[test]
+    fn bump() {
+        // Bumped versions are greater, finalized versions aren't less
+        for version in TEST_VERSIONS {
+            let ver = Version::from(version.0).unwrap();
+            if ver
+                .part(0)
+                .is_ok_and(|part| matches!(part, VersionPart::Number(_)))
+            {
+                for bumped in [ver.bump_major(), ver.bump_minor(), ver.bump_patch()] {
+                    match bumped {
+                        Ok(bumped) => assert!(bumped > ver),
+                        Err(err) => assert!(matches!(err, Error::NumberOverflow { .. })),
+                    }
+                }
+                assert!(ver.finalize().unwrap() >= ver);
+            }
+        }
+
+        // Bumped versions are owned, and keep the manifest
+        let manifest = VersionManifest::new().with_separators(Some(&['.', '_']));
+        let bumped = {
+            let version = String::from("1_2_3");
+            Version::from_manifest(&version, &manifest)
+                .unwrap()
+                .bump_minor()
+                .unwrap()
+        };
+        assert_eq!(bumped.as_str(), "1_3_0");
+        assert_eq!(bumped.manifest(), Some(&manifest));
+        assert_eq!(format!("{}", bumped), "1_3_0");
+    }
+
+    #[test]
+    fn bump_scheme() {
+        // Semver versions keep their scheme, and must stay valid
+        let ver = Version::from_scheme("1.2.3-rc.1+build.5", Scheme::Semver).unwrap();
+        let next = ver.bump_pre_release("rc").unwrap();
+        assert_eq!(next.as_str(), "1.2.3-rc.2+build.5");
+        assert_eq!(next.scheme(), &Scheme::Semver);
+        assert_eq!(ver.finalize().unwrap().as_str(), "1.2.3+build.5");
+        assert_eq!(ver.bump_major().unwrap().as_str(), "2.0.0+build.5");
+        assert_eq!(ver.bump_part(3), Err(Error::CannotBump));
+
+        // Numeric pre-releases are pre-releases too
+        let ver = Version::from_scheme("1.2.3-0", Scheme::Semver).unwrap();
+        assert_eq!(ver.bump_patch().unwrap().as_str(), "1.2.3");
+        assert_eq!(ver.finalize().unwrap().as_str(), "1.2.3");
+        assert_eq!(ver.bump_pre_release("rc").unwrap().as_str(), "1.2.3-rc.1");
+        assert!(ver.bump_patch().unwrap() > ver);
+
+        // Other schemes can't be bumped
+        let ver = Version::from_scheme("1:2.3-4", Scheme::Debian).unwrap();
+        assert_eq!(ver.bump_minor(), Err(Error::CannotBump));
+        assert_eq!(
+            Version::from("1.2").unwrap().bump_pre_release("rc1"),
+            Err(Error::InvalidLabel("rc1".into()))
+        );
+    }
+
+    #[test]
+    fn manifest() {
+        let manifest = VersionManifest::new();
+        let mut version = Version::from("1.2.3").unwrap();
+
+        version.manifest = Some(Cow::Borrowed(&manifest));
+        assert_eq!(version.manifest(), Some(&manifest));
+
+        version.manifest = None;
+        assert_eq!(version.manifest(), None);
+    }
+
+    #[test]
+    fn has_manifest() {
+        let manifest = VersionManifest::new();
+        let mut version = Version::from("1.2.3").unwrap();
+
+        version.manifest = Some(Cow::Borrowed(&manifest));
+        assert!(version.has_manifest());
+
+        version.manifest = None;
+        assert!(!version.has_manifest());
+    }
+
+    #[test]
+    fn from_scheme() {
+        // Test whether parsing works for each semver test version
+        for version in TEST_SEMVER_VERSIONS {
+            let ver = Version::from_scheme(version.0, Scheme::Semver).unwrap();
+            assert_eq!(ver.part_count(), version.1);
+            assert_eq!(ver.manifest().unwrap().scheme(), &Scheme::Semver);
+        }
+
+        // Generic versions aren't necessarily valid semver versions
+        assert!(Version::from_scheme("1.2", Scheme::Generic).is_ok());
+        assert_eq!(
+            Version::from_scheme("1.2", Scheme::Semver).unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 3
+            }
+        );
+    }
+
+    #[test]
+    fn scheme() {
+        assert_eq!(Version::from("1.2.3").unwrap().scheme(), &Scheme::Generic);
+
+        let mut manifest = VersionManifest::new();
+        manifest.set_scheme(Scheme::Semver);
+        let version = Version::from_manifest("1.2.3", &manifest).unwrap();
+        assert_eq!(version.scheme(), &Scheme::Semver);
+    }
+
+    #[test]
+    fn build_metadata() {
+        let version = Version::from_scheme("1.2.3-rc.1+build.5", Scheme::Semver).unwrap();
+        assert_eq!(version.build_metadata(), Some("build.5"));
+
+        let version = Version::from_scheme("1.2.3-rc.1", Scheme::Semver).unwrap();
+        assert_eq!(version.build_metadata(), None);
+
+        let version = Version::from("1.2.3+build.5").unwrap();
+        assert_eq!(version.build_metadata(), None);
+    }
+
+    #[test]
+    fn epoch() {
+        let version = Version::from_scheme("1!2.0", Scheme::Pep440).unwrap();
+        assert_eq!(version.epoch(), Some(1));
+
+        let version = Version::from_scheme("2.0", Scheme::Pep440).unwrap();
+        assert_eq!(version.epoch(), Some(0));
+
+        let version = Version::from_scheme("2:1.0-1", Scheme::Debian).unwrap();
+        assert_eq!(version.epoch(), Some(2));
+
+        let version = Version::from_scheme("3:1.0-1.fc39", Scheme::Rpm).unwrap();
+        assert_eq!(version.epoch(), Some(3));
+
+        let version = Version::from_scheme("2.0.0", Scheme::Semver).unwrap();
+        assert_eq!(version.epoch(), None);
+    }
+
+    #[test]
+    fn revision() {
+        let version = Version::from_scheme("1.0-rc1-0ubuntu1", Scheme::Debian).unwrap();
+        assert_eq!(version.revision(), Some("0ubuntu1"));
+
+        let version = Version::from_scheme("1.0", Scheme::Debian).unwrap();
+        assert_eq!(version.revision(), None);
+
+        let version = Version::from_scheme("1.0-1.fc39", Scheme::Rpm).unwrap();
+        assert_eq!(version.revision(), Some("1.fc39"));
+
+        let version = Version::from("1.0-1").unwrap();
+        assert_eq!(version.revision(), None);
+    }
+
+    #[test]
+    fn prefix() {
+        let version = Version::from("MyApp 3.2.0 / build 0932").unwrap();
+        assert_eq!(version.prefix(), Some("MyApp "));
+        assert_eq!(version.parts().len(), 3);
+
+        assert_eq!(
+            Version::from("release-1.2").unwrap().prefix(),
+            Some("release-")
+        );
+        assert_eq!(
+            Version::from("Version_1.2").unwrap().prefix(),
+            Some("Version_")
+        );
+        assert_eq!(Version::from("V1.2").unwrap().prefix(), Some("V"));
+        assert_eq!(
+            Version::from("git version v2.39").unwrap().prefix(),
+            Some("git version v")
+        );
+        assert_eq!(Version::from("1.2").unwrap().prefix(), None);
 
-        // Iterate over the iterator, without consuming it
-        loop {
-            match iter.next() {
-                Some(part) => {
-                    // Get the part for the other version
-                    other_part = other_iter.next();
-
-                    // If there are no parts left in the other version, try to determine the result
-                    if other_part.is_none() {
-                        // In the main version: if the current part is zero, continue to the next one
-                        match part {
-                            &VersionPart::Number(num) => {
-                                if num == 0 {
-                                    continue;
-                                }
-                            }
-                            &VersionPart::Text(_) => return CompOp::Lt,
-                        }
+        // Unknown words directly before the version aren't a prefix
+        let version = Version::from("snapshot-1.2").unwrap();
+        assert_eq!(version.prefix(), None);
+        assert_eq!(version.parts()[0], VersionPart::Text("snapshot".into()));
 
-                        // The main version is greater
-                        return CompOp::Gt;
-                    }
+        // Words without letters before the version aren't a prefix
+        let version = Version::from(" .   -32 . 1").unwrap();
+        assert_eq!(version.prefix(), None);
+        assert_eq!(
+            version.parts(),
+            &[VersionPart::Number(32), VersionPart::Number(1)]
+        );
 
-                    // Match both part as numbers to destruct their numerical values
-                    match part {
-                        &VersionPart::Number(num) => match other_part.unwrap() {
-                            &VersionPart::Number(other_num) => {
-                                // Compare the numbers
-                                match num {
-                                    n if n < other_num => return CompOp::Lt,
-                                    n if n > other_num => return CompOp::Gt,
-                                    _ => continue,
-                                }
-                            }
-                            _ => {}
-                        },
-                        _ => {}
-                    }
-                }
-                None => break,
+        // Prefixes are only recognized by the generic scheme
+        let version = Version::from_scheme("v1.2", Scheme::Pep440).unwrap();
+        assert_eq!(version.prefix(), None);
+
+        // Offsets are relative to the version string
+        let manifest = VersionManifest::new().with_other_chars(CharPolicy::Error);
+        assert_eq!(
+            Version::from_manifest("MyApp 1.2\u{2122}", &manifest).unwrap_err(),
+            Error::InvalidChar {
+                offset: 9,
+                ch: '\u{2122}'
             }
-        }
+        );
+    }
 
-        // Check whether we should iterate over the other iterator, if it has any items left
-        match other_iter.peek() {
-            // Compare based on the other iterator
-            Some(_) => Self::compare_iter(other_iter, iter).as_flipped(),
+    #[test]
+    fn suffix() {
+        let version = Version::from("MyApp 3.2.0 / build 0932").unwrap();
+        assert_eq!(version.suffix(), Some(" / build 0932"));
 
-            // Nothing more to iterate over, the versions should be equal
-            None => CompOp::Eq,
-        }
+        assert_eq!(
+            Version::from("1.2 (stable)").unwrap().suffix(),
+            Some(" (stable)")
+        );
+        assert_eq!(Version::from("1.2-rc1").unwrap().suffix(), None);
+        assert_eq!(Version::from("1.2").unwrap().suffix(), None);
+
+        // Other words after the version are part of the version core
+        let version = Version::from("Version 1.2 rc1").unwrap();
+        assert_eq!(version.suffix(), None);
+        assert_eq!(version.parts()[2], VersionPart::Text("rc1".into()));
+        assert_eq!(Version::from("1 2 3").unwrap().parts().len(), 3);
+
+        // The suffix is only compared if the version cores are equal
+        assert!(Version::from("1.2 (beta)").unwrap() < Version::from("1.2 (rc)").unwrap());
+        assert!(Version::from("1.2 (rc)").unwrap() < Version::from("1.2.1 (beta)").unwrap());
+
+        // Build numbers in the suffix may be larger than a number part
+        let version = Version::from("1.2 / build 99999999999999999999").unwrap();
+        assert_eq!(version.suffix(), Some(" / build 99999999999999999999"));
+        assert!(version < Version::from("1.2 / build 100000000000000000000").unwrap());
     }
-}
 
-impl<'a> fmt::Display for Version<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.version)
+    #[test]
+    fn compare_scheme() {
+        // Semver versions are compared with semver precedence
+        let version_a = Version::from_scheme("1.0.0-beta.11", Scheme::Semver).unwrap();
+        let version_b = Version::from_scheme("1.0.0-rc.1", Scheme::Semver).unwrap();
+        assert_eq!(version_a.compare(&version_b), CompOp::Lt);
+
+        // Maven versions are compared like Maven, using the manifest
+        let mut manifest = VersionManifest::new();
+        manifest.set_scheme(Scheme::Maven);
+        let version_a = Version::from_manifest("1.0-SNAPSHOT", &manifest).unwrap();
+        let version_b = Version::from_manifest("1.0.0.Final", &manifest).unwrap();
+        let version_c = Version::from_manifest("1.0-sp1", &manifest).unwrap();
+        assert_eq!(version_a.compare(&version_b), CompOp::Lt);
+        assert_eq!(version_b.compare(&version_c), CompOp::Lt);
+        assert_eq!(version_b, Version::from_manifest("1", &manifest).unwrap());
+        assert_eq!(
+            version_a.parts(),
+            &[VersionPart::Number(1), VersionPart::Text("snapshot".into())]
+        );
+
+        // Calendar versions are compared by date, even if their formats differ
+        let short = Scheme::Calver(CalverFormat::from("YY.0M").unwrap());
+        let full = Scheme::Calver(CalverFormat::from("YYYY.0M.MICRO").unwrap());
+        let version_a = Version::from_scheme("24.04", short.clone()).unwrap();
+        let version_b = Version::from_scheme("2024.04", full.clone()).unwrap();
+        let version_c = Version::from_scheme("2024.04.1", full).unwrap();
+        assert_eq!(version_a.compare(&version_b), CompOp::Eq);
+        assert_eq!(version_a.compare(&version_c), CompOp::Lt);
+        assert_eq!(
+            version_a.parts(),
+            &[VersionPart::Number(2024), VersionPart::Number(4)]
+        );
+        assert!(Version::from_scheme("24.13", short).is_err());
+
+        // Versions with different schemes are compared using the generic rules
+        let version_a = Version::from_scheme("1.0.0+build", Scheme::Semver).unwrap();
+        let version_b = Version::from("1.0.0").unwrap();
+        assert_eq!(version_a.compare(&version_b), CompOp::Eq);
     }
-}
 
-// Show just the version component parts as debug output
-impl<'a> fmt::Debug for Version<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{:#?}", self.parts)
-        } else {
-            write!(f, "{:?}", self.parts)
-        }
+    #[test]
+    fn separators() {
+        let mut manifest = VersionManifest::new();
+        manifest.set_separators(Some(&['.', '-']));
+
+        // Other characters are ignored by default, or may be invalid
+        assert_eq!(
+            Version::from_manifest("1.0_1", &manifest).unwrap().parts(),
+            &[VersionPart::Number(1), VersionPart::Number(1)]
+        );
+        manifest.set_other_chars(CharPolicy::Error);
+        assert_eq!(
+            Version::from_manifest("1.0_1", &manifest).unwrap_err(),
+            Error::InvalidChar { offset: 3, ch: '_' }
+        );
+        assert_eq!(
+            Version::from_manifest("1.0-1", &manifest).unwrap().parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Number(0),
+                VersionPart::Number(1)
+            ]
+        );
+
+        // Other characters may be part of the text of a part
+        manifest.set_other_chars(CharPolicy::Literal);
+        assert_eq!(
+            Version::from_manifest("1.0_1-rc+2", &manifest)
+                .unwrap()
+                .parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Text("0_1".into()),
+                VersionPart::Text("rc+2".into())
+            ]
+        );
+
+        // Other characters may be skipped
+        manifest.set_other_chars(CharPolicy::Ignore);
+        assert_eq!(
+            Version::from_manifest("1.0_1-rc+2.+", &manifest)
+                .unwrap()
+                .parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Number(1),
+                VersionPart::Text("rc2".into())
+            ]
+        );
+        assert_eq!(
+            Version::from_manifest("1.99999999999999999999", &manifest)
+                .unwrap()
+                .parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Text("99999999999999999999".into())
+            ]
+        );
     }
-}
 
-/// Implement the partial ordering trait for the version struct, to easily allow version comparison.
-impl<'a> PartialOrd for Version<'a> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.compare(other).ord().unwrap())
+    #[test]
+    fn min_depth() {
+        let manifest = VersionManifest::new().with_min_depth(Some(3));
+
+        assert_eq!(
+            Version::from_manifest("1", &manifest).unwrap().parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Number(0),
+                VersionPart::Number(0)
+            ]
+        );
+        assert_eq!(
+            Version::from_manifest("1.2.3.4", &manifest)
+                .unwrap()
+                .part_count(),
+            4
+        );
+        assert_eq!(
+            Version::from_manifest("", &manifest).unwrap().part_count(),
+            3
+        );
+        assert_eq!(
+            Version::from_manifest("1", &manifest).unwrap(),
+            Version::from("1.0.0").unwrap()
+        );
+
+        // The maximum depth takes precedence
+        let manifest = manifest.with_max_depth(Some(2));
+        assert_eq!(
+            Version::from_manifest("1", &manifest).unwrap().part_count(),
+            2
+        );
     }
-}
 
-/// Implement the partial equality trait for the version struct, to easily allow version comparison.
-impl<'a> PartialEq for Version<'a> {
-    fn eq(&self, other: &Self) -> bool {
-        self.compare_to(other, &CompOp::Eq)
+    #[test]
+    fn case_sensitive() {
+        // Get the hash of a version
+        fn hash(version: &Version) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let manifest = VersionManifest::new().with_case_sensitive(true);
+        let version = |version| Version::from_manifest(version, &manifest).unwrap();
+
+        // Text parts are compared case-insensitively by default
+        assert_eq!(
+            Version::from("1.0-RC1").unwrap(),
+            Version::from("1.0-rc1").unwrap()
+        );
+
+        // Text parts that only differ in case are ordered by their characters
+        assert_eq!(version("1.0-RC1").compare(&version("1.0-rc1")), CompOp::Lt);
+        assert_eq!(version("1.0-rc1").compare(&version("1.0-RC1")), CompOp::Gt);
+        assert_eq!(version("1.0-rc1").compare(&version("1.0-rc1")), CompOp::Eq);
+        assert_eq!(version("1.0-RC1").compare(&version("1.0-rc2")), CompOp::Lt);
+        assert_eq!(version("1.0-beta").compare(&version("1.0-RC")), CompOp::Lt);
+        assert_eq!(version("1.0-RC.1").compare(&version("1.0-rc")), CompOp::Lt);
+
+        // Text parts are only compared case-sensitively if enabled for both versions
+        assert_eq!(
+            version("1.0-RC1").compare(&Version::from("1.0-rc1").unwrap()),
+            CompOp::Eq
+        );
+        assert_eq!(
+            hash(&version("1.0-RC1")),
+            hash(&Version::from("1.0-rc1").unwrap())
+        );
+
+        // Equality and ordering ignore the option, to stay transitive
+        assert_eq!(version("1.0-RC1"), version("1.0-rc1"));
+        assert_eq!(version("1.0-RC1").cmp(&version("1.0-rc1")), Ordering::Equal);
     }
-}
 
-#[cfg_attr(tarpaulin, skip)]
-#[cfg(test)]
-mod tests {
-    use std::cmp;
+    #[test]
+    fn v_prefix() {
+        let manifest = VersionManifest::new();
 
-    use crate::comp_op::CompOp;
-    use crate::test::test_version::{TEST_VERSIONS, TEST_VERSIONS_ERROR};
-    use crate::test::test_version_set::TEST_VERSION_SETS;
-    use crate::version_manifest::VersionManifest;
-    use crate::version_part::VersionPart;
+        assert_eq!(
+            Version::from_manifest("v1.2.3", &manifest).unwrap().parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Number(2),
+                VersionPart::Number(3)
+            ]
+        );
+        assert_eq!(
+            Version::from_manifest("V1.2", &manifest).unwrap().parts(),
+            &[VersionPart::Number(1), VersionPart::Number(2)]
+        );
 
-    use super::Version;
+        // The prefix must be directly followed by a digit
+        assert_eq!(
+            Version::from_manifest("v.1", &manifest).unwrap().parts(),
+            &[VersionPart::Text("v".into()), VersionPart::Number(1)]
+        );
+        assert_eq!(
+            Version::from_manifest("vx1", &manifest).unwrap_err(),
+            Error::NoNumber
+        );
+
+        // The prefix is part of the first text part if not allowed
+        let manifest_no_prefix = VersionManifest::new().with_v_prefix(false);
+        assert_eq!(
+            Version::from_manifest("v1.2", &manifest_no_prefix)
+                .unwrap()
+                .parts(),
+            &[VersionPart::Text("v1".into()), VersionPart::Number(2)]
+        );
+        assert_eq!(
+            Version::from("v1.2").unwrap().parts(),
+            &[VersionPart::Number(1), VersionPart::Number(2)]
+        );
+
+        // Offsets are relative to the version string
+        let manifest = manifest.with_other_chars(CharPolicy::Error);
+        assert_eq!(
+            Version::from_manifest("v1.2\u{2122}", &manifest).unwrap_err(),
+            Error::InvalidChar {
+                offset: 4,
+                ch: '\u{2122}'
+            }
+        );
+    }
 
     #[test]
-    // TODO: This doesn't really test whether this method fully works
-    fn from() {
-        // Test whether parsing works for each test version
-        for version in TEST_VERSIONS {
-            assert!(Version::from(&version.0).is_some());
+    fn significant_trailing_zeros() {
+        // Get the hash of a version
+        fn hash(version: &Version) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
         }
 
-        // Test whether parsing works for each test invalid version
-        for version in TEST_VERSIONS_ERROR {
-            assert!(Version::from(&version.0).is_none());
+        let manifest = VersionManifest::new().with_significant_trailing_zeros(true);
+        let version = |version| Version::from_manifest(version, &manifest).unwrap();
+
+        // A version with more parts is greater
+        assert_eq!(version("1.0").compare(&version("1.0.0")), CompOp::Lt);
+        assert_eq!(version("1.0.0").compare(&version("1.0")), CompOp::Gt);
+        assert_eq!(version("1").compare(&version("1.0")), CompOp::Lt);
+        assert_eq!(version("1.0.0").compare(&version("1.0.1")), CompOp::Lt);
+        assert_eq!(version("1.0.0").compare(&version("1.1")), CompOp::Lt);
+        assert_eq!(version("1.0-rc1").compare(&version("1.0")), CompOp::Lt);
+        assert_eq!(version("1.0.0-rc1").compare(&version("1.0")), CompOp::Gt);
+        assert_eq!(version("1.0.0").compare(&version("1.0.0")), CompOp::Eq);
+
+        // Equality, ordering and hashing ignore the option, to stay transitive
+        assert_eq!(version("1.0"), version("1.0.0"));
+        assert_eq!(version("1.0").cmp(&version("1.0.0")), Ordering::Equal);
+        assert_eq!(hash(&version("1.0")), hash(&version("1.0.0")));
+        let set: HashSet<_> = ["1", "1.0", "1.0.0", "1.0.0"]
+            .iter()
+            .map(|v| version(v))
+            .collect();
+        assert_eq!(set.len(), 1);
+
+        // Sorting with the option orders by depth
+        let mut versions = [version("1.0.0"), version("1"), version("1.0")];
+        versions.sort_by(|a, b| a.compare(b).ord().unwrap());
+        let sorted: Vec<_> = versions.iter().map(Version::as_str).collect();
+        assert_eq!(sorted, ["1", "1.0", "1.0.0"]);
+
+        // Padding to a minimum depth makes versions equal again
+        let padded = manifest.clone().with_min_depth(Some(3));
+        let padded = |version| Version::from_manifest(version, &padded).unwrap();
+        assert_eq!(padded("1").compare(&padded("1.0.0")), CompOp::Eq);
+
+        // Trailing zeros are significant for separators too
+        let separators = manifest
+            .clone()
+            .with_separators(Some(&['-', '.']))
+            .with_significant_separators(true);
+        let separators = |version| Version::from_manifest(version, &separators).unwrap();
+        assert_eq!(separators("1-0").compare(&separators("1.0")), CompOp::Lt);
+
+        // Trailing zeros are only significant if they are for both versions
+        assert_eq!(
+            version("1.0").compare(&Version::from("1").unwrap()),
+            CompOp::Eq
+        );
+        for (a, b) in &[("1.0", "1"), ("1", "1.0.0"), ("1.0.0", "1.0")] {
+            assert_eq!(version(a).compare(&Version::from(b).unwrap()), CompOp::Eq);
         }
     }
 
     #[test]
-    // TODO: This doesn't really test whether this method fully works
-    fn from_manifest() {
-        // Create a manifest
-        let manifest = VersionManifest::new();
-
-        // Test whether parsing works for each test version
-        for version in TEST_VERSIONS {
-            assert_eq!(
-                Version::from_manifest(&version.0, &manifest)
-                    .unwrap()
-                    .manifest,
-                Some(&manifest)
-            );
+    fn significant_separators() {
+        // Get the hash of a version
+        fn hash(version: &Version) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
         }
 
-        // Test whether parsing works for each test invalid version
-        for version in TEST_VERSIONS_ERROR {
-            assert!(Version::from_manifest(&version.0, &manifest).is_none());
+        let mut manifest = VersionManifest::new();
+        manifest.set_separators(Some(&['-', '_', '.']));
+        let version = |version| Version::from_manifest(version, &manifest).unwrap();
+
+        // Separators are ignored when comparing by default
+        assert_eq!(version("1.0-1"), version("1.0.1"));
+
+        // Separators are ranked in the order they're configured in
+        let mut significant = manifest.clone();
+        significant.set_significant_separators(true);
+        let significant = |version| Version::from_manifest(version, &significant).unwrap();
+        assert_eq!(
+            significant("1.0-1").compare(&significant("1.0.1")),
+            CompOp::Lt
+        );
+        assert_eq!(
+            significant("1.0_1").compare(&significant("1.0-2")),
+            CompOp::Gt
+        );
+        assert_eq!(
+            significant("1.0-2").compare(&significant("1.0.1")),
+            CompOp::Lt
+        );
+        assert_eq!(
+            significant("1.1-2").compare(&significant("1.0.1")),
+            CompOp::Gt
+        );
+        assert_eq!(
+            significant("1-0").compare(&significant("1.0.0")),
+            CompOp::Eq
+        );
+        assert_eq!(
+            significant("1-0.1").compare(&significant("1.0.1")),
+            CompOp::Lt
+        );
+        assert_eq!(
+            significant("1.0").compare(&significant("1.0-0")),
+            CompOp::Eq
+        );
+        assert_eq!(hash(&significant("1.0")), hash(&significant("1.0-0")));
+
+        // Separators are only significant if they are for both versions, and equality and
+        // hashing ignore them
+        assert_eq!(significant("1.0-1").compare(&version("1.0.1")), CompOp::Eq);
+        assert_eq!(significant("1.0-1"), significant("1.0.1"));
+        for (a, b) in &[
+            ("1.0-1", "1.0.1"),
+            ("1_0-1", "1.0.1"),
+            ("1-0", "1"),
+            ("1.0", "1_0_0"),
+        ] {
+            assert_eq!(significant(a), version(b));
+            assert_eq!(hash(&significant(a)), hash(&version(b)));
         }
+
+        // Without configured separators, separators are ranked by their character
+        let mut default = VersionManifest::new();
+        default.set_significant_separators(true);
+        let default = |version| Version::from_manifest(version, &default).unwrap();
+        assert_eq!(default("1.0-1").compare(&default("1.0.1")), CompOp::Lt);
+        assert_eq!(default("1.0+1").compare(&default("1.0.1")), CompOp::Lt);
     }
 
     #[test]
-    fn manifest() {
+    fn set_manifest() {
         let manifest = VersionManifest::new();
         let mut version = Version::from("1.2.3").unwrap();
 
-        version.manifest = Some(&manifest);
+        version.set_manifest(Some(&manifest)).unwrap();
         assert_eq!(version.manifest(), Some(&manifest));
 
-        version.manifest = None;
+        version.set_manifest(None).unwrap();
         assert_eq!(version.manifest(), None);
-    }
 
-    #[test]
-    fn has_manifest() {
-        let manifest = VersionManifest::new();
-        let mut version = Version::from("1.2.3").unwrap();
+        // The version string is parsed again with the new manifest
+        let mut depth = VersionManifest::new();
+        depth.set_max_depth_number(2);
+        version.set_manifest(Some(&depth)).unwrap();
+        assert_eq!(
+            version.parts(),
+            &[VersionPart::Number(1), VersionPart::Number(2)]
+        );
+        assert_eq!(version, Version::from("1.2").unwrap());
 
-        version.manifest = Some(&manifest);
-        assert!(version.has_manifest());
+        let mut semver = VersionManifest::new();
+        semver.set_scheme(Scheme::Semver);
+        version.set_manifest(Some(&semver)).unwrap();
+        assert_eq!(version.part_count(), 3);
 
-        version.manifest = None;
-        assert!(!version.has_manifest());
+        // The version is unchanged if it's invalid with the new manifest
+        let mut version = Version::from_string("1.2-rc.1".into()).unwrap();
+        assert_eq!(
+            version.set_manifest(Some(&semver)).unwrap_err(),
+            Error::InvalidFormat {
+                scheme: Scheme::Semver,
+                offset: 3
+            }
+        );
+        assert_eq!(version.manifest(), None);
+        assert_eq!(version.part_count(), 4);
+
+        // Owned versions are parsed again too
+        version.set_manifest(Some(&depth)).unwrap();
+        assert_eq!(version.part_count(), 2);
     }
 
     #[test]
-    fn set_manifest() {
-        let manifest = VersionManifest::new();
-        let mut version = Version::from("1.2.3").unwrap();
+    fn with_manifest() {
+        let mut manifest = VersionManifest::new();
+        manifest.set_ignore_text(true);
 
-        version.set_manifest(Some(&manifest));
-        assert_eq!(version.manifest, Some(&manifest));
+        let version = Version::from("1.2-rc.3").unwrap();
+        assert_eq!(version.part_count(), 4);
+
+        let version = version.with_manifest(&manifest).unwrap();
+        assert_eq!(version.manifest(), Some(&manifest));
+        assert_eq!(
+            version.parts(),
+            &[
+                VersionPart::Number(1),
+                VersionPart::Number(2),
+                VersionPart::Number(3)
+            ]
+        );
 
-        version.set_manifest(None);
-        assert_eq!(version.manifest, None);
+        let mut semver = VersionManifest::new();
+        semver.set_scheme(Scheme::Semver);
+        assert!(version.with_manifest(&semver).is_err());
     }
 
     #[test]
@@ -556,7 +2074,7 @@ mod tests {
         // Test for each test version
         for version in TEST_VERSIONS {
             // The input version string must be the same as the returned string
-            assert_eq!(Version::from(&version.0).unwrap().as_str(), version.0);
+            assert_eq!(Version::from(version.0).unwrap().as_str(), version.0);
         }
     }
 
@@ -565,7 +2083,7 @@ mod tests {
         // Test for each test version
         for version in TEST_VERSIONS {
             // Create a version object
-            let ver = Version::from(&version.0).unwrap();
+            let ver = Version::from(version.0).unwrap();
 
             // Loop through each part
             for i in 0..version.1 {
@@ -582,10 +2100,19 @@ mod tests {
         // Test for each test version
         for version in TEST_VERSIONS {
             // The number of parts must match
-            assert_eq!(Version::from(&version.0).unwrap().parts().len(), version.1);
+            assert_eq!(Version::from(version.0).unwrap().parts().len(), version.1);
         }
     }
 
+    #[test]
+    fn parts_large_number() {
+        let ver = Version::from("2023.1015.2023101512345").unwrap();
+        assert_eq!(ver.part(2), Ok(&VersionPart::Number(2_023_101_512_345)));
+
+        let ver = Version::from("18446744073709551615").unwrap();
+        assert_eq!(ver.part(0), Ok(&VersionPart::Number(u64::MAX)));
+    }
+
     #[test]
     fn parts_max_depth() {
         // Create a manifest
@@ -599,12 +2126,12 @@ mod tests {
             // Test for each test version with the manifest
             for version in TEST_VERSIONS {
                 // Create a version object, and count it's parts
-                let ver = Version::from_manifest(&version.0, &manifest);
+                let ver = Version::from_manifest(version.0, &manifest);
 
-                // Some versions might be none, because not all of the start with a number when the
-                // maximum depth is 1. A version string with only text isn't allowed,
-                // resulting in none.
-                if ver.is_none() {
+                // Some versions might fail to parse, because not all of the start with a number
+                // when the maximum depth is 1. A version string with only text isn't allowed,
+                // resulting in an error.
+                if ver.is_err() {
                     continue;
                 }
 
@@ -627,7 +2154,7 @@ mod tests {
         let mut manifest = VersionManifest::new();
 
         // Try this for true and false
-        for ignore in vec![true, false] {
+        for ignore in [true, false] {
             // Set to ignore text
             manifest.set_ignore_text(ignore);
 
@@ -637,21 +2164,18 @@ mod tests {
             // Test each test version
             for version in TEST_VERSIONS {
                 // Create a version instance, and get it's parts
-                let ver = Version::from_manifest(&version.0, &manifest).unwrap();
+                let ver = Version::from_manifest(version.0, &manifest).unwrap();
 
                 // Loop through all version parts
                 for part in ver.parts() {
-                    match part {
-                        &VersionPart::Text(_) => {
-                            // Set the flag
-                            had_text = true;
-
-                            // Break the loop if we already reached text when not ignored
-                            if !ignore {
-                                break;
-                            }
+                    if let &VersionPart::Text(_) = part {
+                        // Set the flag
+                        had_text = true;
+
+                        // Break the loop if we already reached text when not ignored
+                        if !ignore {
+                            break;
                         }
-                        _ => {}
                     }
                 }
             }
@@ -666,7 +2190,7 @@ mod tests {
         // Test for each test version
         for version in TEST_VERSIONS {
             // The number of parts must match the metadata
-            assert_eq!(Version::from(&version.0).unwrap().part_count(), version.1);
+            assert_eq!(Version::from(version.0).unwrap().part_count(), version.1);
         }
     }
 
@@ -675,8 +2199,8 @@ mod tests {
         // Compare each version in the version set
         for entry in TEST_VERSION_SETS {
             // Get both versions
-            let version_a = Version::from(&entry.0).unwrap();
-            let version_b = Version::from(&entry.1).unwrap();
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
 
             // Compare them
             assert_eq!(
@@ -695,14 +2219,14 @@ mod tests {
         // Compare each version in the version set
         for entry in TEST_VERSION_SETS {
             // Get both versions
-            let version_a = Version::from(&entry.0).unwrap();
-            let version_b = Version::from(&entry.1).unwrap();
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
 
             // Test
             assert!(version_a.compare_to(&version_b, &entry.2));
 
             // Make sure the inverse operator is not correct
-            assert_eq!(version_a.compare_to(&version_b, &entry.2.invert()), false);
+            assert!(!version_a.compare_to(&version_b, &entry.2.invert()));
         }
 
         // Assert an exceptional case, compare to not equal
@@ -714,6 +2238,12 @@ mod tests {
     #[test]
     fn display() {
         assert_eq!(format!("{}", Version::from("1.2.3").unwrap()), "1.2.3");
+        assert_eq!(format!("{}", Version::from("1.0-RC1").unwrap()), "1.0-RC1");
+
+        // PEP 440 versions are normalized
+        let version = Version::from_scheme("v1.0-RC1", Scheme::Pep440).unwrap();
+        assert_eq!(format!("{}", version), "1.0rc1");
+        assert_eq!(version.as_str(), "v1.0-RC1");
     }
 
     #[test]
@@ -733,8 +2263,8 @@ mod tests {
         // Compare each version in the version set
         for entry in TEST_VERSION_SETS {
             // Get both versions
-            let version_a = Version::from(&entry.0).unwrap();
-            let version_b = Version::from(&entry.1).unwrap();
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
 
             // Compare and assert
             match entry.2 {
@@ -757,14 +2287,11 @@ mod tests {
             }
 
             // Get both versions
-            let version_a = Version::from(&entry.0).unwrap();
-            let version_b = Version::from(&entry.1).unwrap();
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
 
             // Determine what the result should be
-            let result = match entry.2 {
-                CompOp::Eq => true,
-                _ => false,
-            };
+            let result = matches!(entry.2, CompOp::Eq);
 
             // Test
             assert_eq!(version_a == version_b, result);
@@ -773,4 +2300,240 @@ mod tests {
         // Assert an exceptional case, compare to not equal
         assert!(Version::from("1.2").unwrap() != Version::from("1.2.3").unwrap());
     }
+
+    #[test]
+    fn cmp() {
+        // Compare each version in the version set
+        for entry in TEST_VERSION_SETS {
+            // Get both versions
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
+
+            // Compare and assert
+            assert_eq!(
+                version_a.cmp(&version_b),
+                entry.2.ord().unwrap(),
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1
+            );
+        }
+
+        // Sort, and find the maximum version
+        let mut versions: Vec<Version> = ["1.2", "1.10", "1.2-rc1", "0.9", "1.2.1"]
+            .iter()
+            .map(|version| Version::from(version).unwrap())
+            .collect();
+        versions.sort();
+        let sorted: Vec<&str> = versions.iter().map(|version| version.as_str()).collect();
+        assert_eq!(sorted, vec!["0.9", "1.2-rc1", "1.2", "1.2.1", "1.10"]);
+        assert_eq!(versions.iter().max().unwrap().as_str(), "1.10");
+    }
+
+    #[test]
+    fn cmp_total() {
+        // Mix versions with different schemes and manifests
+        let trailing_zeros = VersionManifest::new().with_significant_trailing_zeros(true);
+        let case_sensitive = VersionManifest::new().with_case_sensitive(true);
+        let year_month = Scheme::Calver(CalverFormat::from("YY.0M").unwrap());
+        let full_year_month = Scheme::Calver(CalverFormat::from("YYYY.0M").unwrap());
+        let versions = [
+            Version::from("1").unwrap(),
+            Version::from("1.0.0").unwrap(),
+            Version::from("1.0.0-alpha.1").unwrap(),
+            Version::from("1.0.0-alpha.beta").unwrap(),
+            Version::from("1.0.0-RC1").unwrap(),
+            Version::from("24.04").unwrap(),
+            Version::from_manifest("1.0", &trailing_zeros).unwrap(),
+            Version::from_manifest("1.0.0", &trailing_zeros).unwrap(),
+            Version::from_manifest("1.0.0-rc1", &case_sensitive).unwrap(),
+            Version::from_manifest("1.0.0-RC1", &case_sensitive).unwrap(),
+            Version::from_scheme("1.0.0-alpha.1", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0.0-alpha.beta", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0.0", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0a1", Scheme::Pep440).unwrap(),
+            Version::from_scheme("1.0", Scheme::Pep440).unwrap(),
+            Version::from_scheme("1.0~rc1", Scheme::Debian).unwrap(),
+            Version::from_scheme("1.0", Scheme::Rpm).unwrap(),
+            Version::from_scheme("1.0-alpha-1", Scheme::Maven).unwrap(),
+            Version::from_scheme("24.04", year_month).unwrap(),
+            Version::from_scheme("2024.04", full_year_month).unwrap(),
+        ];
+
+        // Equality and ordering are transitive for each triple of versions
+        for a in &versions {
+            for b in &versions {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "Testing {} and {}", a, b);
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+                for c in &versions {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "Testing that {} <= {} <= {}", a, b, c);
+                    }
+                    if a == b && b == c {
+                        assert!(a == c, "Testing that {} == {} == {}", a, b, c);
+                    }
+                }
+            }
+        }
+
+        // Versions using different schemes are ordered by their scheme
+        assert!(versions[2] < versions[11]);
+        assert_eq!(versions[2].compare(&versions[11]), CompOp::Gt);
+        assert!(versions[10] < versions[11]);
+        assert_ne!(versions[2], versions[10]);
+        assert_eq!(versions[2].compare(&versions[10]), CompOp::Eq);
+        assert_eq!(versions[18], versions[19]);
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version
+        fn hash(version: &Version) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Equal versions must have equal hashes
+        for entry in TEST_VERSION_SETS {
+            let version_a = Version::from(entry.0).unwrap();
+            let version_b = Version::from(entry.1).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(
+                    hash(&version_a),
+                    hash(&version_b),
+                    "Testing that {} and {} have an equal hash",
+                    entry.0,
+                    entry.1
+                );
+            }
+        }
+
+        // Equal semver versions must have equal hashes, build metadata is ignored
+        for entry in TEST_SEMVER_SETS {
+            let version_a = Version::from_scheme(entry.0, Scheme::Semver).unwrap();
+            let version_b = Version::from_scheme(entry.1, Scheme::Semver).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(hash(&version_a), hash(&version_b));
+            }
+        }
+
+        // Equal Debian versions must have equal hashes
+        for entry in TEST_DEBIAN_SETS {
+            let version_a = Version::from_scheme(entry.0, Scheme::Debian).unwrap();
+            let version_b = Version::from_scheme(entry.1, Scheme::Debian).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(hash(&version_a), hash(&version_b));
+            }
+        }
+
+        // Equal RPM versions must have equal hashes
+        for entry in TEST_RPM_SETS {
+            let version_a = Version::from_scheme(entry.0, Scheme::Rpm).unwrap();
+            let version_b = Version::from_scheme(entry.1, Scheme::Rpm).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(hash(&version_a), hash(&version_b));
+            }
+        }
+
+        // Equal Maven versions must have equal hashes
+        for entry in TEST_MAVEN_SETS {
+            let version_a = Version::from_scheme(entry.0, Scheme::Maven).unwrap();
+            let version_b = Version::from_scheme(entry.1, Scheme::Maven).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(hash(&version_a), hash(&version_b));
+            }
+        }
+
+        // Equal calendar versions must have equal hashes, even if their formats differ
+        for entry in TEST_CALVER_SETS {
+            let format_a = CalverFormat::from(entry.0).unwrap();
+            let format_b = CalverFormat::from(entry.2).unwrap();
+            let version_a = Version::from_scheme(entry.1, Scheme::Calver(format_a)).unwrap();
+            let version_b = Version::from_scheme(entry.3, Scheme::Calver(format_b)).unwrap();
+
+            if version_a == version_b {
+                assert_eq!(hash(&version_a), hash(&version_b));
+            }
+        }
+
+        // Equal PEP 440 versions must have equal hashes
+        for (version, normalized) in TEST_PEP440_NORMALIZED {
+            let version_a = Version::from_scheme(version, Scheme::Pep440).unwrap();
+            let version_b = Version::from_scheme(normalized, Scheme::Pep440).unwrap();
+
+            assert_eq!(version_a, version_b);
+            assert_eq!(hash(&version_a), hash(&version_b));
+        }
+
+        // Versions with compatible schemes are compared following different rules, equal
+        // versions must still have equal hashes
+        let year_month = Scheme::Calver(CalverFormat::from("YYYY.0M").unwrap());
+        let year_month_micro = Scheme::Calver(CalverFormat::from("YYYY.0M.MICRO").unwrap());
+        let versions = [
+            Version::from("1").unwrap(),
+            Version::from("1.0.0").unwrap(),
+            Version::from("1.0.0-rc.1").unwrap(),
+            Version::from("1.0a1").unwrap(),
+            Version::from("2024.04").unwrap(),
+            Version::from_scheme("1.0.0", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0.0+build", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0.0-rc.1", Scheme::Semver).unwrap(),
+            Version::from_scheme("1.0", Scheme::Pep440).unwrap(),
+            Version::from_scheme("1.0a1", Scheme::Pep440).unwrap(),
+            Version::from_scheme("1.0.0a1", Scheme::Pep440).unwrap(),
+            Version::from_scheme("0!1.0.0", Scheme::Pep440).unwrap(),
+            Version::from_scheme("1.0", Scheme::Debian).unwrap(),
+            Version::from_scheme("0:1.0-0", Scheme::Debian).unwrap(),
+            Version::from_scheme("1.0", Scheme::Rpm).unwrap(),
+            Version::from_scheme("1.0.0.Final", Scheme::Maven).unwrap(),
+            Version::from_scheme("1", Scheme::Maven).unwrap(),
+            Version::from_scheme("2024.04", year_month).unwrap(),
+            Version::from_scheme("2024.04.0", year_month_micro).unwrap(),
+        ];
+        assert_eq!(versions[6], versions[5]);
+        assert_ne!(versions[5], versions[1]);
+        assert_eq!(versions[9], versions[10]);
+        assert_eq!(versions[17], versions[18]);
+        for version_a in &versions {
+            for version_b in versions.iter().filter(|version_b| *version_b == version_a) {
+                assert_eq!(
+                    hash(version_a),
+                    hash(version_b),
+                    "Testing that {} ({}) and {} ({}) have an equal hash",
+                    version_a,
+                    version_a.scheme(),
+                    version_b,
+                    version_b.scheme()
+                );
+            }
+        }
+
+        // Use versions as keys in collections
+        let mut set = HashSet::new();
+        assert!(set.insert(Version::from("1.0.0").unwrap()));
+        assert!(!set.insert(Version::from("1").unwrap()));
+        assert!(!set.insert(Version::from("1.0-0").unwrap()));
+        assert!(!set.insert(Version::from("MyApp 1.0").unwrap()));
+        assert!(set.insert(Version::from("1.0.1").unwrap()));
+        assert!(set.insert(Version::from("1.0-rc1").unwrap()));
+        assert!(!set.insert(Version::from("1.0-RC01").unwrap()));
+
+        let mut map = BTreeMap::new();
+        map.insert(Version::from("2.0").unwrap(), "b");
+        map.insert(Version::from("1.0").unwrap(), "a");
+        map.insert(Version::from("2").unwrap(), "c");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.values().collect::<Vec<_>>(), vec![&"a", &"c"]);
+        assert_eq!(
+            map.keys().next().unwrap().cmp(&Version::from("1").unwrap()),
+            Ordering::Equal
+        );
+    }
 }
@@ -3,8 +3,21 @@
 //! This module provides the `VersionCompare` struct, which provides many static functions, that are
 //! useful for version comparison.
 
+use std::collections::BTreeMap;
+
 use crate::comp_op::CompOp;
+use crate::error::{BatchError, Error, Side};
+use crate::scheme::generic;
 use crate::version::Version;
+use crate::version_part::VersionPart;
+
+/// Result of a batch method of `VersionCompare`, along with the version strings that failed to
+/// parse.
+pub type BatchResult<'a, T> = (T, Vec<BatchError<'a>>);
+
+/// Groups of version strings, each with the key its versions share, see
+/// `VersionCompare::group_by_major`.
+pub type VersionGroups<'a, K> = Vec<(K, Vec<&'a str>)>;
 
 /// The main library structure, which provides various static methods for easy version comparison.
 ///
@@ -16,7 +29,11 @@ impl VersionCompare {
     /// This compares version `a` to version `b`, and returns whether version `a` is greater, less
     /// or equal to version `b`.
     ///
-    /// The two given version numbers must be valid, or an error will be returned.
+    /// The two given version numbers must be valid, or an error will be returned. The error tells
+    /// which side of the comparison failed to parse.
+    ///
+    /// Both version strings are compared part by part while they're parsed, without allocating,
+    /// which is faster than parsing them to `Version` instances first. The result is the same.
     ///
     /// One of the following ok results may be returned:
     ///
@@ -33,25 +50,17 @@ impl VersionCompare {
     /// assert_eq!(VersionCompare::compare("1.2.3", "1.2.3"), Ok(CompOp::Eq));
     /// assert_eq!(VersionCompare::compare("1.2.3", "1.2.4"), Ok(CompOp::Lt));
     /// assert_eq!(VersionCompare::compare("1", "0.1"), Ok(CompOp::Gt));
+    /// assert!(VersionCompare::compare("1", "abc").is_err());
     /// ```
-    pub fn compare(a: &str, b: &str) -> Result<CompOp, ()> {
-        // Create version instances
-        let a_ver = Version::from(a);
-        let b_ver = Version::from(b);
-
-        // Both version numbers must have been parsed
-        if a_ver.is_none() || b_ver.is_none() {
-            return Err(());
-        }
-
-        // Compare and return the result
-        Ok(a_ver.unwrap().compare(&b_ver.unwrap()))
+    pub fn compare(a: &str, b: &str) -> Result<CompOp, Error> {
+        generic::compare(a, b)
     }
 
     /// Compare two version number strings to each other and check whether the given comparison
     /// `operator` is valid.
     ///
-    /// The two given version numbers must be valid, or an error will be returned.
+    /// The two given version numbers must be valid, or an error will be returned. The error tells
+    /// which side of the comparison failed to parse.
     ///
     /// # Examples
     ///
@@ -65,18 +74,260 @@ impl VersionCompare {
     /// assert!(VersionCompare::compare_to("1", "0.1", &CompOp::Gt).unwrap());
     /// assert!(VersionCompare::compare_to("1", "0.1", &CompOp::Ge).unwrap());
     /// ```
-    pub fn compare_to(a: &str, b: &str, operator: &CompOp) -> Result<bool, ()> {
-        // Create version instances
-        let a_ver = Version::from(a);
-        let b_ver = Version::from(b);
+    pub fn compare_to(a: &str, b: &str, operator: &CompOp) -> Result<bool, Error> {
+        // Compare and match the result against the given operator
+        Ok(operator.holds_for(generic::compare(a, b)?))
+    }
+
+    /// Sort the given version strings in ascending order.
+    ///
+    /// Each version string is parsed once. Equal versions, such as `1.2` and `1.2.0`, keep their
+    /// original order. Version strings that fail to parse are left out, and returned as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (sorted, errors) = VersionCompare::sort(&["1.10", "1.2", "abc", "1.9"]);
+    ///
+    /// assert_eq!(sorted, ["1.2", "1.9", "1.10"]);
+    /// assert_eq!(errors[0].version, "abc");
+    /// ```
+    pub fn sort<'a>(versions: &[&'a str]) -> BatchResult<'a, Vec<&'a str>> {
+        let (mut parsed, errors) = Self::parse_all(versions);
+        parsed.sort_by(|a, b| a.1.cmp(&b.1));
+        (
+            parsed.into_iter().map(|(version, _)| version).collect(),
+            errors,
+        )
+    }
+
+    /// Find the greatest of the given version strings.
+    ///
+    /// Each version string is parsed once. The first of equal versions is returned, and `None`
+    /// if no version string could be parsed. Version strings that fail to parse are skipped, and
+    /// returned as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (max, errors) = VersionCompare::max(&["1.2", "1.10", "1.10.0", "1.9"]);
+    ///
+    /// assert_eq!(max, Some("1.10"));
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn max<'a>(versions: &[&'a str]) -> BatchResult<'a, Option<&'a str>> {
+        Self::extreme(versions, CompOp::Gt)
+    }
+
+    /// Find the least of the given version strings.
+    ///
+    /// Each version string is parsed once. The first of equal versions is returned, and `None`
+    /// if no version string could be parsed. Version strings that fail to parse are skipped, and
+    /// returned as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (min, errors) = VersionCompare::min(&["1.2", "1.10", "1.2.0", "abc"]);
+    ///
+    /// assert_eq!(min, Some("1.2"));
+    /// assert_eq!(errors[0].index, 3);
+    /// ```
+    pub fn min<'a>(versions: &[&'a str]) -> BatchResult<'a, Option<&'a str>> {
+        Self::extreme(versions, CompOp::Lt)
+    }
+
+    /// Remove equal versions from the given version strings, keeping the first of each.
+    ///
+    /// Each version string is parsed once. Versions are equal if they compare as equal, so `1.2`
+    /// and `1.2.0` are duplicates. The remaining versions keep their original order. Version
+    /// strings that fail to parse are left out, and returned as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (unique, errors) = VersionCompare::dedup(&["1.2", "1.3", "1.2.0", "v1.3"]);
+    ///
+    /// assert_eq!(unique, ["1.2", "1.3"]);
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn dedup<'a>(versions: &[&'a str]) -> BatchResult<'a, Vec<&'a str>> {
+        let (parsed, errors) = Self::parse_all(versions);
+
+        // Sort the versions, so equal versions are next to each other with the first one leading
+        let mut order: Vec<usize> = (0..parsed.len()).collect();
+        order.sort_by(|&a, &b| parsed[a].1.cmp(&parsed[b].1));
+
+        // Keep the first of each run of equal versions
+        let mut keep = vec![false; parsed.len()];
+        let mut last: Option<usize> = None;
+        for index in order {
+            if last.map_or(true, |last| parsed[last].1 != parsed[index].1) {
+                keep[index] = true;
+                last = Some(index);
+            }
+        }
+
+        let unique = parsed
+            .into_iter()
+            .zip(keep)
+            .filter(|(_, keep)| *keep)
+            .map(|((version, _), _)| version)
+            .collect();
+        (unique, errors)
+    }
+
+    /// Group the given version strings by their major version, the number of the first part.
+    ///
+    /// Each version string is parsed once. The groups are sorted by their major version, and the
+    /// versions in each group are sorted in ascending order. A missing or textual first part
+    /// counts as major version `0`. Version strings that fail to parse are left out, and returned
+    /// as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (groups, errors) = VersionCompare::group_by_major(&["2.1", "1.10", "1.2", "2.0"]);
+    ///
+    /// assert_eq!(groups, [(1, vec!["1.2", "1.10"]), (2, vec!["2.0", "2.1"])]);
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn group_by_major<'a>(versions: &[&'a str]) -> BatchResult<'a, VersionGroups<'a, u64>> {
+        Self::group_by(versions, |version| Self::number(version, 0))
+    }
+
+    /// Group the given version strings by their major and minor version, the numbers of the first
+    /// two parts.
+    ///
+    /// Each version string is parsed once. The groups are sorted by their major and minor
+    /// version, and the versions in each group are sorted in ascending order. A missing or
+    /// textual part counts as `0`, so `1` and `1.0.2` are in group `(1, 0)`. Version strings that
+    /// fail to parse are left out, and returned as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionCompare;
+    ///
+    /// let (groups, errors) = VersionCompare::group_by_minor(&["1.2.1", "1", "1.2", "1.0.2"]);
+    ///
+    /// assert_eq!(
+    ///     groups,
+    ///     [((1, 0), vec!["1", "1.0.2"]), ((1, 2), vec!["1.2", "1.2.1"])],
+    /// );
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn group_by_minor<'a>(
+        versions: &[&'a str],
+    ) -> BatchResult<'a, VersionGroups<'a, (u64, u64)>> {
+        Self::group_by(versions, |version| {
+            (Self::number(version, 0), Self::number(version, 1))
+        })
+    }
+
+    /// Keep the version strings for which the given comparison `operator` holds against `pivot`.
+    ///
+    /// This tests `version <operator> pivot` for each version. Each version string is parsed
+    /// once, and the remaining versions keep their original order. Version strings that fail to
+    /// parse are left out, and returned as errors. An error is returned if the pivot fails to
+    /// parse, as the right side of the comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, VersionCompare};
+    ///
+    /// let (newer, errors) =
+    ///     VersionCompare::filter(&["1.2", "2.0", "1.10", "0.9"], &CompOp::Ge, "1.10").unwrap();
+    ///
+    /// assert_eq!(newer, ["2.0", "1.10"]);
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn filter<'a>(
+        versions: &[&'a str],
+        operator: &CompOp,
+        pivot: &str,
+    ) -> Result<BatchResult<'a, Vec<&'a str>>, Error> {
+        let pivot = Version::from(pivot).map_err(|err| Side::Right.wrap(err))?;
+        let (parsed, errors) = Self::parse_all(versions);
+
+        let matched = parsed
+            .into_iter()
+            .filter(|(_, version)| version.compare_to(&pivot, operator))
+            .map(|(version, _)| version)
+            .collect();
+        Ok((matched, errors))
+    }
+
+    /// Parse each of the given version strings, and collect the ones that failed as errors.
+    ///
+    /// The parsed versions are returned along with their version string, in their original order.
+    fn parse_all<'a>(versions: &[&'a str]) -> BatchResult<'a, Vec<(&'a str, Version<'a>)>> {
+        let mut parsed = Vec::with_capacity(versions.len());
+        let mut errors = Vec::new();
+        for (index, &version) in versions.iter().enumerate() {
+            match Version::from(version) {
+                Ok(ver) => parsed.push((version, ver)),
+                Err(error) => errors.push(BatchError {
+                    index,
+                    version,
+                    error,
+                }),
+            }
+        }
+        (parsed, errors)
+    }
+
+    /// Find the greatest version if `op` is `CompOp::Gt`, or the least version if it's
+    /// `CompOp::Lt`, keeping the first of equal versions.
+    fn extreme<'a>(versions: &[&'a str], op: CompOp) -> BatchResult<'a, Option<&'a str>> {
+        let (parsed, errors) = Self::parse_all(versions);
+        let result = parsed
+            .into_iter()
+            .reduce(|result, next| {
+                if next.1.compare(&result.1) == op {
+                    next
+                } else {
+                    result
+                }
+            })
+            .map(|(version, _)| version);
+        (result, errors)
+    }
 
-        // Both version numbers must have been parsed
-        if a_ver.is_none() || b_ver.is_none() {
-            return Err(());
+    /// Group the given version strings by the key that `key` gives for each parsed version.
+    ///
+    /// The groups are sorted by their key, the versions in each group in ascending order.
+    fn group_by<'a, K: Ord>(
+        versions: &[&'a str],
+        key: impl Fn(&Version) -> K,
+    ) -> BatchResult<'a, VersionGroups<'a, K>> {
+        let (mut parsed, errors) = Self::parse_all(versions);
+        parsed.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let mut groups: BTreeMap<K, Vec<&'a str>> = BTreeMap::new();
+        for (version, ver) in parsed {
+            groups.entry(key(&ver)).or_default().push(version);
         }
+        (groups.into_iter().collect(), errors)
+    }
 
-        // Compare and return the result
-        Ok(a_ver.unwrap().compare_to(&b_ver.unwrap(), &operator))
+    /// Get the number of the part at the given `index`, or `0` if it's missing or textual.
+    fn number(version: &Version, index: usize) -> u64 {
+        match version.part(index) {
+            Ok(VersionPart::Number(number)) => *number,
+            _ => 0,
+        }
     }
 }
 
@@ -84,24 +335,49 @@ impl VersionCompare {
 #[cfg(test)]
 mod tests {
     use crate::comp_op::CompOp;
+    use crate::error::{BatchError, Error, Side};
     use crate::test::test_version_set::{TEST_VERSION_SETS, TEST_VERSION_SETS_ERROR};
 
     use super::VersionCompare;
 
+    /// List of versions with duplicates and invalid versions, for batch tests.
+    const BATCH: &[&str] = &[
+        "1.10", "1.2.0", "abc", "2.0-rc1", "1.2", "0.9", "2.0", "rc.dev", "1.9",
+    ];
+
+    /// Errors for the invalid versions in `BATCH`.
+    fn batch_errors() -> Vec<BatchError<'static>> {
+        vec![
+            BatchError {
+                index: 2,
+                version: "abc",
+                error: Error::NoNumber,
+            },
+            BatchError {
+                index: 7,
+                version: "rc.dev",
+                error: Error::NoNumber,
+            },
+        ]
+    }
+
     #[test]
     fn compare() {
         // Compare each version in the version set
         for entry in TEST_VERSION_SETS {
             assert_eq!(
-                VersionCompare::compare(&entry.0, &entry.1),
+                VersionCompare::compare(entry.0, entry.1),
                 Ok(entry.2.clone()),
-                "Testing that {} is {} {}", &entry.0, &entry.2.sign(), &entry.1
+                "Testing that {} is {} {}",
+                &entry.0,
+                &entry.2.sign(),
+                &entry.1
             );
         }
 
         // Compare each error version in the version set
         for entry in TEST_VERSION_SETS_ERROR {
-            let result = VersionCompare::compare(&entry.0, &entry.1);
+            let result = VersionCompare::compare(entry.0, entry.1);
 
             if result.is_ok() {
                 assert!(result != Ok(entry.2.clone()));
@@ -109,30 +385,148 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compare_error() {
+        assert_eq!(
+            VersionCompare::compare("abc", "1.2"),
+            Err(Side::Left.wrap(Error::NoNumber))
+        );
+        assert_eq!(
+            VersionCompare::compare("1.2", "abc"),
+            Err(Side::Right.wrap(Error::NoNumber))
+        );
+        assert_eq!(
+            VersionCompare::compare_to("1.2", "rc.dev", &CompOp::Lt),
+            Err(Side::Right.wrap(Error::NoNumber))
+        );
+    }
+
     #[test]
     fn compare_to() {
         // Compare each version in the version set
         for entry in TEST_VERSION_SETS {
             // Test
-            assert!(VersionCompare::compare_to(&entry.0, &entry.1, &entry.2).unwrap());
+            assert!(VersionCompare::compare_to(entry.0, entry.1, &entry.2).unwrap());
 
             // Make sure the inverse operator is not correct
-            assert_eq!(
-                VersionCompare::compare_to(&entry.0, &entry.1, &entry.2.invert()).unwrap(),
-                false
-            );
+            assert!(!VersionCompare::compare_to(entry.0, entry.1, &entry.2.invert()).unwrap());
         }
 
         // Compare each error version in the version set
         for entry in TEST_VERSION_SETS_ERROR {
-            let result = VersionCompare::compare_to(&entry.0, &entry.1, &entry.2);
+            let result = VersionCompare::compare_to(entry.0, entry.1, &entry.2);
 
-            if result.is_ok() {
-                assert!(!result.unwrap())
+            if let Ok(result) = result {
+                assert!(!result)
             }
         }
 
         // Assert an exceptional case, compare to not equal
         assert!(VersionCompare::compare_to("1.2.3", "1.2", &CompOp::Ne).unwrap());
     }
+
+    #[test]
+    fn sort() {
+        assert_eq!(
+            VersionCompare::sort(BATCH),
+            (
+                vec!["0.9", "1.2.0", "1.2", "1.9", "1.10", "2.0-rc1", "2.0"],
+                batch_errors()
+            )
+        );
+
+        // Each ordered version set is sorted in order
+        for entry in TEST_VERSION_SETS {
+            let (sorted, errors) = VersionCompare::sort(&[entry.0, entry.1]);
+            assert!(errors.is_empty());
+            match entry.2 {
+                CompOp::Gt => assert_eq!(sorted, [entry.1, entry.0]),
+                _ => assert_eq!(sorted, [entry.0, entry.1]),
+            }
+        }
+
+        assert_eq!(VersionCompare::sort(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn max_min() {
+        assert_eq!(VersionCompare::max(BATCH), (Some("2.0"), batch_errors()));
+        assert_eq!(VersionCompare::min(BATCH), (Some("0.9"), batch_errors()));
+
+        // The first of equal versions is returned
+        assert_eq!(VersionCompare::max(&["1.2", "1.2.0"]).0, Some("1.2"));
+        assert_eq!(VersionCompare::min(&["1.2.0", "1.2"]).0, Some("1.2.0"));
+
+        assert_eq!(VersionCompare::max(&[]), (None, vec![]));
+        assert_eq!(VersionCompare::min(&["abc"]).0, None);
+    }
+
+    #[test]
+    fn dedup() {
+        assert_eq!(
+            VersionCompare::dedup(BATCH),
+            (
+                vec!["1.10", "1.2.0", "2.0-rc1", "0.9", "2.0", "1.9"],
+                batch_errors()
+            )
+        );
+        assert_eq!(
+            VersionCompare::dedup(&["1", "1.0.0", "v1", "1.0.1", "1.0"]).0,
+            ["1", "1.0.1"]
+        );
+    }
+
+    #[test]
+    fn group_by() {
+        assert_eq!(
+            VersionCompare::group_by_major(BATCH),
+            (
+                vec![
+                    (0, vec!["0.9"]),
+                    (1, vec!["1.2.0", "1.2", "1.9", "1.10"]),
+                    (2, vec!["2.0-rc1", "2.0"]),
+                ],
+                batch_errors()
+            )
+        );
+        assert_eq!(
+            VersionCompare::group_by_minor(BATCH),
+            (
+                vec![
+                    ((0, 9), vec!["0.9"]),
+                    ((1, 2), vec!["1.2.0", "1.2"]),
+                    ((1, 9), vec!["1.9"]),
+                    ((1, 10), vec!["1.10"]),
+                    ((2, 0), vec!["2.0-rc1", "2.0"]),
+                ],
+                batch_errors()
+            )
+        );
+
+        // Missing and textual parts count as zero
+        assert_eq!(
+            VersionCompare::group_by_minor(&["1-rc1", "1", "1.0.3"]).0,
+            [((1, 0), vec!["1-rc1", "1", "1.0.3"])]
+        );
+    }
+
+    #[test]
+    fn filter() {
+        assert_eq!(
+            VersionCompare::filter(BATCH, &CompOp::Lt, "1.9"),
+            Ok((vec!["1.2.0", "1.2", "0.9"], batch_errors()))
+        );
+        assert_eq!(
+            VersionCompare::filter(BATCH, &CompOp::Eq, "1.2").unwrap().0,
+            ["1.2.0", "1.2"]
+        );
+        assert_eq!(
+            VersionCompare::filter(BATCH, &CompOp::Ne, "1.2").unwrap().0,
+            ["1.10", "2.0-rc1", "0.9", "2.0", "1.9"]
+        );
+        assert_eq!(
+            VersionCompare::filter(BATCH, &CompOp::Ge, "abc"),
+            Err(Side::Right.wrap(Error::NoNumber))
+        );
+    }
 }
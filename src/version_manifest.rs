@@ -1,19 +1,95 @@
 //! Module for the version manifest.
 //!
 //! A version manifest can be used to configure and specify how versions are parsed and compared.
-//! For example, you can configure the maximum depth of a version number, and set whether text
-//! parts are ignored in a version string.
+//! For example, you can configure the maximum depth of a version number, set whether text
+//! parts are ignored in a version string, and choose which characters separate version parts.
+//!
+//! The manifest also selects the version scheme, such as strict semver, that is used to parse and
+//! compare versions.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::scheme::Scheme;
+
+/// How characters that are neither alphanumeric nor a separator are treated when parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum CharPolicy {
+    /// The version is invalid, an `Error::InvalidChar` is returned.
+    Error,
+
+    /// The character is part of the text of the version part it's in, so `1.0+local` has the
+    /// parts `1` and `0+local` if `+` isn't a separator.
+    Literal,
+
+    /// The character is skipped, as if it wasn't in the version string. This is the default.
+    #[default]
+    Ignore,
+}
 
 /// Version manifest (configuration).
 ///
 /// A manifest (configuration) that is used respectively when parsing and comparing version strings.
-#[derive(Debug, PartialEq)]
+///
+/// A manifest can be configured with setters, or built fluently with the `with_*` methods. Presets
+/// are available for common ecosystems, such as `VersionManifest::semver()`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{CharPolicy, Version, VersionManifest};
+///
+/// let manifest = VersionManifest::new()
+///     .with_min_depth(Some(3))
+///     .with_other_chars(CharPolicy::Ignore);
+///
+/// let ver = Version::from_manifest("v1.2", &manifest).unwrap();
+///
+/// assert_eq!(ver.part_count(), 3);
+/// ```
+///
+/// With the `serde` feature, a manifest can be loaded from a configuration file. Options that
+/// aren't specified keep their default value, the scheme is given by its name, such as
+/// `"semver"`, or as `{ "calver": "YYYY.0M" }` for calendar versioning.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct VersionManifest {
     /// The maximum depth of a version number. This specifies the maximum number of parts.
     max_depth: Option<usize>,
 
+    /// The minimum depth of a version number. Versions with fewer parts are padded with zeros.
+    min_depth: Option<usize>,
+
     /// True to ignore text parts in version strings.
     ignore_text: bool,
+
+    /// True to compare text parts case-sensitively.
+    case_sensitive: bool,
+
+    /// True to allow a leading `v` or `V` before the version, such as in `v1.2.3`. Enabled by
+    /// default.
+    v_prefix: bool,
+
+    /// The characters that separate version parts, or `None` for any whitespace or ASCII
+    /// punctuation.
+    separators: Option<Vec<char>>,
+
+    /// How characters that are neither alphanumeric nor a separator are treated.
+    other_chars: CharPolicy,
+
+    /// True if the type of separator affects the ordering of versions.
+    significant_separators: bool,
+
+    /// True if trailing zero parts affect the ordering of versions, so `1.0` and `1` differ.
+    significant_trailing_zeros: bool,
+
+    /// The version scheme used to parse and compare versions.
+    scheme: Scheme,
 }
 
 /// Version manifest implementation.
@@ -33,10 +109,87 @@ impl VersionManifest {
     pub fn new() -> Self {
         VersionManifest {
             max_depth: None,
+            min_depth: None,
             ignore_text: false,
+            case_sensitive: false,
+            v_prefix: true,
+            separators: None,
+            other_chars: CharPolicy::Ignore,
+            significant_separators: false,
+            significant_trailing_zeros: false,
+            scheme: Scheme::Generic,
         }
     }
 
+    /// Preset for strict semver versions, using the semver scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::semver();
+    ///
+    /// assert_eq!(manifest.scheme(), &Scheme::Semver);
+    /// assert!(Version::from_manifest("1.2", &manifest).is_err());
+    /// ```
+    pub fn semver() -> Self {
+        Self::new().with_scheme(Scheme::Semver)
+    }
+
+    /// Preset for Python package versions, using the PEP 440 scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::pep440();
+    ///
+    /// assert_eq!(manifest.scheme(), &Scheme::Pep440);
+    /// assert_eq!(Version::from_manifest("1.0-RC1", &manifest).unwrap().to_string(), "1.0rc1");
+    /// ```
+    pub fn pep440() -> Self {
+        Self::new().with_scheme(Scheme::Pep440)
+    }
+
+    /// Preset for Debian package versions, using the Debian scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::debian();
+    ///
+    /// assert_eq!(manifest.scheme(), &Scheme::Debian);
+    /// assert_eq!(Version::from_manifest("1:2.30-1", &manifest).unwrap().epoch(), Some(1));
+    /// ```
+    pub fn debian() -> Self {
+        Self::new().with_scheme(Scheme::Debian)
+    }
+
+    /// Preset for parsing versions of unknown format as leniently as possible.
+    ///
+    /// This uses the generic scheme, and ignores characters that aren't alphanumeric, whitespace
+    /// or ASCII punctuation, even if the default character policy changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::loose();
+    ///
+    /// let ver_a = Version::from_manifest("V1.2.0", &manifest).unwrap();
+    /// let ver_b = Version::from_manifest("1.2\u{2122}", &manifest).unwrap();
+    ///
+    /// assert_eq!(ver_a, ver_b);
+    /// ```
+    pub fn loose() -> Self {
+        Self::new().with_other_chars(CharPolicy::Ignore)
+    }
+
     /// The maximum depth of a version number.
     /// None if no depth is configured.
     ///
@@ -69,11 +222,7 @@ impl VersionManifest {
     /// println!("Maximum depth of {}", manifest.max_depth_number());
     /// ```
     pub fn max_depth_number(&self) -> usize {
-        if self.max_depth.is_some() {
-            self.max_depth.unwrap()
-        } else {
-            0
-        }
+        self.max_depth.unwrap_or(0)
     }
 
     /// Set the maximum depth of a version number.
@@ -141,6 +290,102 @@ impl VersionManifest {
         self.max_depth.is_some() && self.max_depth.unwrap() > 0
     }
 
+    /// The minimum depth of a version number.
+    /// None if no depth is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert_eq!(manifest.min_depth(), &None);
+    /// ```
+    pub fn min_depth(&self) -> &Option<usize> {
+        &self.min_depth
+    }
+
+    /// The minimum depth of a version number as numerical value.
+    /// Zero is returned if no depth is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// println!("Minimum depth of {}", manifest.min_depth_number());
+    /// ```
+    pub fn min_depth_number(&self) -> usize {
+        self.min_depth.unwrap_or(0)
+    }
+
+    /// Set the minimum depth of a version number.
+    ///
+    /// Versions with fewer parts are padded with zero parts, up to the maximum depth if there
+    /// is one. Padded parts are treated as if they were preceded by a `.` separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionManifest, VersionPart};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    ///
+    /// // Set the minimum depth to 3
+    /// manifest.set_min_depth(Some(3));
+    /// assert_eq!(
+    ///     Version::from_manifest("1.2", &manifest).unwrap().parts(),
+    ///     &[VersionPart::Number(1), VersionPart::Number(2), VersionPart::Number(0)],
+    /// );
+    ///
+    /// // Don't use a minimum depth
+    /// manifest.set_min_depth(None);
+    /// ```
+    pub fn set_min_depth(&mut self, min_depth: Option<usize>) {
+        self.min_depth = min_depth.filter(|depth| *depth > 0);
+    }
+
+    /// Set the minimum depth of a version number.
+    /// Use zero to disable the minimum depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let mut manifest = VersionManifest::new();
+    ///
+    /// // Set the minimum depth to 3
+    /// manifest.set_min_depth_number(3);
+    ///
+    /// // Don't use a minimum depth
+    /// manifest.set_min_depth_number(0);
+    /// ```
+    pub fn set_min_depth_number(&mut self, min_depth: usize) {
+        self.set_min_depth(Some(min_depth));
+    }
+
+    /// Check whether there's a minimum configured depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let mut manifest = VersionManifest::new();
+    ///
+    /// assert!(!manifest.has_min_depth());
+    ///
+    /// manifest.set_min_depth(Some(3));
+    /// assert!(manifest.has_min_depth());
+    /// ```
+    pub fn has_min_depth(&self) -> bool {
+        self.min_depth.is_some()
+    }
+
     /// Check whether to ignore text parts in version numbers.
     ///
     /// # Examples
@@ -178,12 +423,482 @@ impl VersionManifest {
     pub fn set_ignore_text(&mut self, ignore_text: bool) {
         self.ignore_text = ignore_text;
     }
+
+    /// Check whether text parts are compared case-sensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert!(!manifest.case_sensitive());
+    /// ```
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Set whether text parts are compared case-sensitively.
+    ///
+    /// Text parts are compared case-insensitively by default, so `1.0-RC1` equals `1.0-rc1`. If
+    /// enabled, text parts that only differ in case are ordered by their characters. This only
+    /// applies if both compared versions have it enabled, and only to the generic scheme.
+    ///
+    /// The option is used by `Version::compare` and `Version::compare_to`. Equality and ordering
+    /// of versions ignore it, so they stay transitive when mixing manifests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_case_sensitive(true);
+    ///
+    /// let ver_a = Version::from_manifest("1.0-RC1", &manifest).unwrap();
+    /// let ver_b = Version::from_manifest("1.0-rc1", &manifest).unwrap();
+    ///
+    /// assert_eq!(ver_a.compare(&ver_b), CompOp::Lt);
+    /// ```
+    pub fn set_case_sensitive(&mut self, case_sensitive: bool) {
+        self.case_sensitive = case_sensitive;
+    }
+
+    /// Check whether a leading `v` or `V` is allowed before the version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert!(manifest.v_prefix());
+    /// ```
+    pub fn v_prefix(&self) -> bool {
+        self.v_prefix
+    }
+
+    /// Set whether a leading `v` or `V` is allowed before the version.
+    ///
+    /// If allowed, a `v` or `V` directly followed by a digit at the start of the version core is
+    /// part of the version prefix, so `v1.2.3` equals `1.2.3`. Otherwise it's part of the first
+    /// text part. This is allowed by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_v_prefix(false);
+    ///
+    /// assert_eq!(Version::from_manifest("v1.2.3", &manifest).unwrap().part_count(), 3);
+    /// assert_eq!(Version::from_manifest("v1.2.3", &manifest).unwrap().prefix(), None);
+    /// ```
+    pub fn set_v_prefix(&mut self, v_prefix: bool) {
+        self.v_prefix = v_prefix;
+    }
+
+    /// The characters that separate version parts.
+    ///
+    /// `None` is returned if no separators are configured, in which case any whitespace or ASCII
+    /// punctuation character is a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// assert_eq!(manifest.separators(), None);
+    ///
+    /// manifest.set_separators(Some(&['.', '-']));
+    /// assert_eq!(manifest.separators(), Some(&['.', '-'][..]));
+    /// ```
+    pub fn separators(&self) -> Option<&[char]> {
+        self.separators.as_deref()
+    }
+
+    /// Set the characters that separate version parts.
+    ///
+    /// Other characters that aren't alphanumeric are treated following `other_chars`. Use `None`
+    /// to use any whitespace or ASCII punctuation character as separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CharPolicy, Error, Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    ///
+    /// // Only split at dots and hyphens, and reject other characters
+    /// manifest.set_separators(Some(&['.', '-']));
+    /// manifest.set_other_chars(CharPolicy::Error);
+    ///
+    /// assert_eq!(
+    ///     Version::from_manifest("1.0_1", &manifest).unwrap_err(),
+    ///     Error::InvalidChar { offset: 3, ch: '_' },
+    /// );
+    /// ```
+    pub fn set_separators(&mut self, separators: Option<&[char]>) {
+        self.separators = separators.map(<[char]>::to_vec);
+    }
+
+    /// Check whether the given character separates version parts.
+    pub(crate) fn is_separator(&self, c: char) -> bool {
+        match &self.separators {
+            Some(separators) => separators.contains(&c),
+            None => c.is_whitespace() || c.is_ascii_punctuation(),
+        }
+    }
+
+    /// How characters that are neither alphanumeric nor a separator are treated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CharPolicy, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert_eq!(manifest.other_chars(), CharPolicy::Ignore);
+    /// ```
+    pub fn other_chars(&self) -> CharPolicy {
+        self.other_chars
+    }
+
+    /// Set how characters that are neither alphanumeric nor a separator are treated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CharPolicy, Version, VersionManifest, VersionPart};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_separators(Some(&['.']));
+    ///
+    /// // Reject other characters
+    /// manifest.set_other_chars(CharPolicy::Error);
+    /// assert!(Version::from_manifest("1.0+local", &manifest).is_err());
+    ///
+    /// // Keep other characters in the text of parts
+    /// manifest.set_other_chars(CharPolicy::Literal);
+    /// let ver = Version::from_manifest("1.0+local", &manifest).unwrap();
+    /// assert_eq!(ver.part(1), Ok(&VersionPart::Text("0+local".into())));
+    ///
+    /// // Skip other characters
+    /// manifest.set_other_chars(CharPolicy::Ignore);
+    /// let ver = Version::from_manifest("1.2_000", &manifest).unwrap();
+    /// assert_eq!(ver.part(1), Ok(&VersionPart::Number(2000)));
+    /// ```
+    pub fn set_other_chars(&mut self, other_chars: CharPolicy) {
+        self.other_chars = other_chars;
+    }
+
+    /// Check whether the type of separator affects the ordering of versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert!(!manifest.significant_separators());
+    /// ```
+    pub fn significant_separators(&self) -> bool {
+        self.significant_separators
+    }
+
+    /// Set whether the type of separator affects the ordering of versions.
+    ///
+    /// If enabled, parts preceded by a different separator are ordered by their separator, in
+    /// the order the separators are configured in. If no separators are configured, they're
+    /// ordered by their character. This only applies if both compared versions have it enabled,
+    /// and only to the generic scheme. Like case sensitivity, it's only used by
+    /// `Version::compare` and `Version::compare_to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_separators(Some(&['-', '.']));
+    /// manifest.set_significant_separators(true);
+    ///
+    /// let ver_a = Version::from_manifest("1.0-1", &manifest).unwrap();
+    /// let ver_b = Version::from_manifest("1.0.1", &manifest).unwrap();
+    ///
+    /// assert_eq!(ver_a.compare(&ver_b), CompOp::Lt);
+    /// ```
+    pub fn set_significant_separators(&mut self, significant_separators: bool) {
+        self.significant_separators = significant_separators;
+    }
+
+    /// Check whether trailing zero parts affect the ordering of versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert!(!manifest.significant_trailing_zeros());
+    /// ```
+    pub fn significant_trailing_zeros(&self) -> bool {
+        self.significant_trailing_zeros
+    }
+
+    /// Set whether trailing zero parts affect the ordering of versions.
+    ///
+    /// Trailing zeros are insignificant by default, so `1.0.0` equals `1`. If enabled, a version
+    /// with more parts is greater than the same version without its trailing zero parts, for
+    /// ecosystems where `1.0` and `1.0.0` are distinct releases. This only applies if both
+    /// compared versions have it enabled, and only to the generic scheme.
+    ///
+    /// Only `Version::compare` and `Version::compare_to` use it. The `==` and `<` operators,
+    /// sorting and hashing treat `1.0` and `1.0.0` as equal, as `1` equals both of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, Version, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    /// manifest.set_significant_trailing_zeros(true);
+    ///
+    /// let ver_a = Version::from_manifest("1.0", &manifest).unwrap();
+    /// let ver_b = Version::from_manifest("1.0.0", &manifest).unwrap();
+    ///
+    /// assert_eq!(ver_a.compare(&ver_b), CompOp::Lt);
+    /// assert_eq!(ver_a, ver_b);
+    /// ```
+    pub fn set_significant_trailing_zeros(&mut self, significant_trailing_zeros: bool) {
+        self.significant_trailing_zeros = significant_trailing_zeros;
+    }
+
+    /// Get the rank of the given separator, used to order versions if separators are
+    /// significant.
+    pub(crate) fn separator_rank(&self, separator: char) -> usize {
+        match &self.separators {
+            Some(separators) => separators
+                .iter()
+                .position(|c| *c == separator)
+                .unwrap_or(separators.len()),
+            None => separator as usize,
+        }
+    }
+
+    /// The version scheme used to parse and compare versions.
+    ///
+    /// The depth, text, prefix and separator options only apply to the generic scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::new();
+    ///
+    /// assert_eq!(manifest.scheme(), &Scheme::Generic);
+    /// ```
+    pub fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    /// Set the version scheme used to parse and compare versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, VersionManifest};
+    ///
+    /// let mut manifest = VersionManifest::new();
+    ///
+    /// // Use strict semver parsing and precedence
+    /// manifest.set_scheme(Scheme::Semver);
+    /// ```
+    pub fn set_scheme(&mut self, scheme: Scheme) {
+        self.scheme = scheme;
+    }
+
+    /// Get this manifest with the given maximum depth, see `set_max_depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_max_depth(Some(3));
+    ///
+    /// assert_eq!(manifest.max_depth(), &Some(3));
+    /// ```
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.set_max_depth(max_depth);
+        self
+    }
+
+    /// Get this manifest with the given minimum depth, see `set_min_depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_min_depth(Some(3));
+    ///
+    /// assert_eq!(manifest.min_depth(), &Some(3));
+    /// ```
+    pub fn with_min_depth(mut self, min_depth: Option<usize>) -> Self {
+        self.set_min_depth(min_depth);
+        self
+    }
+
+    /// Get this manifest with the given text part option, see `set_ignore_text`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_ignore_text(true);
+    ///
+    /// assert!(manifest.ignore_text());
+    /// ```
+    pub fn with_ignore_text(mut self, ignore_text: bool) -> Self {
+        self.set_ignore_text(ignore_text);
+        self
+    }
+
+    /// Get this manifest with the given case sensitivity, see `set_case_sensitive`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_case_sensitive(true);
+    ///
+    /// assert!(manifest.case_sensitive());
+    /// ```
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.set_case_sensitive(case_sensitive);
+        self
+    }
+
+    /// Get this manifest with the given prefix option, see `set_v_prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_v_prefix(false);
+    ///
+    /// assert!(!manifest.v_prefix());
+    /// ```
+    pub fn with_v_prefix(mut self, v_prefix: bool) -> Self {
+        self.set_v_prefix(v_prefix);
+        self
+    }
+
+    /// Get this manifest with the given separators, see `set_separators`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_separators(Some(&['.', '-']));
+    ///
+    /// assert_eq!(manifest.separators(), Some(&['.', '-'][..]));
+    /// ```
+    pub fn with_separators(mut self, separators: Option<&[char]>) -> Self {
+        self.set_separators(separators);
+        self
+    }
+
+    /// Get this manifest with the given character policy, see `set_other_chars`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CharPolicy, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::new().with_other_chars(CharPolicy::Literal);
+    ///
+    /// assert_eq!(manifest.other_chars(), CharPolicy::Literal);
+    /// ```
+    pub fn with_other_chars(mut self, other_chars: CharPolicy) -> Self {
+        self.set_other_chars(other_chars);
+        self
+    }
+
+    /// Get this manifest with the given separator option, see `set_significant_separators`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_significant_separators(true);
+    ///
+    /// assert!(manifest.significant_separators());
+    /// ```
+    pub fn with_significant_separators(mut self, significant_separators: bool) -> Self {
+        self.set_significant_separators(significant_separators);
+        self
+    }
+
+    /// Get this manifest with the given trailing zero option, see
+    /// `set_significant_trailing_zeros`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionManifest;
+    ///
+    /// let manifest = VersionManifest::new().with_significant_trailing_zeros(true);
+    ///
+    /// assert!(manifest.significant_trailing_zeros());
+    /// ```
+    pub fn with_significant_trailing_zeros(mut self, significant_trailing_zeros: bool) -> Self {
+        self.set_significant_trailing_zeros(significant_trailing_zeros);
+        self
+    }
+
+    /// Get this manifest with the given version scheme, see `set_scheme`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, VersionManifest};
+    ///
+    /// let manifest = VersionManifest::new().with_scheme(Scheme::Rpm);
+    ///
+    /// assert_eq!(manifest.scheme(), &Scheme::Rpm);
+    /// ```
+    pub fn with_scheme(mut self, scheme: Scheme) -> Self {
+        self.set_scheme(scheme);
+        self
+    }
+}
+
+impl Default for VersionManifest {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
 #[cfg(test)]
 mod tests {
-    use crate::version_manifest::VersionManifest;
+    use crate::scheme::Scheme;
+    use crate::version_manifest::{CharPolicy, VersionManifest};
 
     #[test]
     fn max_depth() {
@@ -258,6 +973,28 @@ mod tests {
         assert!(!manifest.has_max_depth());
     }
 
+    #[test]
+    fn min_depth() {
+        let mut manifest = VersionManifest::new();
+        assert_eq!(manifest.min_depth(), &None);
+        assert_eq!(manifest.min_depth_number(), 0);
+        assert!(!manifest.has_min_depth());
+
+        manifest.set_min_depth(Some(3));
+        assert_eq!(manifest.min_depth, Some(3));
+        assert_eq!(manifest.min_depth_number(), 3);
+        assert!(manifest.has_min_depth());
+
+        manifest.set_min_depth(Some(0));
+        assert_eq!(manifest.min_depth, None);
+
+        manifest.set_min_depth_number(2);
+        assert_eq!(manifest.min_depth(), &Some(2));
+
+        manifest.set_min_depth_number(0);
+        assert_eq!(manifest.min_depth(), &None);
+    }
+
     #[test]
     fn ignore_text() {
         let mut manifest = VersionManifest::new();
@@ -279,4 +1016,144 @@ mod tests {
         manifest.set_ignore_text(false);
         assert!(!manifest.ignore_text);
     }
+
+    #[test]
+    fn case_sensitive() {
+        let mut manifest = VersionManifest::new();
+        assert!(!manifest.case_sensitive());
+
+        manifest.set_case_sensitive(true);
+        assert!(manifest.case_sensitive);
+
+        manifest.set_case_sensitive(false);
+        assert!(!manifest.case_sensitive());
+    }
+
+    #[test]
+    fn v_prefix() {
+        let mut manifest = VersionManifest::new();
+        assert!(manifest.v_prefix());
+
+        manifest.set_v_prefix(false);
+        assert!(!manifest.v_prefix);
+
+        manifest.set_v_prefix(true);
+        assert!(manifest.v_prefix());
+    }
+
+    #[test]
+    fn separators() {
+        let mut manifest = VersionManifest::new();
+        assert_eq!(manifest.separators(), None);
+        assert!(manifest.is_separator('_'));
+        assert!(manifest.is_separator(' '));
+        assert!(!manifest.is_separator('a'));
+
+        manifest.set_separators(Some(&['.', '-']));
+        assert_eq!(manifest.separators, Some(vec!['.', '-']));
+        assert!(manifest.is_separator('-'));
+        assert!(!manifest.is_separator('_'));
+
+        manifest.set_separators(None);
+        assert_eq!(manifest.separators, None);
+    }
+
+    #[test]
+    fn other_chars() {
+        let mut manifest = VersionManifest::new();
+        assert_eq!(manifest.other_chars(), CharPolicy::Ignore);
+
+        manifest.set_other_chars(CharPolicy::Literal);
+        assert_eq!(manifest.other_chars, CharPolicy::Literal);
+
+        manifest.set_other_chars(CharPolicy::Error);
+        assert_eq!(manifest.other_chars(), CharPolicy::Error);
+    }
+
+    #[test]
+    fn significant_separators() {
+        let mut manifest = VersionManifest::new();
+        assert!(!manifest.significant_separators());
+
+        manifest.set_significant_separators(true);
+        assert!(manifest.significant_separators);
+
+        manifest.set_separators(Some(&['-', '.']));
+        assert_eq!(manifest.separator_rank('-'), 0);
+        assert_eq!(manifest.separator_rank('.'), 1);
+        assert_eq!(manifest.separator_rank('_'), 2);
+    }
+
+    #[test]
+    fn significant_trailing_zeros() {
+        let mut manifest = VersionManifest::new();
+        assert!(!manifest.significant_trailing_zeros());
+
+        manifest.set_significant_trailing_zeros(true);
+        assert!(manifest.significant_trailing_zeros);
+
+        manifest.set_significant_trailing_zeros(false);
+        assert!(!manifest.significant_trailing_zeros());
+    }
+
+    #[test]
+    fn scheme() {
+        let mut manifest = VersionManifest::new();
+        assert_eq!(manifest.scheme(), &Scheme::Generic);
+
+        manifest.scheme = Scheme::Semver;
+        assert_eq!(manifest.scheme(), &Scheme::Semver);
+    }
+
+    #[test]
+    fn set_scheme() {
+        let mut manifest = VersionManifest::new();
+
+        manifest.set_scheme(Scheme::Semver);
+        assert_eq!(manifest.scheme, Scheme::Semver);
+
+        manifest.set_scheme(Scheme::Generic);
+        assert_eq!(manifest.scheme, Scheme::Generic);
+    }
+
+    #[test]
+    fn builder() {
+        let manifest = VersionManifest::new()
+            .with_max_depth(Some(4))
+            .with_min_depth(Some(2))
+            .with_ignore_text(true)
+            .with_case_sensitive(true)
+            .with_v_prefix(false)
+            .with_separators(Some(&['.']))
+            .with_other_chars(CharPolicy::Literal)
+            .with_significant_separators(true)
+            .with_significant_trailing_zeros(true)
+            .with_scheme(Scheme::Semver);
+
+        let mut expected = VersionManifest::new();
+        expected.set_max_depth(Some(4));
+        expected.set_min_depth(Some(2));
+        expected.set_ignore_text(true);
+        expected.set_case_sensitive(true);
+        expected.set_v_prefix(false);
+        expected.set_separators(Some(&['.']));
+        expected.set_other_chars(CharPolicy::Literal);
+        expected.set_significant_separators(true);
+        expected.set_significant_trailing_zeros(true);
+        expected.set_scheme(Scheme::Semver);
+
+        assert_eq!(manifest, expected);
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(VersionManifest::semver().scheme(), &Scheme::Semver);
+        assert_eq!(VersionManifest::pep440().scheme(), &Scheme::Pep440);
+        assert_eq!(VersionManifest::debian().scheme(), &Scheme::Debian);
+
+        let loose = VersionManifest::loose();
+        assert_eq!(loose.scheme(), &Scheme::Generic);
+        assert!(loose.v_prefix());
+        assert_eq!(loose.other_chars(), CharPolicy::Ignore);
+    }
 }
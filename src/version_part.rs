@@ -4,21 +4,119 @@
 //! parts. Each version string is broken down into these version parts when being parsed to a
 //! `Version`.
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::comp_op::CompOp;
+
+/// Well-known pre-release tags, ordered from lowest to highest precedence.
+///
+/// Each entry lists the tag and its aliases. Tags are matched case-insensitively.
+const PRE_RELEASE_TAGS: &[&[&str]] = &[
+    &["dev"],
+    &["alpha", "a"],
+    &["beta", "b"],
+    &["rc", "c"],
+    &["release"],
+];
 
 /// Enum of version string parts.
 ///
 /// Each version string is broken down into these version parts when being parsed to a `Version`.
-#[derive(Debug, PartialEq)]
+///
+/// With the `serde` feature, a number part is serialized as number and a text part as string.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
 pub enum VersionPart<'a> {
     /// Numeric part, most common in version strings.
-    /// Holds the numerical value.
-    Number(i32),
+    /// Holds the numerical value, which fits large date stamps and build numbers.
+    ///
+    /// Numbers are unsigned, a leading `-` is treated as separator. Numbers that don't fit in an
+    /// `u64` are kept as text part, which is still compared by its numerical value.
+    Number(u64),
 
     /// A text part.
     /// These parts usually hold text with an yet unknown definition.
-    /// Holds the string slice.
-    Text(&'a str),
+    /// Holds the string slice, or an owned string.
+    Text(Cow<'a, str>),
+}
+
+impl<'a> VersionPart<'a> {
+    /// Convert into an owned version part, that doesn't borrow any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionPart;
+    ///
+    /// let text = String::from("alpha");
+    /// let part: VersionPart<'static> = VersionPart::Text(text.as_str().into()).into_owned();
+    /// drop(text);
+    ///
+    /// assert_eq!(part, VersionPart::Text("alpha".into()));
+    /// ```
+    pub fn into_owned(self) -> VersionPart<'static> {
+        match self {
+            VersionPart::Number(n) => VersionPart::Number(n),
+            VersionPart::Text(t) => VersionPart::Text(Cow::Owned(t.into_owned())),
+        }
+    }
+
+    /// Compare this version part to the given `other` part.
+    ///
+    /// The following rules are used:
+    ///
+    /// * Numbers are compared by their value.
+    /// * A number is always greater than text. Text with only digits holds a number too large
+    ///   for a number part, and is greater than any number or other text.
+    /// * Text is compared case-insensitively. A leading well-known pre-release tag is ranked as
+    ///   `dev` < `alpha` (`a`) < `beta` (`b`) < `rc` (`c`) < `release`, any other leading text
+    ///   ranks below these tags and is compared lexically. Digits following the tag are compared
+    ///   numerically, so `rc2` < `rc10`.
+    ///
+    /// This method returns one of the following comparison operators:
+    ///
+    /// * `Lt`
+    /// * `Eq`
+    /// * `Gt`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, VersionPart};
+    ///
+    /// assert_eq!(VersionPart::Number(1).compare(&VersionPart::Number(2)), CompOp::Lt);
+    /// assert_eq!(VersionPart::Number(1).compare(&VersionPart::Text("rc".into())), CompOp::Gt);
+    /// assert_eq!(VersionPart::Text("alpha".into()).compare(&VersionPart::Text("beta".into())), CompOp::Lt);
+    /// assert_eq!(VersionPart::Text("rc10".into()).compare(&VersionPart::Text("rc2".into())), CompOp::Gt);
+    /// ```
+    pub fn compare(&self, other: &VersionPart) -> CompOp {
+        match (self, other) {
+            (VersionPart::Number(num), VersionPart::Number(other_num)) => {
+                CompOp::from_ord(num.cmp(other_num))
+            }
+            (VersionPart::Number(_), VersionPart::Text(text)) => match is_digits(text) {
+                true => CompOp::Lt,
+                false => CompOp::Gt,
+            },
+            (VersionPart::Text(text), VersionPart::Number(_)) => match is_digits(text) {
+                true => CompOp::Gt,
+                false => CompOp::Lt,
+            },
+            (VersionPart::Text(text), VersionPart::Text(other_text)) => {
+                match (is_digits(text), is_digits(other_text)) {
+                    (true, false) => CompOp::Gt,
+                    (false, true) => CompOp::Lt,
+                    _ => CompOp::from_ord(compare_text(text, other_text)),
+                }
+            }
+        }
+    }
 }
 
 impl<'a> fmt::Display for VersionPart<'a> {
@@ -30,14 +128,301 @@ impl<'a> fmt::Display for VersionPart<'a> {
     }
 }
 
+/// Implement the hash trait for version parts, consistent with `VersionPart::compare`.
+///
+/// Text parts that compare equal, such as `RC01` and `rc1`, produce the same hash.
+impl<'a> Hash for VersionPart<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            VersionPart::Number(n) => {
+                state.write_u8(0);
+                n.hash(state);
+            }
+            VersionPart::Text(text) => {
+                state.write_u8(1);
+                hash_text(text, state);
+            }
+        }
+    }
+}
+
+/// Hash a text part, consistent with `compare_text`.
+fn hash_text<H: Hasher>(text: &str, state: &mut H) {
+    let (tag, mut rest) = split_tag(text);
+
+    // Hash the rank of well-known tags, or the tag itself
+    match pre_release_rank(tag) {
+        Some(rank) => {
+            state.write_u8(0);
+            state.write_usize(rank);
+        }
+        None => {
+            state.write_u8(1);
+            hash_ignore_case(tag, state);
+        }
+    }
+
+    // Hash each run of the remainder
+    loop {
+        let (run, run_rest) = split_run(rest);
+        if run.is_empty() {
+            break;
+        }
+
+        if is_digits(run) {
+            state.write_u8(2);
+            run.trim_start_matches('0').hash(state);
+        } else {
+            state.write_u8(3);
+            hash_ignore_case(run, state);
+        }
+
+        rest = run_rest;
+    }
+}
+
+/// Hash a string, ignoring ASCII case.
+fn hash_ignore_case<H: Hasher>(text: &str, state: &mut H) {
+    for c in text.chars() {
+        c.to_ascii_lowercase().hash(state);
+    }
+    state.write_u8(0xff);
+}
+
+/// Compare two text parts.
+///
+/// The leading alphabetic tag of both parts is ranked first, the remainder is compared using
+/// natural ordering.
+fn compare_text(text: &str, other: &str) -> Ordering {
+    let (tag, rest) = split_tag(text);
+    let (other_tag, other_rest) = split_tag(other);
+
+    let ord = match (pre_release_rank(tag), pre_release_rank(other_tag)) {
+        (Some(rank), Some(other_rank)) => rank.cmp(&other_rank),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (None, None) => compare_ignore_case(tag, other_tag),
+    };
+
+    ord.then_with(|| compare_natural(rest, other_rest))
+}
+
+/// Split the given text at the end of its leading alphabetic tag.
+fn split_tag(text: &str) -> (&str, &str) {
+    let end = text
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
+
+/// Get the rank of a well-known pre-release tag, if it is one.
+fn pre_release_rank(tag: &str) -> Option<usize> {
+    PRE_RELEASE_TAGS
+        .iter()
+        .position(|aliases| aliases.iter().any(|alias| alias.eq_ignore_ascii_case(tag)))
+}
+
+/// Compare two strings lexically, ignoring ASCII case.
+fn compare_ignore_case(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .map(|c| c.to_ascii_lowercase())
+        .cmp(b.chars().map(|c| c.to_ascii_lowercase()))
+}
+
+/// Compare two strings using natural ordering.
+///
+/// Both strings are walked in runs of digits and non-digits. Digit runs are compared
+/// numerically and are greater than non-digit runs, other runs are compared lexically.
+fn compare_natural(mut a: &str, mut b: &str) -> Ordering {
+    loop {
+        let (run, rest) = split_run(a);
+        let (other_run, other_rest) = split_run(b);
+
+        let ord = match (run, other_run) {
+            ("", "") => return Ordering::Equal,
+            ("", _) => Ordering::Less,
+            (_, "") => Ordering::Greater,
+            _ => match (is_digits(run), is_digits(other_run)) {
+                (true, true) => compare_digits(run, other_run),
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => compare_ignore_case(run, other_run),
+            },
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+
+        a = rest;
+        b = other_rest;
+    }
+}
+
+/// Split off the leading run of digits or non-digits from the given string.
+fn split_run(text: &str) -> (&str, &str) {
+    let digits = text.starts_with(|c: char| c.is_ascii_digit());
+    let end = text
+        .find(|c: char| c.is_ascii_digit() != digits)
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
+
+/// Check whether the given string only consists of ASCII digits.
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Compare two strings of digits by their numerical value, without parsing them.
+fn compare_digits(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
 #[cfg_attr(tarpaulin, skip)]
 #[cfg(test)]
 mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use crate::comp_op::CompOp;
     use crate::version_part::VersionPart;
 
+    #[test]
+    fn compare() {
+        // Numbers
+        assert_eq!(
+            VersionPart::Number(1).compare(&VersionPart::Number(1)),
+            CompOp::Eq
+        );
+        assert_eq!(
+            VersionPart::Number(1).compare(&VersionPart::Number(2)),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Number(3).compare(&VersionPart::Number(2)),
+            CompOp::Gt
+        );
+
+        // Numbers against text
+        assert_eq!(
+            VersionPart::Number(0).compare(&VersionPart::Text("rc".into())),
+            CompOp::Gt
+        );
+        assert_eq!(
+            VersionPart::Text("rc".into()).compare(&VersionPart::Number(0)),
+            CompOp::Lt
+        );
+
+        // Numbers too large for a number part
+        let large = VersionPart::Text("99999999999999999999".into());
+        assert_eq!(VersionPart::Number(u64::MAX).compare(&large), CompOp::Lt);
+        assert_eq!(large.compare(&VersionPart::Number(0)), CompOp::Gt);
+        assert_eq!(large.compare(&VersionPart::Text("rc1".into())), CompOp::Gt);
+        assert_eq!(
+            large.compare(&VersionPart::Text("100000000000000000000".into())),
+            CompOp::Lt
+        );
+
+        // Pre-release tags
+        assert_eq!(
+            VersionPart::Text("dev".into()).compare(&VersionPart::Text("alpha".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("alpha".into()).compare(&VersionPart::Text("beta".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("beta".into()).compare(&VersionPart::Text("rc".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("rc".into()).compare(&VersionPart::Text("release".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("a".into()).compare(&VersionPart::Text("alpha".into())),
+            CompOp::Eq
+        );
+        assert_eq!(
+            VersionPart::Text("RC".into()).compare(&VersionPart::Text("rc".into())),
+            CompOp::Eq
+        );
+        assert_eq!(
+            VersionPart::Text("foo".into()).compare(&VersionPart::Text("dev".into())),
+            CompOp::Lt
+        );
+
+        // Natural ordering of text
+        assert_eq!(
+            VersionPart::Text("rc1".into()).compare(&VersionPart::Text("rc2".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("rc10".into()).compare(&VersionPart::Text("rc2".into())),
+            CompOp::Gt
+        );
+        assert_eq!(
+            VersionPart::Text("rc02".into()).compare(&VersionPart::Text("rc2".into())),
+            CompOp::Eq
+        );
+        assert_eq!(
+            VersionPart::Text("rc".into()).compare(&VersionPart::Text("rc1".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("beta2".into()).compare(&VersionPart::Text("rc1".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("foo".into()).compare(&VersionPart::Text("bar".into())),
+            CompOp::Gt
+        );
+        assert_eq!(
+            VersionPart::Text("r1a".into()).compare(&VersionPart::Text("r1b".into())),
+            CompOp::Lt
+        );
+        assert_eq!(
+            VersionPart::Text("r1a".into()).compare(&VersionPart::Text("r12".into())),
+            CompOp::Lt
+        );
+    }
+
+    #[test]
+    fn hash() {
+        // Get the hash of a version part
+        fn hash(part: &VersionPart) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            part.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Parts that compare equal must have an equal hash
+        let parts = [
+            ("rc1", "RC01"),
+            ("a", "alpha"),
+            ("Beta2b", "b2B"),
+            ("foo", "FOO"),
+        ];
+        for (a, b) in parts.iter() {
+            let part_a = VersionPart::Text((*a).into());
+            let part_b = VersionPart::Text((*b).into());
+            assert_eq!(part_a.compare(&part_b), CompOp::Eq);
+            assert_eq!(hash(&part_a), hash(&part_b), "Hashing {} and {}", a, b);
+        }
+
+        assert_ne!(
+            hash(&VersionPart::Text("rc1".into())),
+            hash(&VersionPart::Text("rc2".into()))
+        );
+        assert_eq!(hash(&VersionPart::Number(1)), hash(&VersionPart::Number(1)));
+    }
+
     #[test]
     fn display() {
         assert_eq!(format!("{}", VersionPart::Number(123)), "123");
-        assert_eq!(format!("{}", VersionPart::Text("123")), "123");
+        assert_eq!(format!("{}", VersionPart::Text("123".into())), "123");
     }
 }
@@ -0,0 +1,561 @@
+//! Version range module, which provides the `VersionRange` struct as parsed range representation.
+//!
+//! A version range is a set of constraints that a version must satisfy, such as `>=1.2, <2.0`.
+//! Ranges use the [npm-style](https://docs.npmjs.com/misc/semver) syntax, and are tested against
+//! a `Version` using `VersionRange::matches`.
+
+use std::fmt;
+
+use crate::comp_op::CompOp;
+use crate::error::Error;
+use crate::version::Version;
+use crate::version_part::VersionPart;
+
+/// Version range struct, which is a representation for a parsed version range string.
+///
+/// A range string consists of one or more comparator sets separated by `||`. A version matches
+/// the range if it matches all comparators of any set. Comparators within a set are separated by
+/// whitespace or commas.
+///
+/// The following comparators are supported:
+///
+/// * `1.2.3`, `=1.2.3`: an exact version.
+/// * `>1.2`, `>=1.2`, `<1.2`, `<=1.2`, `!=1.2`: any comparison operator supported by
+///   `CompOp::from_sign`.
+/// * `1.x`, `1.2.*`, `1.2`, `*`: any version starting with the given parts.
+/// * `^1.4`: any version compatible with the given version, allowing changes that do not modify
+///   the left-most non-zero part.
+/// * `~1.4.2`: any version with the same major and minor part, allowing patch-level changes.
+/// * `1.2 - 1.8`: any version within the inclusive range.
+///
+/// Exclusive upper bounds of partial versions don't match pre-releases of the bound, so neither
+/// `^1.4` nor `<2.0` matches `2.0.0-rc.1`.
+///
+/// The original range string is stored in the struct, and can be accessed using the
+/// `range.as_str()` method.
+#[derive(Debug)]
+pub struct VersionRange<'a> {
+    range: &'a str,
+    sets: Vec<Vec<Comparator<'a>>>,
+}
+
+impl<'a> VersionRange<'a> {
+    /// Create a `VersionRange` instance from a range string.
+    ///
+    /// An error is returned if the range string couldn't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::from(">=1.2, <2.0").unwrap();
+    ///
+    /// assert!(range.matches(&Version::from("1.5").unwrap()));
+    /// assert!(!range.matches(&Version::from("2.0").unwrap()));
+    /// ```
+    pub fn from(range: &'a str) -> Result<Self, Error> {
+        // Parse each set of comparators
+        let sets = range
+            .split("||")
+            .map(|set| Self::parse_set(range, set))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VersionRange { range, sets })
+    }
+
+    /// Parse a set of comparators, that must all match.
+    ///
+    /// The `set` must be a slice of `range`, which is used to report error offsets.
+    fn parse_set(range: &'a str, set: &'a str) -> Result<Vec<Comparator<'a>>, Error> {
+        // Split the set into its tokens
+        let tokens: Vec<&str> = set
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .collect();
+
+        // Parse hyphen ranges, such as `1.2 - 1.8`
+        if tokens.len() == 3 && tokens[1] == "-" {
+            let mut comparators =
+                Self::parse_comparator(range, Operator::Cmp(CompOp::Ge), tokens[0])?;
+            comparators.extend(Self::parse_comparator(
+                range,
+                Operator::Cmp(CompOp::Le),
+                tokens[2],
+            )?);
+            return Ok(comparators);
+        }
+
+        // Parse each comparator
+        let mut comparators = Vec::new();
+        let mut tokens = tokens.into_iter();
+        while let Some(token) = tokens.next() {
+            let (operator, version) = Operator::split(token)?;
+
+            // The operator and version may be separated by whitespace
+            let version = match version {
+                "" => tokens.next().ok_or(Error::InvalidRange {
+                    offset: offset_in(range, token) + token.len(),
+                })?,
+                version => version,
+            };
+
+            comparators.extend(Self::parse_comparator(range, operator, version)?);
+        }
+
+        Ok(comparators)
+    }
+
+    /// Parse the comparators for the given `operator` applied to the `version` string.
+    ///
+    /// The `version` must be a slice of `range`, which is used to report error offsets.
+    fn parse_comparator(
+        range: &'a str,
+        operator: Operator,
+        version: &'a str,
+    ) -> Result<Vec<Comparator<'a>>, Error> {
+        let offset = offset_in(range, version);
+        let partial = Partial::from(version).map_err(|err| err.offset_by(offset))?;
+        operator
+            .comparators(partial)
+            .ok_or(Error::InvalidRange { offset })
+    }
+
+    /// Get the original range string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::VersionRange;
+    ///
+    /// let range = VersionRange::from("^1.4").unwrap();
+    ///
+    /// assert_eq!(range.as_str(), "^1.4");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.range
+    }
+
+    /// Get the comparator sets of this range.
+    ///
+    /// Each set holds the comparators a version must all match. Shorthand comparators such as
+    /// `^1.4` are expanded into their bounds, `>=1.4` and `<2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompOp, VersionRange};
+    ///
+    /// let range = VersionRange::from("^1.4 || 3").unwrap();
+    /// let sets = range.sets();
+    ///
+    /// assert_eq!(sets.len(), 2);
+    /// assert_eq!(sets[0][0].op(), &CompOp::Ge);
+    /// assert_eq!(sets[0][1].op(), &CompOp::Lt);
+    /// ```
+    pub fn sets(&self) -> &Vec<Vec<Comparator<'a>>> {
+        &self.sets
+    }
+
+    /// Check whether the given `version` matches this range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::from("~1.4.2 || >=3").unwrap();
+    ///
+    /// assert!(range.matches(&Version::from("1.4.7").unwrap()));
+    /// assert!(range.matches(&Version::from("3.1").unwrap()));
+    /// assert!(!range.matches(&Version::from("1.5").unwrap()));
+    /// ```
+    pub fn matches(&self, version: &Version) -> bool {
+        self.sets
+            .iter()
+            .any(|set| set.iter().all(|comparator| comparator.matches(version)))
+    }
+}
+
+impl<'a> fmt::Display for VersionRange<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.range)
+    }
+}
+
+/// A single comparator in a version range, such as `>=1.2`.
+#[derive(Debug)]
+pub struct Comparator<'a> {
+    op: CompOp,
+    version: Version<'a>,
+
+    /// Whether pre-releases of the version don't match, such as `2.0.0-rc.1` for `<2` in `^1.4`.
+    exclude_pre_releases: bool,
+}
+
+impl<'a> Comparator<'a> {
+    /// Get the comparison operator.
+    pub fn op(&self) -> &CompOp {
+        &self.op
+    }
+
+    /// Get the version that is compared against.
+    pub fn version(&self) -> &Version<'a> {
+        &self.version
+    }
+
+    /// Check whether the given `version` matches this comparator.
+    ///
+    /// Pre-releases of an exclusive upper bound derived from a partial version don't match, so
+    /// `2.0.0-rc.1` doesn't match the `<2` bound of `^1.4`, like `<2.0.0-0` in npm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::from(">=1.2").unwrap();
+    ///
+    /// assert!(range.sets()[0][0].matches(&Version::from("1.3").unwrap()));
+    /// ```
+    pub fn matches(&self, version: &Version) -> bool {
+        version.compare_to(&self.version, &self.op)
+            && !(self.exclude_pre_releases && release(version) == release(&self.version))
+    }
+}
+
+/// The operator of a comparator in a range string.
+enum Operator {
+    /// A plain comparison operator.
+    Cmp(CompOp),
+
+    /// Caret operator (`^`), allowing changes that don't modify the left-most non-zero part.
+    Caret,
+
+    /// Tilde operator (`~`), allowing changes to parts after the minor part.
+    Tilde,
+}
+
+impl Operator {
+    /// Split the leading operator off the given comparator token.
+    ///
+    /// Tokens without operator use `Eq`. An error is returned if the operator isn't recognized.
+    fn split(token: &str) -> Result<(Operator, &str), Error> {
+        if let Some(version) = token.strip_prefix('^') {
+            return Ok((Operator::Caret, version));
+        }
+        if let Some(version) = token.strip_prefix('~') {
+            return Ok((
+                Operator::Tilde,
+                version.strip_prefix('>').unwrap_or(version),
+            ));
+        }
+
+        // Find the end of the comparison sign
+        let end = token
+            .find(|c: char| !"<>=!".contains(c))
+            .unwrap_or(token.len());
+        let (sign, version) = token.split_at(end);
+
+        if sign.is_empty() {
+            return Ok((Operator::Cmp(CompOp::Eq), version));
+        }
+        CompOp::from_sign(sign).map(|op| (Operator::Cmp(op), version))
+    }
+
+    /// Build the comparators for this operator applied to the given `partial` version.
+    ///
+    /// An empty list is returned if any version matches, `None` is returned if the combination is
+    /// invalid or if the upper bound overflows.
+    fn comparators<'a>(&self, partial: Partial<'a>) -> Option<Vec<Comparator<'a>>> {
+        // A wildcard matches anything, except when strictly comparing against it
+        if partial.numbers.is_empty() && partial.version.is_none() {
+            return match self {
+                Operator::Cmp(CompOp::Lt) | Operator::Cmp(CompOp::Gt) => None,
+                Operator::Cmp(CompOp::Ne) => None,
+                _ => Some(vec![]),
+            };
+        }
+
+        let last = partial.numbers.len().saturating_sub(1);
+        let comparators = match self {
+            Operator::Cmp(op) => match (op, partial.version.is_some()) {
+                // Exact versions are compared as is
+                (_, true) => vec![Comparator::new(op.clone(), partial.lower())],
+
+                // Partial versions match any version starting with the given parts
+                (CompOp::Eq, false) => {
+                    let upper = partial.bump(last)?;
+                    vec![
+                        Comparator::new(CompOp::Ge, partial.lower()),
+                        Comparator::upper(upper),
+                    ]
+                }
+                (CompOp::Gt, false) => vec![Comparator::new(CompOp::Ge, partial.bump(last)?)],
+                (CompOp::Le, false) => vec![Comparator::upper(partial.bump(last)?)],
+                (CompOp::Lt, false) => vec![Comparator::upper(partial.lower())],
+                (_, false) => vec![Comparator::new(op.clone(), partial.lower())],
+            },
+            Operator::Caret => {
+                // Bump the left-most non-zero part, or the last given part if all are zero
+                let index = partial.numbers.iter().position(|&n| n != 0).unwrap_or(last);
+                let upper = partial.bump(index)?;
+                vec![
+                    Comparator::new(CompOp::Ge, partial.lower()),
+                    Comparator::upper(upper),
+                ]
+            }
+            Operator::Tilde => {
+                // Bump the minor part, or the major part if no minor part is given
+                let index = if partial.numbers.len() > 1 { 1 } else { 0 };
+                let upper = partial.bump(index)?;
+                vec![
+                    Comparator::new(CompOp::Ge, partial.lower()),
+                    Comparator::upper(upper),
+                ]
+            }
+        };
+
+        Some(comparators)
+    }
+}
+
+impl<'a> Comparator<'a> {
+    /// Constructor.
+    pub(crate) fn new(op: CompOp, version: Version<'a>) -> Self {
+        Comparator {
+            op,
+            version,
+            exclude_pre_releases: false,
+        }
+    }
+
+    /// Constructor for an exclusive upper bound derived from a partial version, which doesn't
+    /// match pre-releases of the bound.
+    fn upper(version: Version<'a>) -> Self {
+        Comparator {
+            op: CompOp::Lt,
+            version,
+            exclude_pre_releases: true,
+        }
+    }
+}
+
+/// Get the leading numeric parts of the given version, without trailing zeros.
+fn release<'v, 'a>(version: &'v Version<'a>) -> &'v [VersionPart<'a>] {
+    let parts = version.parts();
+    let numbers = parts
+        .iter()
+        .take_while(|part| matches!(part, VersionPart::Number(_)))
+        .count();
+    let len = parts[..numbers]
+        .iter()
+        .rposition(|part| *part != VersionPart::Number(0))
+        .map_or(0, |index| index + 1);
+    &parts[..len]
+}
+
+/// Get the byte offset of the `inner` string slice within the `outer` string.
+pub(crate) fn offset_in(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+/// A possibly partial version in a range string, such as `1.2.x`.
+struct Partial<'a> {
+    /// The version string.
+    source: &'a str,
+
+    /// The leading numeric parts.
+    numbers: Vec<u64>,
+
+    /// The parsed version, if it isn't partial.
+    version: Option<Version<'a>>,
+}
+
+impl<'a> Partial<'a> {
+    /// Parse a possibly partial version.
+    ///
+    /// A version is partial if it contains a wildcard part (`x`, `X` or `*`), or if it consists
+    /// of less than three numeric parts.
+    fn from(source: &'a str) -> Result<Self, Error> {
+        // Strip an optional `v` prefix
+        let source = match source.strip_prefix(|c| c == 'v' || c == 'V') {
+            Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+            _ => source,
+        };
+
+        // Collect the numeric parts in front of a wildcard
+        let mut numbers = Vec::new();
+        for part in source.split('.') {
+            if source.is_empty() || matches!(part, "x" | "X" | "*") {
+                return Ok(Partial {
+                    source,
+                    numbers,
+                    version: None,
+                });
+            }
+
+            match part.parse::<u64>() {
+                Ok(number) => numbers.push(number),
+                Err(_) => break,
+            }
+        }
+
+        // Parse the complete version, partial if it only has a few numeric parts
+        let version = Version::from(source)?;
+        if version.part_count() == 0 {
+            return Err(Error::InvalidRange { offset: 0 });
+        }
+        let numbers: Vec<u64> = version
+            .parts()
+            .iter()
+            .map_while(|part| match part {
+                VersionPart::Number(number) => Some(*number),
+                VersionPart::Text(_) => None,
+            })
+            .collect();
+        let partial = numbers.len() == version.part_count() && numbers.len() < 3;
+
+        Ok(Partial {
+            source,
+            numbers,
+            version: if partial { None } else { Some(version) },
+        })
+    }
+
+    /// Get the lowest version matching this partial version.
+    fn lower(self) -> Version<'a> {
+        match self.version {
+            Some(version) => version,
+            None => Version::from_parts(self.source, Self::number_parts(&self.numbers)),
+        }
+    }
+
+    /// Get the version with the part at `index` incremented, and all following parts dropped.
+    ///
+    /// `None` is returned if the part can't be incremented.
+    fn bump(&self, index: usize) -> Option<Version<'a>> {
+        let mut numbers = self.numbers.clone();
+        numbers.resize(index + 1, 0);
+        numbers[index] = numbers[index].checked_add(1)?;
+        Some(Version::from_parts(
+            self.source,
+            Self::number_parts(&numbers),
+        ))
+    }
+
+    /// Transform the given numbers into version parts.
+    fn number_parts(numbers: &[u64]) -> Vec<VersionPart<'a>> {
+        numbers.iter().map(|&n| VersionPart::Number(n)).collect()
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::comp_op::CompOp;
+    use crate::error::Error;
+    use crate::test::test_version_range::{TEST_VERSION_RANGES, TEST_VERSION_RANGES_ERROR};
+    use crate::version::Version;
+
+    use super::VersionRange;
+
+    #[test]
+    fn from() {
+        // Test whether parsing works for each test range
+        for entry in TEST_VERSION_RANGES {
+            assert!(VersionRange::from(entry.0).is_ok(), "Parsing {}", entry.0);
+        }
+
+        // Test whether parsing fails for each invalid range
+        for range in TEST_VERSION_RANGES_ERROR {
+            assert!(VersionRange::from(range).is_err(), "Parsing {}", range);
+        }
+    }
+
+    #[test]
+    fn from_error() {
+        assert_eq!(
+            VersionRange::from(">=1.2, abc").unwrap_err(),
+            Error::NoNumber
+        );
+        assert_eq!(
+            VersionRange::from(">=1.2 =>2").unwrap_err(),
+            Error::UnknownOperator("=>".into())
+        );
+        assert_eq!(
+            VersionRange::from(">=1.2 <99999999999999999999").unwrap_err(),
+            Error::NoNumber
+        );
+        assert_eq!(
+            VersionRange::from("^1.2 || >=").unwrap_err(),
+            Error::InvalidRange { offset: 10 }
+        );
+        assert_eq!(
+            VersionRange::from("1.2 - -").unwrap_err(),
+            Error::InvalidRange { offset: 6 }
+        );
+        assert_eq!(
+            VersionRange::from("1.2 || <*").unwrap_err(),
+            Error::InvalidRange { offset: 8 }
+        );
+
+        // Upper bounds that overflow are invalid
+        assert_eq!(
+            VersionRange::from("^18446744073709551615").unwrap_err(),
+            Error::InvalidRange { offset: 1 }
+        );
+        assert_eq!(
+            VersionRange::from(">=1, <=1.18446744073709551615").unwrap_err(),
+            Error::InvalidRange { offset: 7 }
+        );
+    }
+
+    #[test]
+    fn as_str() {
+        for entry in TEST_VERSION_RANGES {
+            assert_eq!(VersionRange::from(entry.0).unwrap().as_str(), entry.0);
+        }
+    }
+
+    #[test]
+    fn sets() {
+        let range = VersionRange::from("1.2 - 1.8 || ^0.2.3, !=0.2.5").unwrap();
+        let sets = range.sets();
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].len(), 2);
+        assert_eq!(sets[0][0].op(), &CompOp::Ge);
+        assert_eq!(sets[0][1].op(), &CompOp::Lt);
+        assert_eq!(sets[1].len(), 3);
+        assert_eq!(sets[1][2].op(), &CompOp::Ne);
+        assert_eq!(
+            format!("{:?}", sets[1][1].version()),
+            "[Number(0), Number(3)]"
+        );
+    }
+
+    #[test]
+    fn matches() {
+        for entry in TEST_VERSION_RANGES {
+            let range = VersionRange::from(entry.0).unwrap();
+            let version = Version::from(entry.1).unwrap();
+
+            assert_eq!(
+                range.matches(&version),
+                entry.2,
+                "Testing that {} matches {}",
+                entry.1,
+                entry.0
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", VersionRange::from(">=1.2, <2.0").unwrap()),
+            ">=1.2, <2.0"
+        );
+    }
+}